@@ -0,0 +1,144 @@
+use crate::input::cli;
+use crate::location_manager;
+use crate::store::{file, locations};
+use crate::weather_reporter;
+use std::process::ExitCode;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitStatus {
+    Success,
+    Output,
+    InvalidArguments,
+    Config,
+    Geolocation,
+    WeatherFetch,
+    Decoding,
+    AlertTriggered,
+}
+
+impl ExitStatus {
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::Output => 1,
+            Self::InvalidArguments => 2,
+            Self::Config => 3,
+            Self::Geolocation => 4,
+            Self::WeatherFetch => 5,
+            Self::Decoding => 6,
+            Self::AlertTriggered => 7,
+        }
+    }
+}
+
+impl From<ExitStatus> for ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        ExitCode::from(status.code())
+    }
+}
+
+impl From<&weather_reporter::Error> for ExitStatus {
+    fn from(error: &weather_reporter::Error) -> Self {
+        match error {
+            weather_reporter::Error::FetchingCoordinates => Self::Geolocation,
+            weather_reporter::Error::FetchingWeatherReport => Self::WeatherFetch,
+            weather_reporter::Error::DecodingWeatherReport => Self::Decoding,
+            weather_reporter::Error::UnsupportedRequest(_)
+            | weather_reporter::Error::RejectedRequest(_) => Self::InvalidArguments,
+        }
+    }
+}
+
+impl From<&locations::Error> for ExitStatus {
+    fn from(error: &locations::Error) -> Self {
+        match error {
+            locations::Error::UnknownLocation(_) => Self::InvalidArguments,
+            locations::Error::File(error) => Self::from(error),
+        }
+    }
+}
+
+impl From<&file::Error> for ExitStatus {
+    fn from(_: &file::Error) -> Self {
+        Self::Config
+    }
+}
+
+impl From<&location_manager::Error> for ExitStatus {
+    fn from(error: &location_manager::Error) -> Self {
+        match error {
+            location_manager::Error::Store(error) => Self::from(error),
+            location_manager::Error::Geocoding(_) => Self::Geolocation,
+        }
+    }
+}
+
+impl From<&cli::Error> for ExitStatus {
+    fn from(error: &cli::Error) -> Self {
+        match error {
+            cli::Error::InvalidArguments(_) => Self::InvalidArguments,
+            cli::Error::Config(_) => Self::Config,
+            cli::Error::Locations(error) => Self::from(error),
+            cli::Error::UnknownActivity(_) => Self::InvalidArguments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn maps_reporter_errors_to_distinct_statuses() {
+        let statuses = [
+            ExitStatus::from(&weather_reporter::Error::FetchingCoordinates),
+            ExitStatus::from(&weather_reporter::Error::FetchingWeatherReport),
+            ExitStatus::from(&weather_reporter::Error::DecodingWeatherReport),
+        ];
+        assert_eq!(
+            statuses,
+            [
+                ExitStatus::Geolocation,
+                ExitStatus::WeatherFetch,
+                ExitStatus::Decoding
+            ]
+        );
+    }
+
+    #[test]
+    fn treats_unsupported_request_as_invalid_argument() {
+        let error = weather_reporter::Error::UnsupportedRequest("too many days".into());
+        assert_eq!(ExitStatus::from(&error), ExitStatus::InvalidArguments);
+        let error = weather_reporter::Error::RejectedRequest("invalid elevation".into());
+        assert_eq!(ExitStatus::from(&error), ExitStatus::InvalidArguments);
+    }
+
+    #[test]
+    fn treats_unknown_location_as_invalid_argument() {
+        let error = locations::Error::UnknownLocation("home".into());
+        assert_eq!(ExitStatus::from(&error), ExitStatus::InvalidArguments);
+        let error = locations::Error::File(file::Error::Parsing(
+            "locations.toml".into(),
+            "invalid".into(),
+        ));
+        assert_eq!(ExitStatus::from(&error), ExitStatus::Config);
+    }
+
+    #[test]
+    fn uses_distinct_codes() {
+        let statuses = [
+            ExitStatus::Success,
+            ExitStatus::Output,
+            ExitStatus::InvalidArguments,
+            ExitStatus::Config,
+            ExitStatus::Geolocation,
+            ExitStatus::WeatherFetch,
+            ExitStatus::Decoding,
+            ExitStatus::AlertTriggered,
+        ];
+        let codes: HashSet<u8> = statuses.iter().map(ExitStatus::code).collect();
+        assert_eq!(codes.len(), statuses.len());
+        assert_eq!(ExitStatus::Success.code(), 0);
+    }
+}
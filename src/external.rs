@@ -1,5 +1,19 @@
+mod clock;
+mod geocoding;
 mod geolocation;
+mod reverse_geocoding;
 mod weather;
 
-pub use geolocation::ConcreteGeolocationProvider;
+#[cfg(test)]
+mod test_server;
+
+pub use clock::SystemClock;
+pub use geocoding::ConcreteGeocodingProvider;
+pub use geolocation::{
+    ConcreteGeolocationProvider, FallbackGeolocationProvider, FreeIpApiProvider, GpsdProvider,
+    IpApiCoProvider, IpInfoProvider, LocationSource, NmeaProvider, make_geolocation_provider,
+};
+pub use reverse_geocoding::{
+    ConcreteReverseGeocodingProvider, PlaceNameSource, make_reverse_geocoding_provider,
+};
 pub use weather::ConcreteWeatherProvider;
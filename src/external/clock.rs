@@ -0,0 +1,15 @@
+use crate::port::clock::Clock;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
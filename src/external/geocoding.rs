@@ -0,0 +1,62 @@
+use crate::port::geocoding::*;
+use crate::types::error::FetchError;
+use crate::types::units::*;
+use serde::Deserialize;
+
+pub struct ConcreteGeocodingProvider;
+
+impl GeocodingProvider for ConcreteGeocodingProvider {
+    fn search(&self, address: &str) -> Result<Coordinates, FetchError> {
+        const URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+        let response = reqwest::blocking::Client::new()
+            .get(URL)
+            .query(&[("name", address), ("count", "1"), ("format", "json")])
+            .send()
+            .map_err(|_| FetchError::ConnectionFailure)?;
+        let result: SearchResponse = response.json().map_err(|_| FetchError::DecodingFailure)?;
+        result.to_coordinates().ok_or(FetchError::NotFound)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct PlaceResponse {
+    latitude: f32,
+    longitude: f32,
+}
+
+#[derive(Deserialize, Debug)]
+struct SearchResponse {
+    results: Option<Vec<PlaceResponse>>,
+}
+
+impl SearchResponse {
+    fn to_coordinates(&self) -> Option<Coordinates> {
+        let place = self.results.as_ref()?.first()?;
+        Some(Coordinates::new(place.latitude, place.longitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_first_search_result() {
+        let json = r#"{"results": [
+            {"name": "Paris", "latitude": 48.85341, "longitude": 2.3488, "country": "France"},
+            {"name": "Paris", "latitude": 33.66094, "longitude": -95.55551, "country": "United States"}
+        ]}"#;
+        let response: SearchResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            response.to_coordinates(),
+            Some(Coordinates::new(48.85341, 2.3488))
+        );
+    }
+
+    #[test]
+    fn decodes_response_without_results() {
+        let response: SearchResponse =
+            serde_json::from_str(r#"{"generationtime_ms": 0.5}"#).unwrap();
+        assert_eq!(response.to_coordinates(), None);
+    }
+}
@@ -1,27 +1,78 @@
+mod fallback;
+mod freeipapi;
+mod gps;
+mod ipapi_co;
+mod ipinfo;
+mod nmea;
+
 use crate::port::geolocation::*;
 use crate::types::error::FetchError;
 use crate::types::units::*;
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub use fallback::FallbackGeolocationProvider;
+pub use freeipapi::FreeIpApiProvider;
+pub use gps::{GpsdProvider, NmeaProvider};
+pub use ipapi_co::IpApiCoProvider;
+pub use ipinfo::IpInfoProvider;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationSource {
+    Ip,
+    Gpsd { address: String, timeout: Duration },
+    Nmea { path: PathBuf, timeout: Duration },
+}
+
+pub fn make_geolocation_provider(source: &LocationSource) -> Box<dyn GeolocationProvider> {
+    match source {
+        LocationSource::Ip => Box::new(ConcreteGeolocationProvider::default()),
+        LocationSource::Gpsd { address, timeout } => {
+            Box::new(GpsdProvider::new(address.clone(), *timeout))
+        }
+        LocationSource::Nmea { path, timeout } => Box::new(NmeaProvider::new(path, *timeout)),
+    }
+}
+
+pub struct ConcreteGeolocationProvider {
+    providers: FallbackGeolocationProvider,
+}
 
-pub struct ConcreteGeolocationProvider;
+impl Default for ConcreteGeolocationProvider {
+    fn default() -> Self {
+        let providers = FallbackGeolocationProvider::new(vec![
+            Box::new(IpApiCoProvider::default()),
+            Box::new(IpInfoProvider::default()),
+            Box::new(FreeIpApiProvider::default()),
+        ]);
+        Self { providers }
+    }
+}
 
 impl GeolocationProvider for ConcreteGeolocationProvider {
     fn fetch(&self) -> Result<Coordinates, FetchError> {
-        const URL: &str = "http://ip-api.com/json/?fields=status,message,lat,lon";
-        let response = match reqwest::blocking::get(URL) {
-            Ok(result) => result,
-            Err(_) => return Err(FetchError::ConnectionFailure),
-        };
-        match response.json::<CoordinatesResponse>() {
-            Ok(result) => Ok(Coordinates::new(result.lat, result.lon)),
-            Err(_) => Err(FetchError::DecodingFailure),
-        }
+        self.providers.fetch()
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-struct CoordinatesResponse {
-    pub status: String,
-    pub lat: f32,
-    pub lon: f32,
+fn make_client() -> reqwest::blocking::Client {
+    reqwest::blocking::Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+fn fetch_json<R: DeserializeOwned>(
+    client: &reqwest::blocking::Client,
+    url: &str,
+) -> Result<R, FetchError> {
+    client
+        .get(url)
+        .send()
+        .map_err(|_| FetchError::ConnectionFailure)?
+        .json()
+        .map_err(|_| FetchError::DecodingFailure)
 }
@@ -0,0 +1,71 @@
+use crate::port::geolocation::GeolocationProvider;
+use crate::types::error::FetchError;
+use crate::types::units::Coordinates;
+
+pub struct FallbackGeolocationProvider {
+    providers: Vec<Box<dyn GeolocationProvider>>,
+}
+
+impl FallbackGeolocationProvider {
+    pub fn new(providers: Vec<Box<dyn GeolocationProvider>>) -> Self {
+        Self { providers }
+    }
+}
+
+impl GeolocationProvider for FallbackGeolocationProvider {
+    fn fetch(&self) -> Result<Coordinates, FetchError> {
+        let mut last_error = FetchError::NotFound;
+        for provider in &self.providers {
+            match provider.fetch() {
+                Ok(coordinates) => return Ok(coordinates),
+                Err(error) => last_error = error,
+            }
+        }
+        Err(last_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::mocks::MockGeolocationProvider;
+
+    fn make_provider(result: Result<Coordinates, FetchError>) -> Box<dyn GeolocationProvider> {
+        let mut provider = MockGeolocationProvider::new();
+        provider.expect_fetch().once().return_const(result);
+        Box::new(provider)
+    }
+
+    fn make_unused_provider() -> Box<dyn GeolocationProvider> {
+        let mut provider = MockGeolocationProvider::new();
+        provider.expect_fetch().never();
+        Box::new(provider)
+    }
+
+    #[test]
+    fn returns_result_of_first_successful_provider() {
+        let coordinates = Coordinates::new(1.23, 45.67);
+        let sut = FallbackGeolocationProvider::new(vec![
+            make_provider(Err(FetchError::ConnectionFailure)),
+            make_provider(Err(FetchError::Rejected("quota exceeded".into()))),
+            make_provider(Ok(coordinates)),
+            make_unused_provider(),
+        ]);
+        assert_eq!(sut.fetch(), Ok(coordinates));
+    }
+
+    #[test]
+    fn returns_last_error_when_all_providers_fail() {
+        let sut = FallbackGeolocationProvider::new(vec![
+            make_provider(Err(FetchError::Rejected("quota exceeded".into()))),
+            make_provider(Err(FetchError::ConnectionFailure)),
+        ]);
+        assert_eq!(sut.fetch(), Err(FetchError::ConnectionFailure));
+    }
+
+    #[test]
+    fn fails_without_providers() {
+        let sut = FallbackGeolocationProvider::new(Vec::new());
+        assert_eq!(sut.fetch(), Err(FetchError::NotFound));
+    }
+}
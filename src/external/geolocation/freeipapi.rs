@@ -0,0 +1,85 @@
+use crate::external::geolocation::{fetch_json, make_client};
+use crate::port::geolocation::GeolocationProvider;
+use crate::types::error::FetchError;
+use crate::types::units::Coordinates;
+use serde::Deserialize;
+
+const URL: &str = "https://freeipapi.com";
+
+pub struct FreeIpApiProvider {
+    client: reqwest::blocking::Client,
+    base_url: String,
+}
+
+impl FreeIpApiProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: make_client(),
+            base_url: base_url.into(),
+        }
+    }
+}
+
+impl Default for FreeIpApiProvider {
+    fn default() -> Self {
+        Self::new(URL)
+    }
+}
+
+impl GeolocationProvider for FreeIpApiProvider {
+    fn fetch(&self) -> Result<Coordinates, FetchError> {
+        let url = format!("{}/api/json", self.base_url);
+        fetch_json::<FreeIpApiResponse>(&self.client, &url)?.to_coordinates()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct FreeIpApiResponse {
+    latitude: Option<f32>,
+    longitude: Option<f32>,
+    message: Option<String>,
+}
+
+impl FreeIpApiResponse {
+    fn to_coordinates(&self) -> Result<Coordinates, FetchError> {
+        match (self.latitude, self.longitude, &self.message) {
+            (Some(latitude), Some(longitude), _) => Ok(Coordinates::new(latitude, longitude)),
+            (_, _, Some(message)) => Err(FetchError::Rejected(message.clone())),
+            _ => Err(FetchError::NotFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::test_server::{TestServer, make_unreachable_url};
+
+    #[test]
+    fn fetches_coordinates() {
+        let server = TestServer::serve(
+            200,
+            r#"{"ipVersion":4,"ipAddress":"1.2.3.4","latitude":52.2297,"longitude":21.0122}"#,
+        );
+        let sut = FreeIpApiProvider::new(&server.url);
+        assert_eq!(sut.fetch(), Ok(Coordinates::new(52.2297, 21.0122)));
+        assert_eq!(server.received_request(), "GET /api/json HTTP/1.1");
+    }
+
+    #[test]
+    fn reports_error_message() {
+        let server = TestServer::serve(429, r#"{"message":"Too many requests"}"#);
+        let sut = FreeIpApiProvider::new(&server.url);
+        assert_eq!(
+            sut.fetch(),
+            Err(FetchError::Rejected("Too many requests".into()))
+        );
+    }
+
+    #[test]
+    fn fails_when_server_is_unreachable() {
+        let sut = FreeIpApiProvider::new(make_unreachable_url());
+        assert_eq!(sut.fetch(), Err(FetchError::ConnectionFailure));
+    }
+}
@@ -0,0 +1,257 @@
+use crate::external::geolocation::nmea;
+use crate::port::geolocation::GeolocationProvider;
+use crate::types::error::FetchError;
+use crate::types::units::Coordinates;
+use serde::Deserialize;
+use std::fs::File;
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const WATCH_COMMAND: &[u8] = b"?WATCH={\"enable\":true,\"json\":true};\n";
+const MIN_GPSD_MODE: u8 = 2;
+const MAX_HORIZONTAL_ERROR_METERS: f32 = 100.0;
+
+pub struct GpsdProvider {
+    address: String,
+    timeout: Duration,
+}
+
+impl GpsdProvider {
+    pub fn new(address: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            address: address.into(),
+            timeout,
+        }
+    }
+}
+
+impl GeolocationProvider for GpsdProvider {
+    fn fetch(&self) -> Result<Coordinates, FetchError> {
+        let address = self
+            .address
+            .to_socket_addrs()
+            .map_err(|_| FetchError::ConnectionFailure)?
+            .next()
+            .ok_or(FetchError::ConnectionFailure)?;
+        let deadline = Instant::now() + self.timeout;
+        let mut stream = TcpStream::connect_timeout(&address, self.timeout)
+            .map_err(|_| FetchError::ConnectionFailure)?;
+        stream
+            .write_all(WATCH_COMMAND)
+            .map_err(|_| FetchError::ConnectionFailure)?;
+        let mut reader = BufReader::new(stream);
+        let mut line = Vec::new();
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(FetchError::Timeout);
+            }
+            reader
+                .get_ref()
+                .set_read_timeout(Some(remaining))
+                .map_err(|_| FetchError::ConnectionFailure)?;
+            line.clear();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) => return Err(FetchError::NotFound),
+                Ok(_) => {
+                    if let Some(coordinates) = parse_gpsd_report(&String::from_utf8_lossy(&line)) {
+                        return Ok(coordinates);
+                    }
+                }
+                Err(error)
+                    if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+                {
+                    return Err(FetchError::Timeout);
+                }
+                Err(_) => return Err(FetchError::ConnectionFailure),
+            }
+        }
+    }
+}
+
+pub struct NmeaProvider {
+    path: PathBuf,
+    timeout: Duration,
+}
+
+impl NmeaProvider {
+    pub fn new(path: impl Into<PathBuf>, timeout: Duration) -> Self {
+        Self {
+            path: path.into(),
+            timeout,
+        }
+    }
+}
+
+impl GeolocationProvider for NmeaProvider {
+    fn fetch(&self) -> Result<Coordinates, FetchError> {
+        let file = File::open(&self.path).map_err(|_| FetchError::ConnectionFailure)?;
+        wait_for_fix(BufReader::new(file), self.timeout, nmea::parse_fix)
+    }
+}
+
+// Device files have no read timeout, so lines are read on a separate thread that
+// stops with the next line once the caller has given up.
+fn wait_for_fix<R>(
+    reader: R,
+    timeout: Duration,
+    parse: fn(&str) -> Option<Coordinates>,
+) -> Result<Coordinates, FetchError>
+where
+    R: BufRead + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in reader.split(b'\n') {
+            let Ok(line) = line else {
+                return;
+            };
+            let line = String::from_utf8_lossy(&line).into_owned();
+            if sender.send(line).is_err() {
+                return;
+            }
+        }
+    });
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match receiver.recv_timeout(remaining) {
+            Ok(line) => {
+                if let Some(coordinates) = parse(&line) {
+                    return Ok(coordinates);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => return Err(FetchError::Timeout),
+            Err(RecvTimeoutError::Disconnected) => return Err(FetchError::NotFound),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct GpsdReport {
+    class: String,
+    mode: Option<u8>,
+    lat: Option<f32>,
+    lon: Option<f32>,
+    eph: Option<f32>,
+}
+
+fn parse_gpsd_report(line: &str) -> Option<Coordinates> {
+    let report: GpsdReport = serde_json::from_str(line).ok()?;
+    if report.class != "TPV" || report.mode? < MIN_GPSD_MODE {
+        return None;
+    }
+    if report
+        .eph
+        .is_some_and(|error| error > MAX_HORIZONTAL_ERROR_METERS)
+    {
+        return None;
+    }
+    Some(Coordinates::new(report.lat?, report.lon?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::path::Path;
+
+    const VERSION: &str =
+        r#"{"class":"VERSION","release":"3.25","proto_major":3,"proto_minor":15}"#;
+    const DEVICES: &str =
+        r#"{"class":"DEVICES","devices":[{"class":"DEVICE","path":"/dev/ttyUSB0"}]}"#;
+    const NO_FIX: &str = r#"{"class":"TPV","device":"/dev/ttyUSB0","mode":1}"#;
+    const INACCURATE_FIX: &str =
+        r#"{"class":"TPV","device":"/dev/ttyUSB0","mode":2,"lat":52.1,"lon":21.1,"eph":850.0}"#;
+    const GOOD_FIX: &str =
+        r#"{"class":"TPV","device":"/dev/ttyUSB0","mode":3,"lat":52.2297,"lon":21.0122,"eph":8.5}"#;
+
+    fn serve_gpsd(reports: &'static [&'static str], keep_open: Duration) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut command = [0; WATCH_COMMAND.len()];
+            stream.read_exact(&mut command).unwrap();
+            assert_eq!(command, WATCH_COMMAND);
+            for report in reports {
+                writeln!(stream, "{report}").unwrap();
+            }
+            thread::sleep(keep_open);
+        });
+        address
+    }
+
+    fn testdata(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/external/geolocation/testdata")
+            .join(name)
+    }
+
+    #[test]
+    fn reads_fix_from_gpsd() {
+        let reports = &[VERSION, DEVICES, NO_FIX, INACCURATE_FIX, GOOD_FIX];
+        let address = serve_gpsd(reports, Duration::ZERO);
+        let sut = GpsdProvider::new(address, Duration::from_secs(5));
+        assert_eq!(sut.fetch(), Ok(Coordinates::new(52.2297, 21.0122)));
+    }
+
+    #[test]
+    fn times_out_without_good_fix_from_gpsd() {
+        let address = serve_gpsd(&[VERSION, NO_FIX], Duration::from_secs(2));
+        let sut = GpsdProvider::new(address, Duration::from_millis(200));
+        assert_eq!(sut.fetch(), Err(FetchError::Timeout));
+    }
+
+    #[test]
+    fn closes_gpsd_connection_on_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            writeln!(stream, "{NO_FIX}").unwrap();
+            let mut received = Vec::new();
+            let _ = stream.read_to_end(&mut received);
+            sender.send(received).unwrap();
+        });
+        let sut = GpsdProvider::new(address, Duration::from_millis(200));
+        assert_eq!(sut.fetch(), Err(FetchError::Timeout));
+        let received = receiver.recv_timeout(Duration::from_millis(100));
+        assert_eq!(received, Ok(WATCH_COMMAND.to_vec()));
+    }
+
+    #[test]
+    fn fails_when_gpsd_is_not_running() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        drop(listener);
+        let sut = GpsdProvider::new(address, Duration::from_secs(1));
+        assert_eq!(sut.fetch(), Err(FetchError::ConnectionFailure));
+    }
+
+    #[test]
+    fn reads_first_good_fix_from_nmea_log() {
+        let sut = NmeaProvider::new(testdata("van.nmea"), Duration::from_secs(5));
+        let coordinates = sut.fetch().unwrap();
+        assert!((coordinates.latitude.raw() - 52.2297).abs() < 0.0001);
+        assert!((coordinates.longitude.raw() - 21.0122).abs() < 0.0001);
+    }
+
+    #[test]
+    fn fails_when_nmea_log_has_no_good_fix() {
+        let sut = NmeaProvider::new(testdata("no_fix.nmea"), Duration::from_secs(5));
+        assert_eq!(sut.fetch(), Err(FetchError::NotFound));
+    }
+
+    #[test]
+    fn fails_when_nmea_source_is_missing() {
+        let sut = NmeaProvider::new(testdata("missing.nmea"), Duration::from_secs(5));
+        assert_eq!(sut.fetch(), Err(FetchError::ConnectionFailure));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::external::geolocation::{fetch_json, make_client};
+use crate::port::geolocation::GeolocationProvider;
+use crate::types::error::FetchError;
+use crate::types::units::Coordinates;
+use serde::Deserialize;
+
+const URL: &str = "https://ipapi.co";
+
+pub struct IpApiCoProvider {
+    client: reqwest::blocking::Client,
+    base_url: String,
+}
+
+impl IpApiCoProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: make_client(),
+            base_url: base_url.into(),
+        }
+    }
+}
+
+impl Default for IpApiCoProvider {
+    fn default() -> Self {
+        Self::new(URL)
+    }
+}
+
+impl GeolocationProvider for IpApiCoProvider {
+    fn fetch(&self) -> Result<Coordinates, FetchError> {
+        let url = format!("{}/json/", self.base_url);
+        fetch_json::<IpApiCoResponse>(&self.client, &url)?.to_coordinates()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct IpApiCoResponse {
+    #[serde(default)]
+    error: bool,
+    reason: Option<String>,
+    latitude: Option<f32>,
+    longitude: Option<f32>,
+}
+
+impl IpApiCoResponse {
+    fn to_coordinates(&self) -> Result<Coordinates, FetchError> {
+        if self.error {
+            let reason = self
+                .reason
+                .clone()
+                .unwrap_or_else(|| "unknown error".into());
+            return Err(FetchError::Rejected(reason));
+        }
+        match (self.latitude, self.longitude) {
+            (Some(latitude), Some(longitude)) => Ok(Coordinates::new(latitude, longitude)),
+            _ => Err(FetchError::NotFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::test_server::TestServer;
+
+    #[test]
+    fn fetches_coordinates() {
+        let server = TestServer::serve(
+            200,
+            r#"{"ip":"1.2.3.4","city":"Warsaw","latitude":52.2297,"longitude":21.0122}"#,
+        );
+        let sut = IpApiCoProvider::new(&server.url);
+        assert_eq!(sut.fetch(), Ok(Coordinates::new(52.2297, 21.0122)));
+        assert_eq!(server.received_request(), "GET /json/ HTTP/1.1");
+    }
+
+    #[test]
+    fn reports_error_reason() {
+        let server = TestServer::serve(
+            429,
+            r#"{"error":true,"reason":"RateLimited","message":"Visit ipapi.co/ratelimited"}"#,
+        );
+        let sut = IpApiCoProvider::new(&server.url);
+        assert_eq!(sut.fetch(), Err(FetchError::Rejected("RateLimited".into())));
+    }
+
+    #[test]
+    fn fails_without_coordinates() {
+        let server = TestServer::serve(200, r#"{"ip":"127.0.0.1","reserved":true}"#);
+        let sut = IpApiCoProvider::new(&server.url);
+        assert_eq!(sut.fetch(), Err(FetchError::NotFound));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::external::geolocation::{fetch_json, make_client};
+use crate::port::geolocation::GeolocationProvider;
+use crate::types::error::FetchError;
+use crate::types::units::Coordinates;
+use serde::Deserialize;
+
+const URL: &str = "https://ipinfo.io";
+
+pub struct IpInfoProvider {
+    client: reqwest::blocking::Client,
+    base_url: String,
+}
+
+impl IpInfoProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            client: make_client(),
+            base_url: base_url.into(),
+        }
+    }
+}
+
+impl Default for IpInfoProvider {
+    fn default() -> Self {
+        Self::new(URL)
+    }
+}
+
+impl GeolocationProvider for IpInfoProvider {
+    fn fetch(&self) -> Result<Coordinates, FetchError> {
+        let url = format!("{}/json", self.base_url);
+        fetch_json::<IpInfoResponse>(&self.client, &url)?.to_coordinates()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ErrorResponse {
+    title: Option<String>,
+    message: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct IpInfoResponse {
+    loc: Option<String>,
+    error: Option<ErrorResponse>,
+}
+
+impl IpInfoResponse {
+    fn to_coordinates(&self) -> Result<Coordinates, FetchError> {
+        if let Some(error) = &self.error {
+            let message = error
+                .message
+                .clone()
+                .or_else(|| error.title.clone())
+                .unwrap_or_else(|| "unknown error".into());
+            return Err(FetchError::Rejected(message));
+        }
+        let location = self.loc.as_ref().ok_or(FetchError::NotFound)?;
+        let (latitude, longitude) = location
+            .split_once(',')
+            .ok_or(FetchError::DecodingFailure)?;
+        let parse = |value: &str| {
+            value
+                .parse::<f32>()
+                .map_err(|_| FetchError::DecodingFailure)
+        };
+        Ok(Coordinates::new(parse(latitude)?, parse(longitude)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::test_server::TestServer;
+
+    #[test]
+    fn fetches_coordinates() {
+        let server = TestServer::serve(
+            200,
+            r#"{"ip":"1.2.3.4","city":"Warsaw","loc":"52.2297,21.0122"}"#,
+        );
+        let sut = IpInfoProvider::new(&server.url);
+        assert_eq!(sut.fetch(), Ok(Coordinates::new(52.2297, 21.0122)));
+        assert_eq!(server.received_request(), "GET /json HTTP/1.1");
+    }
+
+    #[test]
+    fn reports_error_message() {
+        let server = TestServer::serve(
+            429,
+            r#"{"status":429,"error":{"title":"Rate limit exceeded","message":"Upgrade to increase your usage limits"}}"#,
+        );
+        let sut = IpInfoProvider::new(&server.url);
+        assert_eq!(
+            sut.fetch(),
+            Err(FetchError::Rejected(
+                "Upgrade to increase your usage limits".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fails_for_bogon_address() {
+        let server = TestServer::serve(200, r#"{"ip":"127.0.0.1","bogon":true}"#);
+        let sut = IpInfoProvider::new(&server.url);
+        assert_eq!(sut.fetch(), Err(FetchError::NotFound));
+    }
+
+    #[test]
+    fn fails_on_malformed_location() {
+        let server = TestServer::serve(200, r#"{"loc":"52.2297"}"#);
+        let sut = IpInfoProvider::new(&server.url);
+        assert_eq!(sut.fetch(), Err(FetchError::DecodingFailure));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::types::units::Coordinates;
+
+const MIN_SATELLITES: u8 = 4;
+const MAX_HORIZONTAL_DILUTION: f32 = 5.0;
+
+pub fn parse_fix(line: &str) -> Option<Coordinates> {
+    let fields = split_sentence(line.trim())?;
+    let sentence_type = fields.first()?.get(2..)?;
+    match sentence_type {
+        "GGA" => parse_gga(&fields),
+        "RMC" => parse_rmc(&fields),
+        _ => None,
+    }
+}
+
+fn split_sentence(line: &str) -> Option<Vec<&str>> {
+    let body = line.strip_prefix('$')?;
+    let (data, checksum) = body.split_once('*')?;
+    let expected = u8::from_str_radix(checksum, 16).ok()?;
+    let actual = data.bytes().fold(0, |result, byte| result ^ byte);
+    (actual == expected).then(|| data.split(',').collect())
+}
+
+fn parse_gga(fields: &[&str]) -> Option<Coordinates> {
+    let quality: u8 = fields.get(6)?.parse().ok()?;
+    let satellites: u8 = fields.get(7)?.parse().ok()?;
+    if quality == 0 || satellites < MIN_SATELLITES {
+        return None;
+    }
+    if let Some(Ok(dilution)) = fields.get(8).map(|field| field.parse::<f32>()) {
+        if dilution > MAX_HORIZONTAL_DILUTION {
+            return None;
+        }
+    }
+    parse_position(fields.get(2..6)?)
+}
+
+fn parse_rmc(fields: &[&str]) -> Option<Coordinates> {
+    if *fields.get(2)? != "A" || fields.get(12).is_some_and(|mode| mode.starts_with('N')) {
+        return None;
+    }
+    parse_position(fields.get(3..7)?)
+}
+
+fn parse_position(fields: &[&str]) -> Option<Coordinates> {
+    let [latitude, north_south, longitude, east_west] = fields else {
+        return None;
+    };
+    let latitude = parse_angle(latitude, 2)?
+        * match *north_south {
+            "N" => 1.0,
+            "S" => -1.0,
+            _ => return None,
+        };
+    let longitude = parse_angle(longitude, 3)?
+        * match *east_west {
+            "E" => 1.0,
+            "W" => -1.0,
+            _ => return None,
+        };
+    Some(Coordinates::new(latitude as f32, longitude as f32))
+}
+
+fn parse_angle(field: &str, degree_digits: usize) -> Option<f64> {
+    let degrees: f64 = field.get(..degree_digits)?.parse().ok()?;
+    let minutes: f64 = field.get(degree_digits..)?.parse().ok()?;
+    Some(degrees + minutes / 60.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_gga_sentence() {
+        let line = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+        let coordinates = parse_fix(line).unwrap();
+        assert!((coordinates.latitude.raw() - 48.1173).abs() < 0.0001);
+        assert!((coordinates.longitude.raw() - 11.516_666).abs() < 0.0001);
+    }
+
+    #[test]
+    fn parses_rmc_sentence_in_southern_and_western_hemisphere() {
+        let line = "$GNRMC,081836,A,3751.65,S,14507.36,W,000.0,360.0,130998,011.3,E*6E";
+        let coordinates = parse_fix(line).unwrap();
+        assert!((coordinates.latitude.raw() + 37.860_832).abs() < 0.0001);
+        assert!((coordinates.longitude.raw() + 145.122_67).abs() < 0.0001);
+    }
+
+    #[test]
+    fn rejects_sentences_without_valid_fix() {
+        let no_fix = "$GPGGA,123519,,,,,0,00,,,M,,M,,*6B";
+        assert_eq!(parse_fix(no_fix), None);
+        let void_status = "$GPRMC,081836,V,3751.65,S,14507.36,E,000.0,360.0,130998,011.3,E*75";
+        assert_eq!(parse_fix(void_status), None);
+    }
+
+    #[test]
+    fn rejects_fixes_of_poor_quality() {
+        let few_satellites = "$GPGGA,123519,4807.038,N,01131.000,E,1,03,0.9,545.4,M,46.9,M,,*4C";
+        assert_eq!(parse_fix(few_satellites), None);
+        let high_dilution = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,9.9,545.4,M,46.9,M,,*4E";
+        assert_eq!(parse_fix(high_dilution), None);
+    }
+
+    #[test]
+    fn rejects_sentences_with_invalid_checksum() {
+        let line = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*48";
+        assert_eq!(parse_fix(line), None);
+        let line = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,";
+        assert_eq!(parse_fix(line), None);
+    }
+
+    #[test]
+    fn ignores_other_sentences() {
+        let line = "$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39";
+        assert_eq!(parse_fix(line), None);
+    }
+}
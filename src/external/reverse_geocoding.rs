@@ -0,0 +1,55 @@
+mod cache;
+mod nearest_city;
+mod nominatim;
+
+use crate::port::geocoding::ReverseGeocodingProvider;
+use crate::types::error::FetchError;
+use crate::types::location::PlaceName;
+use crate::types::units::Coordinates;
+use std::path::{Path, PathBuf};
+
+use cache::CachedReverseGeocodingProvider;
+use nearest_city::NearestCityProvider;
+use nominatim::NominatimProvider;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaceNameSource {
+    Off,
+    Offline,
+    Online { cache: Option<PathBuf> },
+}
+
+pub fn make_reverse_geocoding_provider(
+    source: &PlaceNameSource,
+) -> Box<dyn ReverseGeocodingProvider> {
+    match source {
+        PlaceNameSource::Off | PlaceNameSource::Offline => Box::new(NearestCityProvider::default()),
+        PlaceNameSource::Online { cache } => {
+            Box::new(ConcreteReverseGeocodingProvider::new(cache.as_deref()))
+        }
+    }
+}
+
+pub struct ConcreteReverseGeocodingProvider {
+    online: CachedReverseGeocodingProvider<NominatimProvider>,
+    offline: NearestCityProvider,
+}
+
+impl ConcreteReverseGeocodingProvider {
+    pub fn new(cache: Option<&Path>) -> Self {
+        Self {
+            online: CachedReverseGeocodingProvider::new(NominatimProvider::default(), cache),
+            offline: NearestCityProvider::default(),
+        }
+    }
+}
+
+impl ReverseGeocodingProvider for ConcreteReverseGeocodingProvider {
+    fn lookup(&self, coordinates: &Coordinates) -> Result<PlaceName, FetchError> {
+        match self.online.lookup(coordinates) {
+            Ok(place_name) => Ok(place_name),
+            Err(FetchError::NotFound) => Err(FetchError::NotFound),
+            Err(_) => self.offline.lookup(coordinates),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::port::geocoding::ReverseGeocodingProvider;
+use crate::store::places::PlaceStore;
+use crate::types::error::FetchError;
+use crate::types::location::PlaceName;
+use crate::types::units::Coordinates;
+use std::cell::RefCell;
+use std::path::Path;
+
+pub struct CachedReverseGeocodingProvider<P: ReverseGeocodingProvider> {
+    provider: P,
+    store: RefCell<Option<PlaceStore>>,
+}
+
+impl<P: ReverseGeocodingProvider> CachedReverseGeocodingProvider<P> {
+    pub fn new(provider: P, path: Option<&Path>) -> Self {
+        let store = path.and_then(|path| PlaceStore::load(path).ok());
+        Self {
+            provider,
+            store: RefCell::new(store),
+        }
+    }
+}
+
+impl<P: ReverseGeocodingProvider> ReverseGeocodingProvider for CachedReverseGeocodingProvider<P> {
+    fn lookup(&self, coordinates: &Coordinates) -> Result<PlaceName, FetchError> {
+        let mut store = self.store.borrow_mut();
+        if let Some(place_name) = store.as_ref().and_then(|store| store.get(coordinates)) {
+            return Ok(place_name);
+        }
+        let place_name = self.provider.lookup(coordinates)?;
+        if let Some(store) = store.as_mut() {
+            store.add(coordinates, place_name.clone());
+            let _ = store.save();
+        }
+        Ok(place_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::geocoding::MockReverseGeocodingProvider;
+    use crate::store::places::PLACES_FILE_NAME;
+    use crate::test_support::make_test_path;
+
+    #[test]
+    fn looks_up_each_place_once_across_runs() {
+        let place_name = PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        };
+        let mut provider = MockReverseGeocodingProvider::new();
+        provider
+            .expect_lookup()
+            .once()
+            .return_const(Ok(place_name.clone()));
+        let path = make_test_path(PLACES_FILE_NAME);
+        let coordinates = Coordinates::new(52.2297, 21.0122);
+
+        let sut = CachedReverseGeocodingProvider::new(provider, Some(&path));
+        assert_eq!(sut.lookup(&coordinates), Ok(place_name.clone()));
+        assert_eq!(sut.lookup(&coordinates), Ok(place_name.clone()));
+
+        let mut provider = MockReverseGeocodingProvider::new();
+        provider.expect_lookup().never();
+        let sut = CachedReverseGeocodingProvider::new(provider, Some(&path));
+        assert_eq!(sut.lookup(&coordinates), Ok(place_name));
+    }
+
+    #[test]
+    fn does_not_cache_failed_lookups() {
+        let mut provider = MockReverseGeocodingProvider::new();
+        provider
+            .expect_lookup()
+            .times(2)
+            .return_const(Err(FetchError::ConnectionFailure));
+        let path = make_test_path(PLACES_FILE_NAME);
+        let sut = CachedReverseGeocodingProvider::new(provider, Some(&path));
+        let coordinates = Coordinates::new(52.2297, 21.0122);
+        assert_eq!(sut.lookup(&coordinates), Err(FetchError::ConnectionFailure));
+        assert_eq!(sut.lookup(&coordinates), Err(FetchError::ConnectionFailure));
+    }
+}
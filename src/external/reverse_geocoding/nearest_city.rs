@@ -0,0 +1,113 @@
+use crate::port::geocoding::ReverseGeocodingProvider;
+use crate::types::error::FetchError;
+use crate::types::location::PlaceName;
+use crate::types::units::Coordinates;
+
+const CITIES: &str = include_str!("cities.csv");
+const EARTH_RADIUS_KM: f32 = 6371.0;
+const MAX_DISTANCE_KM: f32 = 100.0;
+
+struct City {
+    name: String,
+    country: String,
+    coordinates: Coordinates,
+}
+
+pub struct NearestCityProvider {
+    cities: Vec<City>,
+}
+
+impl Default for NearestCityProvider {
+    fn default() -> Self {
+        Self {
+            cities: parse_cities(CITIES),
+        }
+    }
+}
+
+impl ReverseGeocodingProvider for NearestCityProvider {
+    fn lookup(&self, coordinates: &Coordinates) -> Result<PlaceName, FetchError> {
+        self.cities
+            .iter()
+            .map(|city| (city, distance_km(coordinates, &city.coordinates)))
+            .filter(|(_, distance)| *distance <= MAX_DISTANCE_KM)
+            .min_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+            .map(|(city, _)| PlaceName {
+                name: city.name.clone(),
+                country: Some(city.country.clone()),
+            })
+            .ok_or(FetchError::NotFound)
+    }
+}
+
+fn parse_cities(input: &str) -> Vec<City> {
+    input
+        .lines()
+        .skip(1)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            let [name, country, latitude, longitude] = fields[..] else {
+                panic!("Malformed city entry: {line}");
+            };
+            let parse = |value: &str| -> f32 {
+                value
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Malformed city entry: {line}"))
+            };
+            City {
+                name: name.into(),
+                country: country.into(),
+                coordinates: Coordinates::new(parse(latitude), parse(longitude)),
+            }
+        })
+        .collect()
+}
+
+fn distance_km(from: &Coordinates, to: &Coordinates) -> f32 {
+    let from_latitude = from.latitude.raw().to_radians();
+    let to_latitude = to.latitude.raw().to_radians();
+    let latitude_delta = to_latitude - from_latitude;
+    let longitude_delta = (to.longitude.raw() - from.longitude.raw()).to_radians();
+    let a = (latitude_delta / 2.0).sin().powi(2)
+        + from_latitude.cos() * to_latitude.cos() * (longitude_delta / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bundled_cities() {
+        let cities = parse_cities(CITIES);
+        assert!(cities.len() > 100);
+    }
+
+    #[test]
+    fn measures_distance_between_cities() {
+        let warsaw = Coordinates::new(52.2297, 21.0122);
+        let krakow = Coordinates::new(50.0647, 19.9450);
+        let distance = distance_km(&warsaw, &krakow);
+        assert!((250.0..=255.0).contains(&distance), "{distance}");
+        assert_eq!(distance_km(&warsaw, &warsaw), 0.0);
+    }
+
+    #[test]
+    fn finds_nearest_city() {
+        let provider = NearestCityProvider::default();
+        let expected = PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        };
+        let result = provider.lookup(&Coordinates::new(52.25, 20.95));
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[test]
+    fn finds_nothing_far_from_any_city() {
+        let provider = NearestCityProvider::default();
+        let result = provider.lookup(&Coordinates::new(0.0, -140.0));
+        assert_eq!(result, Err(FetchError::NotFound));
+    }
+}
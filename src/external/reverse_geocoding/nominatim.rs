@@ -0,0 +1,196 @@
+use crate::port::geocoding::ReverseGeocodingProvider;
+use crate::types::error::FetchError;
+use crate::types::location::PlaceName;
+use crate::types::units::Coordinates;
+use serde::Deserialize;
+use std::cell::Cell;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const URL: &str = "https://nominatim.openstreetmap.org/reverse";
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+const TIMEOUT: Duration = Duration::from_secs(5);
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct NominatimProvider {
+    client: reqwest::blocking::Client,
+    url: String,
+    min_request_interval: Duration,
+    last_request: Cell<Option<Instant>>,
+}
+
+impl NominatimProvider {
+    pub fn new(url: impl Into<String>, min_request_interval: Duration) -> Self {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent(USER_AGENT)
+            .timeout(TIMEOUT)
+            .build()
+            .expect("Failed to build HTTP client");
+        Self {
+            client,
+            url: url.into(),
+            min_request_interval,
+            last_request: Cell::new(None),
+        }
+    }
+
+    fn wait_for_turn(&self) {
+        if let Some(last_request) = self.last_request.get() {
+            let elapsed = last_request.elapsed();
+            if elapsed < self.min_request_interval {
+                thread::sleep(self.min_request_interval - elapsed);
+            }
+        }
+        self.last_request.set(Some(Instant::now()));
+    }
+}
+
+impl Default for NominatimProvider {
+    fn default() -> Self {
+        Self::new(URL, MIN_REQUEST_INTERVAL)
+    }
+}
+
+impl ReverseGeocodingProvider for NominatimProvider {
+    fn lookup(&self, coordinates: &Coordinates) -> Result<PlaceName, FetchError> {
+        let latitude = coordinates.latitude.raw().to_string();
+        let longitude = coordinates.longitude.raw().to_string();
+        self.wait_for_turn();
+        let response: ReverseResponse = self
+            .client
+            .get(&self.url)
+            .query(&[
+                ("lat", latitude.as_str()),
+                ("lon", longitude.as_str()),
+                ("format", "jsonv2"),
+                ("zoom", "10"),
+                ("accept-language", "en"),
+            ])
+            .send()
+            .map_err(|_| FetchError::ConnectionFailure)?
+            .json()
+            .map_err(|_| FetchError::DecodingFailure)?;
+        response.to_place_name().ok_or(FetchError::NotFound)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct AddressResponse {
+    city: Option<String>,
+    town: Option<String>,
+    village: Option<String>,
+    municipality: Option<String>,
+    county: Option<String>,
+    state: Option<String>,
+    country: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ReverseResponse {
+    address: Option<AddressResponse>,
+}
+
+impl ReverseResponse {
+    fn to_place_name(&self) -> Option<PlaceName> {
+        let address = self.address.as_ref()?;
+        let name = [
+            &address.city,
+            &address.town,
+            &address.village,
+            &address.municipality,
+            &address.county,
+            &address.state,
+        ]
+        .into_iter()
+        .find_map(Option::clone)?;
+        Some(PlaceName {
+            name,
+            country: address.country.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::test_server::{TestServer, make_unreachable_url};
+
+    #[test]
+    fn decodes_city_and_country() {
+        let json = r#"{
+            "place_id": 123,
+            "display_name": "Warsaw, Masovian Voivodeship, Poland",
+            "address": {
+                "city": "Warsaw",
+                "state": "Masovian Voivodeship",
+                "country": "Poland",
+                "country_code": "pl"
+            }
+        }"#;
+        let response: ReverseResponse = serde_json::from_str(json).unwrap();
+        let expected = PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        };
+        assert_eq!(response.to_place_name(), Some(expected));
+    }
+
+    #[test]
+    fn falls_back_to_smaller_settlements() {
+        let json = r#"{"address": {"village": "Zakopane", "county": "Tatra County"}}"#;
+        let response: ReverseResponse = serde_json::from_str(json).unwrap();
+        let expected = PlaceName {
+            name: "Zakopane".into(),
+            country: None,
+        };
+        assert_eq!(response.to_place_name(), Some(expected));
+    }
+
+    #[test]
+    fn looks_up_place_name() {
+        let server = TestServer::serve(
+            200,
+            r#"{"address": {"city": "Warsaw", "country": "Poland"}}"#,
+        );
+        let sut = NominatimProvider::new(format!("{}/reverse", server.url), Duration::ZERO);
+        let expected = PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        };
+        assert_eq!(
+            sut.lookup(&Coordinates::new(52.2297, 21.0122)),
+            Ok(expected)
+        );
+        assert_eq!(
+            server.received_request(),
+            "GET /reverse?lat=52.2297&lon=21.0122&format=jsonv2&zoom=10&accept-language=en HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let server = TestServer::serve(200, r#"{"address": {"town": "Zakopane"}}"#);
+        let interval = Duration::from_millis(300);
+        let sut = NominatimProvider::new(format!("{}/reverse", server.url), interval);
+        let start = Instant::now();
+        assert!(sut.lookup(&Coordinates::new(49.2992, 19.9496)).is_ok());
+        assert!(sut.lookup(&Coordinates::new(49.2992, 19.9496)).is_ok());
+        assert!(start.elapsed() >= interval);
+    }
+
+    #[test]
+    fn fails_when_service_is_unreachable() {
+        let sut = NominatimProvider::new(make_unreachable_url(), Duration::ZERO);
+        assert_eq!(
+            sut.lookup(&Coordinates::new(52.2297, 21.0122)),
+            Err(FetchError::ConnectionFailure)
+        );
+    }
+
+    #[test]
+    fn decodes_response_without_address() {
+        let response: ReverseResponse =
+            serde_json::from_str(r#"{"error": "Unable to geocode"}"#).unwrap();
+        assert_eq!(response.to_place_name(), None);
+    }
+}
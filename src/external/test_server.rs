@@ -0,0 +1,50 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+pub struct TestServer {
+    pub url: String,
+    requests: Receiver<String>,
+}
+
+impl TestServer {
+    pub fn serve(status: u16, body: &str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind test server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status} Test\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let (sender, requests) = mpsc::channel();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    return;
+                };
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                let _ = reader.read_line(&mut request_line);
+                let mut header = String::new();
+                while reader.read_line(&mut header).is_ok_and(|count| count > 2) {
+                    header.clear();
+                }
+                let _ = stream.write_all(response.as_bytes());
+                if sender.send(request_line.trim_end().to_owned()).is_err() {
+                    return;
+                }
+            }
+        });
+        Self { url, requests }
+    }
+
+    pub fn received_request(&self) -> String {
+        self.requests.recv().expect("No request received")
+    }
+}
+
+pub fn make_unreachable_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind test server");
+    format!("http://{}", listener.local_addr().unwrap())
+}
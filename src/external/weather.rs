@@ -1,85 +1,448 @@
 mod connection;
+mod normals;
 mod query;
 mod response;
 
 use crate::port::weather::WeatherProvider;
-use crate::port::weather::{ReportRequest, RequestKind};
+use crate::port::weather::{
+    BatchReportRequest, ReportRequest, RequestKind, TemperatureUnit, Units,
+};
 use crate::types::attributes::*;
 use crate::types::error::FetchError;
 use crate::types::report::*;
+use crate::types::timezone::Timezone;
+use crate::types::units::{Coordinates, DayCount, Meters};
+use chrono::Days;
+use serde::de::DeserializeOwned;
 use strum::IntoEnumIterator;
 
-#[derive(Default)]
+const MAX_FORECAST_DAYS: DayCount = 16;
+const MAX_FORECAST_PAST_DAYS: DayCount = 92;
+
 pub struct ConcreteWeatherProvider {
-    client: connection::Client,
+    forecast: connection::Client,
+    archive: connection::Client,
 }
 
-impl WeatherProvider for ConcreteWeatherProvider {
-    fn fetch(&self, request: &ReportRequest) -> Result<Report, FetchError> {
-        match &request.kind {
+impl ConcreteWeatherProvider {
+    pub fn new(forecast_url: impl Into<String>, archive_url: impl Into<String>) -> Self {
+        Self {
+            forecast: connection::Client::new(forecast_url),
+            archive: connection::Client::new(archive_url),
+        }
+    }
+
+    fn fetch_responses<R: DeserializeOwned>(
+        client: &connection::Client,
+        mut params: connection::Params,
+        elevation: Option<Meters>,
+        expected_count: usize,
+    ) -> Result<Vec<R>, FetchError> {
+        query::append_elevation(&mut params, elevation, expected_count);
+        let responses = client
+            .fetch_response::<response::BatchResponse<R>>(&params)?
+            .into_vec();
+        if responses.len() != expected_count {
+            return Err(FetchError::DecodingFailure);
+        }
+        Ok(responses)
+    }
+
+    /// Fetches selected attributes, or all of them together with UV index when none are selected
+    fn fetch_past_responses(
+        &self,
+        coordinates: &[Coordinates],
+        elevation: Option<Meters>,
+        timezone: &Timezone,
+        day_count: DayCount,
+        selected_attributes: Option<&WeatherAttributeSet>,
+        units: &Units,
+    ) -> Result<Vec<response::DailyResponse>, FetchError> {
+        let count = coordinates.len();
+        let recent_count = day_count.min(MAX_FORECAST_PAST_DAYS);
+        let all_attributes: WeatherAttributeSet = WeatherAttribute::iter().collect();
+        let attributes = selected_attributes.unwrap_or(&all_attributes);
+        let mut params =
+            query::build_past_params(coordinates, recent_count, attributes, timezone, units)?;
+        if selected_attributes.is_none() {
+            query::append_uv_index(&mut params);
+        }
+        let mut responses: Vec<response::DailyResponse> =
+            Self::fetch_responses(&self.forecast, params, elevation, count)?;
+        let archived_count = day_count - recent_count;
+        if archived_count == 0 {
+            return Ok(responses);
+        }
+        let first_recent_date = responses
+            .first()
+            .and_then(response::DailyResponse::first_date)
+            .ok_or(FetchError::DecodingFailure)?;
+        let end = first_recent_date - Days::new(1);
+        let start = first_recent_date - Days::new(archived_count.into());
+        let params =
+            query::build_archive_params(coordinates, start, end, attributes, timezone, units)?;
+        let archived: Vec<response::DailyResponse> =
+            Self::fetch_responses(&self.archive, params, elevation, count)?;
+        for (response, older) in responses.iter_mut().zip(archived) {
+            if older.day_count() != usize::from(archived_count) {
+                return Err(FetchError::DecodingFailure);
+            }
+            response.prepend(older);
+        }
+        Ok(responses)
+    }
+
+    fn attach_normals(
+        &self,
+        reports: &mut [Report],
+        coordinates: &[Coordinates],
+        elevation: Option<Meters>,
+        timezone: &Timezone,
+        unit: &TemperatureUnit,
+    ) -> Result<(), FetchError> {
+        if !reports.iter().any(has_daily_temperatures) {
+            return Ok(());
+        }
+        let params = query::build_normals_params(coordinates, timezone, unit)?;
+        let responses: Vec<normals::NormalsResponse> =
+            Self::fetch_responses(&self.archive, params, elevation, coordinates.len())?;
+        for (report, response) in reports.iter_mut().zip(responses) {
+            let normals = response.to_temperature_normals();
+            match report {
+                Report::PastFull(inner) | Report::ForecastFull(inner) => {
+                    for day in &mut inner.data {
+                        day.temperature_normal = normals.get(&day.date, unit);
+                    }
+                }
+                Report::PastPartial(inner) | Report::ForecastPartial(inner) => {
+                    for day in &mut inner.data {
+                        if day.temperature_range.is_some() {
+                            day.temperature_normal = normals.get(&day.date, unit);
+                        }
+                    }
+                }
+                Report::CurrentFull(_) | Report::CurrentPartial(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn fetch_reports(
+        &self,
+        coordinates: &[Coordinates],
+        elevation: Option<Meters>,
+        timezone: &Timezone,
+        kind: &RequestKind,
+        units: &Units,
+    ) -> Result<Vec<Report>, FetchError> {
+        validate(kind)?;
+        let count = coordinates.len();
+        match kind {
             RequestKind::PastFull(day_count) => {
-                let attributes: WeatherAttributeSet = WeatherAttribute::iter().collect();
-                let params = query::build_past_params(
-                    &request.coordinates,
+                let responses = self.fetch_past_responses(
+                    coordinates,
+                    elevation,
+                    timezone,
                     *day_count,
-                    &attributes,
-                    &request.units,
-                );
-                let resp: response::DailyResponse = self.client.fetch_response(&params)?;
-                let inner = resp.to_daily_full_report(*day_count, &request.units);
-                Ok(Report::PastFull(inner))
+                    None,
+                    units,
+                )?;
+                Ok(responses
+                    .iter()
+                    .zip(coordinates)
+                    .map(|(resp, coordinates)| {
+                        Report::PastFull(resp.to_daily_full_report(coordinates, *day_count, units))
+                    })
+                    .collect())
             }
             RequestKind::PastPartial(day_count, attributes) => {
-                let params = query::build_past_params(
-                    &request.coordinates,
+                let responses = self.fetch_past_responses(
+                    coordinates,
+                    elevation,
+                    timezone,
                     *day_count,
-                    attributes,
-                    &request.units,
-                );
-                let resp: response::DailyResponse = self.client.fetch_response(&params)?;
-                let inner =
-                    resp.to_daily_partial_report(&request.coordinates, *day_count, &request.units);
-                Ok(Report::PastPartial(inner))
+                    Some(attributes),
+                    units,
+                )?;
+                Ok(responses
+                    .iter()
+                    .zip(coordinates)
+                    .map(|(resp, coordinates)| {
+                        Report::PastPartial(resp.to_daily_partial_report(
+                            coordinates,
+                            *day_count,
+                            units,
+                        ))
+                    })
+                    .collect())
             }
             RequestKind::CurrentFull => {
                 let attributes: WeatherAttributeSet = WeatherAttribute::iter().collect();
-                let params =
-                    query::build_current_params(&request.coordinates, &attributes, &request.units);
-                let resp: response::CurrentResponse = self.client.fetch_response(&params)?;
-                let inner = resp.to_current_full_report(&request.units);
-                Ok(Report::CurrentFull(inner))
+                let mut params =
+                    query::build_current_params(coordinates, &attributes, timezone, units)?;
+                query::append_uv_index(&mut params);
+                let responses: Vec<response::CurrentResponse> =
+                    Self::fetch_responses(&self.forecast, params, elevation, count)?;
+                Ok(responses
+                    .iter()
+                    .zip(coordinates)
+                    .map(|(resp, coordinates)| {
+                        Report::CurrentFull(resp.to_current_full_report(coordinates, units))
+                    })
+                    .collect())
             }
             RequestKind::CurrentPartial(attributes) => {
-                let params =
-                    query::build_current_params(&request.coordinates, attributes, &request.units);
-                let resp: response::CurrentResponse = self.client.fetch_response(&params)?;
-                let inner = resp.to_current_partial_report(&request.coordinates, &request.units);
-                Ok(Report::CurrentPartial(inner))
+                let params = query::build_current_params(coordinates, attributes, timezone, units)?;
+                let responses: Vec<response::CurrentResponse> =
+                    Self::fetch_responses(&self.forecast, params, elevation, count)?;
+                Ok(responses
+                    .iter()
+                    .zip(coordinates)
+                    .map(|(resp, coordinates)| {
+                        Report::CurrentPartial(resp.to_current_partial_report(coordinates, units))
+                    })
+                    .collect())
             }
             RequestKind::ForecastFull(day_count) => {
                 let attributes: WeatherAttributeSet = WeatherAttribute::iter().collect();
-                let params = query::build_forecast_params(
-                    &request.coordinates,
+                let mut params = query::build_forecast_params(
+                    coordinates,
                     *day_count,
                     &attributes,
-                    &request.units,
-                );
-                let resp: response::DailyResponse = self.client.fetch_response(&params)?;
-                let inner = resp.to_daily_full_report(*day_count, &request.units);
-                Ok(Report::ForecastFull(inner))
+                    timezone,
+                    units,
+                )?;
+                query::append_uv_index(&mut params);
+                let responses: Vec<response::DailyResponse> =
+                    Self::fetch_responses(&self.forecast, params, elevation, count)?;
+                Ok(responses
+                    .iter()
+                    .zip(coordinates)
+                    .map(|(resp, coordinates)| {
+                        Report::ForecastFull(resp.to_daily_full_report(
+                            coordinates,
+                            *day_count,
+                            units,
+                        ))
+                    })
+                    .collect())
             }
             RequestKind::ForecastPartial(day_count, attributes) => {
                 let params = query::build_forecast_params(
-                    &request.coordinates,
+                    coordinates,
                     *day_count,
                     attributes,
-                    &request.units,
-                );
-                let resp: response::DailyResponse = self.client.fetch_response(&params)?;
-                let inner =
-                    resp.to_daily_partial_report(&request.coordinates, *day_count, &request.units);
-                Ok(Report::ForecastPartial(inner))
+                    timezone,
+                    units,
+                )?;
+                let responses: Vec<response::DailyResponse> =
+                    Self::fetch_responses(&self.forecast, params, elevation, count)?;
+                Ok(responses
+                    .iter()
+                    .zip(coordinates)
+                    .map(|(resp, coordinates)| {
+                        Report::ForecastPartial(resp.to_daily_partial_report(
+                            coordinates,
+                            *day_count,
+                            units,
+                        ))
+                    })
+                    .collect())
             }
         }
     }
 }
+
+impl Default for ConcreteWeatherProvider {
+    fn default() -> Self {
+        Self::new(connection::FORECAST_URL, connection::ARCHIVE_URL)
+    }
+}
+
+impl WeatherProvider for ConcreteWeatherProvider {
+    fn fetch(&self, request: &ReportRequest) -> Result<Report, FetchError> {
+        let batch_request = BatchReportRequest {
+            coordinates: vec![request.coordinates],
+            elevation: request.elevation,
+            timezone: request.timezone.clone(),
+            include_normals: request.include_normals,
+            kind: request.kind.clone(),
+            units: request.units,
+        };
+        let mut reports = self.fetch_batch(&batch_request)?;
+        reports.pop().ok_or(FetchError::DecodingFailure)
+    }
+
+    fn fetch_batch(&self, request: &BatchReportRequest) -> Result<Vec<Report>, FetchError> {
+        let mut reports = self.fetch_reports(
+            &request.coordinates,
+            request.elevation,
+            &request.timezone,
+            &request.kind,
+            &request.units,
+        )?;
+        if request.include_normals {
+            self.attach_normals(
+                &mut reports,
+                &request.coordinates,
+                request.elevation,
+                &request.timezone,
+                &request.units.temperature,
+            )?;
+        }
+        for report in &mut reports {
+            report.location_mut().requested_elevation = request.elevation;
+        }
+        Ok(reports)
+    }
+}
+
+fn has_daily_temperatures(report: &Report) -> bool {
+    match report {
+        Report::PastFull(_) | Report::ForecastFull(_) => true,
+        Report::PastPartial(inner) | Report::ForecastPartial(inner) => {
+            inner.data.iter().any(|day| day.temperature_range.is_some())
+        }
+        Report::CurrentFull(_) | Report::CurrentPartial(_) => false,
+    }
+}
+
+fn validate(kind: &RequestKind) -> Result<(), FetchError> {
+    match kind {
+        RequestKind::PastFull(0) | RequestKind::PastPartial(0, _) => Err(FetchError::Unsupported(
+            "past weather needs at least 1 day".into(),
+        )),
+        RequestKind::ForecastFull(day_count) | RequestKind::ForecastPartial(day_count, _)
+            if *day_count == 0 || *day_count > MAX_FORECAST_DAYS =>
+        {
+            Err(FetchError::Unsupported(format!(
+                "forecast is available for 1 to {MAX_FORECAST_DAYS} days, requested {day_count}"
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::test_server::{TestServer, make_unreachable_url};
+    use crate::port::weather::SpeedUnit;
+    use crate::port::weather::TemperatureUnit;
+    use crate::types::units::{Date, Temperature};
+
+    fn make_daily_body(first: Date, day_count: u64) -> String {
+        let dates: Vec<String> = (0..day_count)
+            .map(|day| format!("\"{}\"", first + Days::new(day)))
+            .collect();
+        let temperatures = vec!["10.5"; day_count as usize].join(",");
+        format!(
+            r#"{{"daily":{{"time":[{}],"temperature_2m_min":[{temperatures}],"temperature_2m_max":[{temperatures}]}}}}"#,
+            dates.join(",")
+        )
+    }
+
+    fn make_past_request(day_count: DayCount) -> ReportRequest {
+        ReportRequest {
+            coordinates: Coordinates::new(52.23, 21.01),
+            elevation: None,
+            timezone: Timezone::Auto,
+            include_normals: false,
+            kind: RequestKind::PastPartial(
+                day_count,
+                WeatherAttributeSet::from([WeatherAttribute::Temperature]),
+            ),
+            units: Units {
+                temperature: TemperatureUnit::Celsius,
+                speed: SpeedUnit::MetersPerSecond,
+            },
+        }
+    }
+
+    #[test]
+    fn fetches_recent_past_from_forecast_only() {
+        let first = Date::from_ymd_opt(2025, 6, 1).unwrap();
+        let forecast = TestServer::serve(200, &make_daily_body(first, 5));
+        let sut = ConcreteWeatherProvider::new(&forecast.url, make_unreachable_url());
+        let report = sut.fetch(&make_past_request(5)).unwrap();
+        let Report::PastPartial(report) = report else {
+            panic!("Unexpected report kind");
+        };
+        assert_eq!(report.data.len(), 5);
+        assert!(forecast.received_request().contains("past_days=5"));
+    }
+
+    #[test]
+    fn fetches_older_past_from_archive() {
+        let first_recent = Date::from_ymd_opt(2025, 6, 1).unwrap();
+        let first_archived = Date::from_ymd_opt(2025, 5, 29).unwrap();
+        let forecast = TestServer::serve(200, &make_daily_body(first_recent, 92));
+        let archive = TestServer::serve(200, &make_daily_body(first_archived, 3));
+        let sut = ConcreteWeatherProvider::new(&forecast.url, &archive.url);
+        let report = sut.fetch(&make_past_request(95)).unwrap();
+        let Report::PastPartial(report) = report else {
+            panic!("Unexpected report kind");
+        };
+        assert_eq!(report.data.len(), 95);
+        assert_eq!(report.data[0].date, first_archived);
+        assert_eq!(report.data[3].date, first_recent);
+        assert!(forecast.received_request().contains("past_days=92"));
+        let archive_request = archive.received_request();
+        assert!(archive_request.contains("start_date=2025-05-29"));
+        assert!(archive_request.contains("end_date=2025-05-31"));
+    }
+
+    #[test]
+    fn fails_when_archive_returns_other_number_of_days() {
+        let first_recent = Date::from_ymd_opt(2025, 6, 1).unwrap();
+        let first_archived = Date::from_ymd_opt(2025, 5, 30).unwrap();
+        let forecast = TestServer::serve(200, &make_daily_body(first_recent, 92));
+        let archive = TestServer::serve(200, &make_daily_body(first_archived, 2));
+        let sut = ConcreteWeatherProvider::new(&forecast.url, &archive.url);
+        let result = sut.fetch(&make_past_request(95));
+        assert_eq!(result, Err(FetchError::DecodingFailure));
+    }
+
+    #[test]
+    fn attaches_temperature_normals_from_archive() {
+        let first = Date::from_ymd_opt(2025, 6, 1).unwrap();
+        let forecast = TestServer::serve(200, &make_daily_body(first, 2));
+        let archive = TestServer::serve(
+            200,
+            r#"{"daily":{"time":["1991-06-01","1992-06-01","1991-06-02"],"temperature_2m_mean":[8.0,9.0,7.5]}}"#,
+        );
+        let sut = ConcreteWeatherProvider::new(&forecast.url, &archive.url);
+        let mut request = make_past_request(2);
+        request.include_normals = true;
+        let Report::PastPartial(report) = sut.fetch(&request).unwrap() else {
+            panic!("Unexpected report kind");
+        };
+        let normals: Vec<Option<Temperature>> = report
+            .data
+            .iter()
+            .map(|day| day.temperature_normal)
+            .collect();
+        assert_eq!(
+            normals,
+            [
+                Some(Temperature::new_celsius(8.5)),
+                Some(Temperature::new_celsius(7.5))
+            ]
+        );
+        assert!(archive.received_request().contains("temperature_2m_mean"));
+    }
+
+    #[test]
+    fn rejects_forecast_beyond_supported_range() {
+        let sut = ConcreteWeatherProvider::new(make_unreachable_url(), make_unreachable_url());
+        let mut request = make_past_request(1);
+        request.kind = RequestKind::ForecastFull(17);
+        let result = sut.fetch(&request);
+        assert_eq!(
+            result,
+            Err(FetchError::Unsupported(
+                "forecast is available for 1 to 16 days, requested 17".into()
+            ))
+        );
+    }
+}
@@ -1,21 +1,69 @@
 use crate::types::error::FetchError;
+use reqwest::StatusCode;
+use serde::Deserialize;
 use serde::de::DeserializeOwned;
 
 pub type Params = Vec<(String, String)>;
 
-#[derive(Default)]
+pub const FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
+pub const ARCHIVE_URL: &str = "https://archive-api.open-meteo.com/v1/archive";
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    reason: String,
+}
+
 pub struct Client {
     client: reqwest::blocking::Client,
+    url: String,
 }
 
 impl Client {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::blocking::Client::new(),
+            url: url.into(),
+        }
+    }
+
     pub fn fetch_response<R: DeserializeOwned>(&self, params: &Params) -> Result<R, FetchError> {
-        self.client
-            .get("https://api.open-meteo.com/v1/forecast")
+        let response = self
+            .client
+            .get(&self.url)
             .query(&params)
             .send()
-            .map_err(|_| FetchError::ConnectionFailure)?
-            .json()
-            .map_err(|_| FetchError::DecodingFailure)
+            .map_err(|_| FetchError::ConnectionFailure)?;
+        if response.status() == StatusCode::BAD_REQUEST {
+            let error: ErrorResponse = response.json().map_err(|_| FetchError::DecodingFailure)?;
+            return Err(FetchError::Rejected(error.reason));
+        }
+        response.json().map_err(|_| FetchError::DecodingFailure)
+    }
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new(FORECAST_URL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::external::test_server::TestServer;
+
+    #[test]
+    fn passes_reason_of_rejected_request() {
+        let server = TestServer::serve(
+            400,
+            r#"{"error":true,"reason":"Parameter 'start_date' is out of allowed range"}"#,
+        );
+        let result = Client::new(&server.url).fetch_response::<serde_json::Value>(&Params::new());
+        assert_eq!(
+            result,
+            Err(FetchError::Rejected(
+                "Parameter 'start_date' is out of allowed range".into()
+            ))
+        );
     }
 }
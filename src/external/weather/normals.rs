@@ -0,0 +1,82 @@
+use crate::port::weather::TemperatureUnit;
+use crate::types::units::*;
+use chrono::Datelike;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Clone, Deserialize, Debug)]
+struct NormalsData {
+    time: Vec<String>,
+    temperature_2m_mean: Vec<Option<f32>>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct NormalsResponse {
+    daily: NormalsData,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TemperatureNormals {
+    means: HashMap<(u32, u32), f32>,
+}
+
+impl TemperatureNormals {
+    pub fn get(&self, date: &Date, unit: &TemperatureUnit) -> Option<Temperature> {
+        let mean = *self.means.get(&(date.month(), date.day()))?;
+        Some(match unit {
+            TemperatureUnit::Celsius => Temperature::new_celsius(mean),
+            TemperatureUnit::Fahrenheit => Temperature::new_fahrenheit(mean),
+        })
+    }
+}
+
+impl NormalsResponse {
+    pub fn to_temperature_normals(&self) -> TemperatureNormals {
+        let mut sums: HashMap<(u32, u32), (f32, u32)> = HashMap::new();
+        let values = self.daily.time.iter().zip(&self.daily.temperature_2m_mean);
+        for (time, value) in values {
+            let (Ok(date), Some(value)) = (Date::parse_from_str(time, "%Y-%m-%d"), value) else {
+                continue;
+            };
+            let (sum, count) = sums.entry((date.month(), date.day())).or_default();
+            *sum += value;
+            *count += 1;
+        }
+        let means = sums
+            .into_iter()
+            .map(|(day, (sum, count))| (day, sum / count as f32))
+            .collect();
+        TemperatureNormals { means }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn averages_temperatures_of_same_calendar_day() {
+        let json = r#"{"daily": {
+            "time": ["1991-03-01", "1991-03-02", "1992-02-29", "1992-03-01", "1993-03-01"],
+            "temperature_2m_mean": [2.0, 5.0, 1.5, 4.0, null]
+        }}"#;
+        let response: NormalsResponse = serde_json::from_str(json).unwrap();
+        let normals = response.to_temperature_normals();
+        let date = |month, day| Date::from_ymd_opt(2025, month, day).unwrap();
+        let unit = TemperatureUnit::Celsius;
+        assert_eq!(
+            normals.get(&date(3, 1), &unit),
+            Some(Temperature::new_celsius(3.0))
+        );
+        assert_eq!(
+            normals.get(&date(3, 2), &unit),
+            Some(Temperature::new_celsius(5.0))
+        );
+        assert_eq!(normals.get(&date(3, 3), &unit), None);
+        let leap_day = Date::from_ymd_opt(2028, 2, 29).unwrap();
+        assert_eq!(
+            normals.get(&leap_day, &TemperatureUnit::Fahrenheit),
+            Some(Temperature::new_fahrenheit(1.5))
+        );
+    }
+}
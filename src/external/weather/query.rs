@@ -1,7 +1,10 @@
 use super::connection::Params;
 use crate::port::weather::*;
 use crate::types::attributes::*;
-use crate::types::units::Coordinates;
+use crate::types::climate::{NORMAL_PERIOD_FIRST_YEAR, NORMAL_PERIOD_LAST_YEAR};
+use crate::types::error::FetchError;
+use crate::types::timezone::Timezone;
+use crate::types::units::{Coordinates, Date, Meters};
 
 mod keys {
     pub const LATITUDE: &str = "latitude";
@@ -9,12 +12,20 @@ mod keys {
     pub const DAILY: &str = "daily";
     pub const CURRENT: &str = "current";
     pub const PAST_DAYS: &str = "past_days";
+    pub const START_DATE: &str = "start_date";
+    pub const END_DATE: &str = "end_date";
     pub const FORECAST_DAYS: &str = "forecast_days";
     pub const TIMEZONE: &str = "timezone";
+    pub const ELEVATION: &str = "elevation";
     pub const WIND_SPEED_UNIT: &str = "wind_speed_unit";
     pub const TEMPERATURE_UNIT: &str = "temperature_unit";
 }
 
+mod variables {
+    pub const UV_INDEX: &str = "uv_index";
+    pub const UV_INDEX_MAX: &str = "uv_index_max";
+}
+
 mod values {
     pub const TZ_AUTO: &str = "auto";
     pub const METERS_PER_SECOND: &str = "ms";
@@ -26,66 +37,154 @@ mod values {
 }
 
 pub fn build_past_params(
-    coordinates: &Coordinates,
+    coordinates: &[Coordinates],
     day_count: u8,
     attributes: &WeatherAttributeSet,
+    timezone: &Timezone,
     units: &Units,
-) -> Params {
-    vec![
-        make_param(keys::LATITUDE, coordinates.latitude.raw()),
-        make_param(keys::LONGITUDE, coordinates.longitude.raw()),
+) -> Result<Params, FetchError> {
+    Ok(vec![
+        make_param(keys::LATITUDE, join_latitudes(coordinates)),
+        make_param(keys::LONGITUDE, join_longitudes(coordinates)),
         make_param(keys::DAILY, build_daily_attribute_list(attributes.iter())),
         make_param(keys::PAST_DAYS, day_count),
         make_param(keys::FORECAST_DAYS, 0),
-        make_param(keys::TIMEZONE, values::TZ_AUTO),
+        make_param(keys::TIMEZONE, select_timezone(timezone)?),
+        make_param(keys::WIND_SPEED_UNIT, select_speed_unit(&units.speed)),
+        make_param(
+            keys::TEMPERATURE_UNIT,
+            select_temperature_unit(&units.temperature),
+        ),
+    ])
+}
+
+pub fn build_archive_params(
+    coordinates: &[Coordinates],
+    start: Date,
+    end: Date,
+    attributes: &WeatherAttributeSet,
+    timezone: &Timezone,
+    units: &Units,
+) -> Result<Params, FetchError> {
+    Ok(vec![
+        make_param(keys::LATITUDE, join_latitudes(coordinates)),
+        make_param(keys::LONGITUDE, join_longitudes(coordinates)),
+        make_param(keys::DAILY, build_daily_attribute_list(attributes.iter())),
+        make_param(keys::START_DATE, start.format("%Y-%m-%d")),
+        make_param(keys::END_DATE, end.format("%Y-%m-%d")),
+        make_param(keys::TIMEZONE, select_timezone(timezone)?),
         make_param(keys::WIND_SPEED_UNIT, select_speed_unit(&units.speed)),
         make_param(
             keys::TEMPERATURE_UNIT,
             select_temperature_unit(&units.temperature),
         ),
-    ]
+    ])
+}
+
+pub fn build_normals_params(
+    coordinates: &[Coordinates],
+    timezone: &Timezone,
+    unit: &TemperatureUnit,
+) -> Result<Params, FetchError> {
+    let start = Date::from_ymd_opt(NORMAL_PERIOD_FIRST_YEAR, 1, 1).expect("Invalid period start");
+    let end = Date::from_ymd_opt(NORMAL_PERIOD_LAST_YEAR, 12, 31).expect("Invalid period end");
+    Ok(vec![
+        make_param(keys::LATITUDE, join_latitudes(coordinates)),
+        make_param(keys::LONGITUDE, join_longitudes(coordinates)),
+        make_param(keys::DAILY, "temperature_2m_mean"),
+        make_param(keys::START_DATE, start.format("%Y-%m-%d")),
+        make_param(keys::END_DATE, end.format("%Y-%m-%d")),
+        make_param(keys::TIMEZONE, select_timezone(timezone)?),
+        make_param(keys::TEMPERATURE_UNIT, select_temperature_unit(unit)),
+    ])
 }
 
 pub fn build_current_params(
-    coordinates: &Coordinates,
+    coordinates: &[Coordinates],
     attributes: &WeatherAttributeSet,
+    timezone: &Timezone,
     units: &Units,
-) -> Params {
-    vec![
-        make_param(keys::LATITUDE, coordinates.latitude.raw()),
-        make_param(keys::LONGITUDE, coordinates.longitude.raw()),
+) -> Result<Params, FetchError> {
+    Ok(vec![
+        make_param(keys::LATITUDE, join_latitudes(coordinates)),
+        make_param(keys::LONGITUDE, join_longitudes(coordinates)),
         make_param(
             keys::CURRENT,
             build_current_attribute_list(attributes.iter()),
         ),
-        make_param(keys::TIMEZONE, values::TZ_AUTO),
+        make_param(keys::TIMEZONE, select_timezone(timezone)?),
         make_param(keys::WIND_SPEED_UNIT, select_speed_unit(&units.speed)),
         make_param(
             keys::TEMPERATURE_UNIT,
             select_temperature_unit(&units.temperature),
         ),
-    ]
+    ])
 }
 
 pub fn build_forecast_params(
-    coordinates: &Coordinates,
+    coordinates: &[Coordinates],
     day_count: u8,
     attributes: &WeatherAttributeSet,
+    timezone: &Timezone,
     units: &Units,
-) -> Params {
-    vec![
-        make_param(keys::LATITUDE, coordinates.latitude.raw()),
-        make_param(keys::LONGITUDE, coordinates.longitude.raw()),
+) -> Result<Params, FetchError> {
+    Ok(vec![
+        make_param(keys::LATITUDE, join_latitudes(coordinates)),
+        make_param(keys::LONGITUDE, join_longitudes(coordinates)),
         make_param(keys::DAILY, build_daily_attribute_list(attributes.iter())),
         make_param(keys::PAST_DAYS, 0),
         make_param(keys::FORECAST_DAYS, day_count),
-        make_param(keys::TIMEZONE, values::TZ_AUTO),
+        make_param(keys::TIMEZONE, select_timezone(timezone)?),
         make_param(keys::WIND_SPEED_UNIT, select_speed_unit(&units.speed)),
         make_param(
             keys::TEMPERATURE_UNIT,
             select_temperature_unit(&units.temperature),
         ),
-    ]
+    ])
+}
+
+pub fn append_elevation(params: &mut Params, elevation: Option<Meters>, location_count: usize) {
+    if let Some(elevation) = elevation {
+        let elevations = vec![elevation.value.to_string(); location_count];
+        params.push(make_param(keys::ELEVATION, elevations.join(",")));
+    }
+}
+
+/// Asks the forecast service for UV index, which the archive does not provide
+pub fn append_uv_index(params: &mut Params) {
+    for (key, value) in params.iter_mut() {
+        let variable = match key.as_str() {
+            keys::DAILY => variables::UV_INDEX_MAX,
+            keys::CURRENT => variables::UV_INDEX,
+            _ => continue,
+        };
+        value.push(',');
+        value.push_str(variable);
+    }
+}
+
+fn select_timezone(timezone: &Timezone) -> Result<String, FetchError> {
+    match timezone.to_iana_name() {
+        Ok(Some(name)) => Ok(name),
+        Ok(None) => Ok(values::TZ_AUTO.into()),
+        Err(message) => Err(FetchError::Unsupported(message)),
+    }
+}
+
+fn join_latitudes(coordinates: &[Coordinates]) -> String {
+    let latitudes: Vec<String> = coordinates
+        .iter()
+        .map(|coordinates| coordinates.latitude.raw().to_string())
+        .collect();
+    latitudes.join(",")
+}
+
+fn join_longitudes(coordinates: &[Coordinates]) -> String {
+    let longitudes: Vec<String> = coordinates
+        .iter()
+        .map(|coordinates| coordinates.longitude.raw().to_string())
+        .collect();
+    longitudes.join(",")
 }
 
 fn make_param<T: std::fmt::Display>(key: &str, value: T) -> (String, String) {
@@ -230,6 +329,27 @@ mod tests {
         assert_eq!(select_speed_unit(&SpeedUnit::Knots), values::KNOTS);
     }
 
+    #[test]
+    fn selects_timezone() {
+        assert_eq!(select_timezone(&Timezone::Auto), Ok(values::TZ_AUTO.into()));
+        assert_eq!(
+            select_timezone(&Timezone::Named("Asia/Tokyo".into())),
+            Ok("Asia/Tokyo".into())
+        );
+        let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        assert_eq!(
+            select_timezone(&Timezone::Fixed(offset)),
+            Ok("Etc/GMT-2".into())
+        );
+    }
+
+    #[test]
+    fn rejects_timezone_without_iana_name() {
+        let offset = chrono::FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+        let result = select_timezone(&Timezone::Fixed(offset));
+        assert!(matches!(result, Err(FetchError::Unsupported(_))));
+    }
+
     mod utils {
         use super::*;
 
@@ -259,17 +379,14 @@ mod tests {
 
         pub fn matches(actual: &Params, mut expected: Vec<ParamMatcher>) -> bool {
             for item in actual {
-                let matcher_index = expected.iter().position(|matcher| matcher.matches(&item));
+                let matcher_index = expected.iter().position(|matcher| matcher.matches(item));
                 if let Some(index) = matcher_index {
                     expected.remove(index);
                 } else {
                     return false;
                 }
             }
-            if !expected.is_empty() {
-                return false;
-            }
-            return true;
+            expected.is_empty()
         }
     }
 
@@ -283,7 +400,14 @@ mod tests {
             temperature: TemperatureUnit::Celsius,
             speed: SpeedUnit::MetersPerSecond,
         };
-        let result = build_past_params(&coordinates, day_count, &attributes, &units);
+        let result = build_past_params(
+            &[coordinates],
+            day_count,
+            &attributes,
+            &Timezone::Auto,
+            &units,
+        )
+        .unwrap();
 
         use utils::*;
         let expected = vec![
@@ -299,6 +423,57 @@ mod tests {
         assert!(matches(&result, expected));
     }
 
+    #[test]
+    fn builds_params_for_archive_query() {
+        let coordinates = Coordinates::new(1.23, 45.67);
+        let attributes = WeatherAttributeSet::from([WeatherAttribute::Temperature]);
+        let units = Units {
+            temperature: TemperatureUnit::Fahrenheit,
+            speed: SpeedUnit::Knots,
+        };
+        let result = build_archive_params(
+            &[coordinates],
+            Date::from_ymd_opt(2024, 2, 28).unwrap(),
+            Date::from_ymd_opt(2024, 3, 2).unwrap(),
+            &attributes,
+            &Timezone::Auto,
+            &units,
+        )
+        .unwrap();
+
+        use utils::*;
+        let expected = vec![
+            ParamMatcher::some("latitude", "1.23"),
+            ParamMatcher::some("longitude", "45.67"),
+            ParamMatcher::some("daily", "temperature_2m_min,temperature_2m_max"),
+            ParamMatcher::some("start_date", "2024-02-28"),
+            ParamMatcher::some("end_date", "2024-03-02"),
+            ParamMatcher::some("timezone", "auto"),
+            ParamMatcher::some("wind_speed_unit", "kn"),
+            ParamMatcher::some("temperature_unit", "fahrenheit"),
+        ];
+        assert!(matches(&result, expected));
+    }
+
+    #[test]
+    fn builds_params_for_normals_query() {
+        let coordinates = [Coordinates::new(1.23, 45.67), Coordinates::new(8.9, 10.0)];
+        let result =
+            build_normals_params(&coordinates, &Timezone::Auto, &TemperatureUnit::Celsius).unwrap();
+
+        use utils::*;
+        let expected = vec![
+            ParamMatcher::some("latitude", "1.23,8.9"),
+            ParamMatcher::some("longitude", "45.67,10"),
+            ParamMatcher::some("daily", "temperature_2m_mean"),
+            ParamMatcher::some("start_date", "1991-01-01"),
+            ParamMatcher::some("end_date", "2020-12-31"),
+            ParamMatcher::some("timezone", "auto"),
+            ParamMatcher::some("temperature_unit", "celsius"),
+        ];
+        assert!(matches(&result, expected));
+    }
+
     #[test]
     fn builds_params_for_current_query() {
         let coordinates = Coordinates::new(1.23, 45.67);
@@ -308,7 +483,8 @@ mod tests {
             temperature: TemperatureUnit::Celsius,
             speed: SpeedUnit::MetersPerSecond,
         };
-        let result = build_current_params(&coordinates, &attributes, &units);
+        let result =
+            build_current_params(&[coordinates], &attributes, &Timezone::Auto, &units).unwrap();
 
         use utils::*;
         let expected = vec![
@@ -332,7 +508,14 @@ mod tests {
             temperature: TemperatureUnit::Celsius,
             speed: SpeedUnit::MetersPerSecond,
         };
-        let result = build_forecast_params(&coordinates, day_count, &attributes, &units);
+        let result = build_forecast_params(
+            &[coordinates],
+            day_count,
+            &attributes,
+            &Timezone::Named("Asia/Tokyo".into()),
+            &units,
+        )
+        .unwrap();
 
         use utils::*;
         let expected = vec![
@@ -341,10 +524,65 @@ mod tests {
             ParamMatcher::any("daily"),
             ParamMatcher::some("past_days", "0"),
             ParamMatcher::some("forecast_days", "3"),
+            ParamMatcher::some("timezone", "Asia/Tokyo"),
+            ParamMatcher::some("wind_speed_unit", "ms"),
+            ParamMatcher::some("temperature_unit", "celsius"),
+        ];
+        assert!(matches(&result, expected));
+    }
+
+    #[test]
+    fn appends_uv_index_to_daily_and_current_variables() {
+        let attributes = WeatherAttributeSet::from([WeatherAttribute::Temperature]);
+        let units = Units {
+            temperature: TemperatureUnit::Celsius,
+            speed: SpeedUnit::MetersPerSecond,
+        };
+        let mut params = build_forecast_params(
+            &[Coordinates::new(1.23, 45.67)],
+            3,
+            &attributes,
+            &Timezone::Auto,
+            &units,
+        )
+        .unwrap();
+        append_uv_index(&mut params);
+        let daily = params.iter().find(|(key, _)| key == keys::DAILY).unwrap();
+        assert_eq!(
+            daily.1,
+            "temperature_2m_min,temperature_2m_max,uv_index_max"
+        );
+    }
+
+    #[test]
+    fn builds_params_for_batch_query() {
+        let coordinates = [Coordinates::new(1.23, 45.67), Coordinates::new(-8.9, 10.0)];
+        let attributes = WeatherAttributeSet::from([WeatherAttribute::Temperature]);
+        let units = Units {
+            temperature: TemperatureUnit::Celsius,
+            speed: SpeedUnit::MetersPerSecond,
+        };
+        let result =
+            build_current_params(&coordinates, &attributes, &Timezone::Auto, &units).unwrap();
+
+        use utils::*;
+        let expected = vec![
+            ParamMatcher::some("latitude", "1.23,-8.9"),
+            ParamMatcher::some("longitude", "45.67,10"),
+            ParamMatcher::any("current"),
             ParamMatcher::some("timezone", "auto"),
             ParamMatcher::some("wind_speed_unit", "ms"),
             ParamMatcher::some("temperature_unit", "celsius"),
         ];
         assert!(matches(&result, expected));
     }
+
+    #[test]
+    fn appends_elevation_for_each_location() {
+        let mut params = Params::new();
+        append_elevation(&mut params, None, 2);
+        assert!(params.is_empty());
+        append_elevation(&mut params, Some(Meters::from(1780.0)), 2);
+        assert_eq!(params, vec![make_param("elevation", "1780,1780")]);
+    }
 }
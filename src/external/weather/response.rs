@@ -1,7 +1,9 @@
 use crate::port::weather::*;
+use crate::types::location::*;
 use crate::types::report::*;
 use crate::types::units::*;
 use crate::types::weather::*;
+use chrono::{FixedOffset, NaiveDateTime};
 use serde::Deserialize;
 
 #[derive(Clone, Deserialize, Debug)]
@@ -19,9 +21,40 @@ pub struct DailyData {
     wind_direction_10m_dominant: Option<Vec<f32>>,
     pressure_msl_min: Option<Vec<f32>>,
     pressure_msl_max: Option<Vec<f32>>,
+    uv_index_max: Option<Vec<Option<f32>>>,
 }
 
 impl DailyData {
+    fn prepend(&mut self, older: DailyData) {
+        // The archive has no UV index, its days are prepended without one
+        let older_count = older.time.as_ref().map_or(0, Vec::len);
+        let older_uv_index = older
+            .uv_index_max
+            .unwrap_or_else(|| vec![None; older_count]);
+        prepend_values(&mut self.uv_index_max, Some(older_uv_index));
+        prepend_values(&mut self.time, older.time);
+        prepend_values(&mut self.weather_code, older.weather_code);
+        prepend_values(&mut self.temperature_2m_min, older.temperature_2m_min);
+        prepend_values(&mut self.temperature_2m_max, older.temperature_2m_max);
+        prepend_values(&mut self.cloud_cover_min, older.cloud_cover_min);
+        prepend_values(&mut self.cloud_cover_max, older.cloud_cover_max);
+        prepend_values(
+            &mut self.relative_humidity_2m_min,
+            older.relative_humidity_2m_min,
+        );
+        prepend_values(
+            &mut self.relative_humidity_2m_max,
+            older.relative_humidity_2m_max,
+        );
+        prepend_values(&mut self.wind_speed_10m_min, older.wind_speed_10m_min);
+        prepend_values(&mut self.wind_speed_10m_max, older.wind_speed_10m_max);
+        prepend_values(
+            &mut self.wind_direction_10m_dominant,
+            older.wind_direction_10m_dominant,
+        );
+        prepend_values(&mut self.pressure_msl_min, older.pressure_msl_min);
+        prepend_values(&mut self.pressure_msl_max, older.pressure_msl_max);
+    }
     fn date(&self, day_index: usize) -> Option<Date> {
         self.time
             .as_ref()?
@@ -119,6 +152,13 @@ impl DailyData {
             _ => None,
         }
     }
+    fn uv_index(&self, day_index: usize) -> Option<f32> {
+        self.uv_index_max
+            .as_ref()?
+            .get(day_index)
+            .copied()
+            .flatten()
+    }
     fn pressure_range(&self, day_index: usize) -> Option<PressureRange> {
         let min = self
             .pressure_msl_min
@@ -137,11 +177,35 @@ impl DailyData {
 
 #[derive(Clone, Deserialize, Debug)]
 pub struct DailyResponse {
+    elevation: Option<f32>,
+    timezone: Option<String>,
+    utc_offset_seconds: Option<i32>,
     daily: DailyData,
 }
 
 impl DailyResponse {
-    pub fn to_daily_full_report(&self, day_count: DayCount, units: &Units) -> DailyFullReport {
+    pub fn first_date(&self) -> Option<Date> {
+        self.daily
+            .time
+            .as_ref()?
+            .first()
+            .map(|string| convert_date(string))
+    }
+
+    pub fn day_count(&self) -> usize {
+        self.daily.time.as_ref().map_or(0, Vec::len)
+    }
+
+    pub fn prepend(&mut self, older: DailyResponse) {
+        self.daily.prepend(older.daily);
+    }
+
+    pub fn to_daily_full_report(
+        &self,
+        coordinates: &Coordinates,
+        day_count: DayCount,
+        units: &Units,
+    ) -> DailyFullReport {
         let mut data = Vec::new();
         let day_count: usize = day_count.into();
         let daily = &self.daily;
@@ -171,6 +235,8 @@ impl DailyResponse {
                 date,
                 kind,
                 temperature_range,
+                temperature_normal: None,
+                uv_index: daily.uv_index(day_index),
                 cloud_coverage_range,
                 humidity_range,
                 wind,
@@ -179,7 +245,15 @@ impl DailyResponse {
             data.push(daily_data);
         }
         data.sort_by_key(|day| day.date);
-        DailyFullReport { data }
+        DailyFullReport {
+            location: make_location(
+                coordinates,
+                self.elevation,
+                &self.timezone,
+                self.utc_offset_seconds,
+            ),
+            data,
+        }
     }
 
     pub fn to_daily_partial_report(
@@ -199,6 +273,8 @@ impl DailyResponse {
                 date,
                 kind: self.daily.weather_kind(day_index),
                 temperature_range: self.daily.temperature_range(day_index, &units.temperature),
+                temperature_normal: None,
+                uv_index: self.daily.uv_index(day_index),
                 cloud_coverage_range: self.daily.cloud_coverage_range(day_index),
                 humidity_range: self.daily.humidity_range(day_index),
                 wind: self.daily.wind_scope(day_index, &units.speed),
@@ -208,7 +284,12 @@ impl DailyResponse {
         }
         data.sort_by_key(|day| day.date);
         DailyPartialReport {
-            coordinates: *coordinates,
+            location: make_location(
+                coordinates,
+                self.elevation,
+                &self.timezone,
+                self.utc_offset_seconds,
+            ),
             data,
         }
     }
@@ -216,6 +297,7 @@ impl DailyResponse {
 
 #[derive(Clone, Deserialize, Debug)]
 struct CurrentData {
+    time: Option<String>,
     weather_code: Option<u8>,
     temperature_2m: Option<f32>,
     cloud_cover: Option<u8>,
@@ -223,6 +305,7 @@ struct CurrentData {
     wind_speed_10m: Option<f32>,
     wind_direction_10m: Option<f32>,
     pressure_msl: Option<f32>,
+    uv_index: Option<f32>,
 }
 
 impl CurrentData {
@@ -266,11 +349,24 @@ impl CurrentData {
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct CurrentResponse {
+    elevation: Option<f32>,
+    timezone: Option<String>,
+    utc_offset_seconds: Option<i32>,
     current: CurrentData,
 }
 impl CurrentResponse {
-    pub fn to_current_full_report(&self, units: &Units) -> CurrentFullReport {
+    fn time(&self) -> Option<DateTime> {
+        let offset = FixedOffset::east_opt(self.utc_offset_seconds.unwrap_or(0))?;
+        let time = self.current.time.as_ref()?;
+        Some(convert_time(time, offset))
+    }
+    pub fn to_current_full_report(
+        &self,
+        coordinates: &Coordinates,
+        units: &Units,
+    ) -> CurrentFullReport {
         let data = &self.current;
+        let time = self.time().unwrap_or_else(|| panic!("Missing time"));
         let kind = data
             .weather_kind()
             .unwrap_or_else(|| panic!("Missing weather code"));
@@ -290,12 +386,20 @@ impl CurrentResponse {
             .pressure()
             .unwrap_or_else(|| panic!("Missing pressure"));
         CurrentFullReport {
+            location: make_location(
+                coordinates,
+                self.elevation,
+                &self.timezone,
+                self.utc_offset_seconds,
+            ),
+            time,
             kind,
             temperature,
             cloud_coverage,
             humidity,
             wind,
             pressure,
+            uv_index: data.uv_index,
         }
     }
     pub fn to_current_partial_report(
@@ -304,14 +408,61 @@ impl CurrentResponse {
         units: &Units,
     ) -> CurrentPartialReport {
         CurrentPartialReport {
-            coordinates: *coordinates,
+            location: make_location(
+                coordinates,
+                self.elevation,
+                &self.timezone,
+                self.utc_offset_seconds,
+            ),
+            time: self.time(),
             kind: self.current.weather_kind(),
             temperature: self.current.temperature(&units.temperature),
             cloud_coverage: self.current.cloud_coverage(),
             humidity: self.current.humidity(),
             wind: self.current.wind(&units.speed),
             pressure: self.current.pressure(),
+            uv_index: self.current.uv_index,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum BatchResponse<R> {
+    Many(Vec<R>),
+    One(R),
+}
+
+impl<R> BatchResponse<R> {
+    pub fn into_vec(self) -> Vec<R> {
+        match self {
+            Self::Many(responses) => responses,
+            Self::One(response) => vec![response],
+        }
+    }
+}
+
+fn prepend_values<T>(values: &mut Option<Vec<T>>, older: Option<Vec<T>>) {
+    *values = match (values.take(), older) {
+        (Some(recent), Some(mut older)) => {
+            older.extend(recent);
+            Some(older)
         }
+        _ => None,
+    };
+}
+
+fn make_location(
+    coordinates: &Coordinates,
+    elevation: Option<f32>,
+    timezone: &Option<String>,
+    utc_offset_seconds: Option<i32>,
+) -> Location {
+    Location {
+        elevation: elevation.map(Meters::from),
+        timezone: timezone.clone(),
+        utc_offset: utc_offset_seconds.and_then(FixedOffset::east_opt),
+        ..Location::new(*coordinates)
     }
 }
 
@@ -319,6 +470,14 @@ fn convert_date(input: &str) -> Date {
     Date::parse_from_str(input, "%Y-%m-%d").expect("Failed to parse date")
 }
 
+fn convert_time(input: &str, offset: FixedOffset) -> DateTime {
+    NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M")
+        .expect("Failed to parse time")
+        .and_local_timezone(offset)
+        .single()
+        .expect("Ambiguous time")
+}
+
 fn convert_code_to_weather_kind(code: u8) -> Kind {
     match code {
         0 => Kind::Clouds(Clouds::Clear),
@@ -555,6 +714,7 @@ mod tests {
 
     fn generate_current_data() -> CurrentData {
         CurrentData {
+            time: Some("2025-09-18T14:15".into()),
             weather_code: Some(1),
             temperature_2m: Some(12.3),
             cloud_cover: Some(23),
@@ -562,6 +722,7 @@ mod tests {
             wind_speed_10m: Some(1.23),
             wind_direction_10m: Some(90.0),
             pressure_msl: Some(1012.3),
+            uv_index: Some(2.5),
         }
     }
 
@@ -597,6 +758,9 @@ mod tests {
 
     fn generate_current_response() -> CurrentResponse {
         CurrentResponse {
+            elevation: None,
+            timezone: None,
+            utc_offset_seconds: Some(7200),
             current: generate_current_data(),
         }
     }
@@ -611,13 +775,19 @@ mod tests {
 
     #[test]
     fn converts_current_response_to_current_full_report() {
+        let coordinates = Coordinates::new(1.23, 45.67);
         let response = generate_current_response();
         let units = Units {
             temperature: TemperatureUnit::Celsius,
             speed: SpeedUnit::MetersPerSecond,
         };
-        let report = response.to_current_full_report(&units);
+        let report = response.to_current_full_report(&coordinates, &units);
         let expected = CurrentFullReport {
+            location: Location {
+                utc_offset: FixedOffset::east_opt(7200),
+                ..Location::new(coordinates)
+            },
+            time: "2025-09-18T14:15:00+02:00".parse().unwrap(),
             kind: Kind::Clouds(Clouds::Light),
             temperature: Temperature::new_celsius(12.3),
             cloud_coverage: Percentage::from(23),
@@ -627,6 +797,7 @@ mod tests {
                 direction: Azimuth::from(90.0),
             },
             pressure: Pressure::new_hpa(1012.3),
+            uv_index: Some(2.5),
         };
         assert_eq!(report, expected);
     }
@@ -638,7 +809,9 @@ mod tests {
             speed: SpeedUnit::MetersPerSecond,
         };
         let expect_panic = |response: CurrentResponse| {
-            let result = std::panic::catch_unwind(|| response.to_current_full_report(&units));
+            let coordinates = Coordinates::new(1.23, 45.67);
+            let result =
+                std::panic::catch_unwind(|| response.to_current_full_report(&coordinates, &units));
             assert!(result.is_err());
         };
         expect_panic(generate_current_response_without!(weather_code));
@@ -664,13 +837,18 @@ mod tests {
         };
         let report = response.to_current_partial_report(&coordinates, &units);
         let expected = CurrentPartialReport {
-            coordinates,
+            location: Location {
+                utc_offset: FixedOffset::east_opt(7200),
+                ..Location::new(coordinates)
+            },
+            time: Some("2025-09-18T14:15:00+02:00".parse().unwrap()),
             kind: None,
             temperature: Some(Temperature::new_celsius(12.3)),
             cloud_coverage: Some(Percentage::from(23)),
             humidity: None,
             wind: None,
             pressure: Some(Pressure::new_hpa(1012.3)),
+            uv_index: Some(2.5),
         };
         assert_eq!(report, expected);
     }
@@ -694,6 +872,7 @@ mod tests {
             wind_direction_10m_dominant: Some(vec![90.1, 180.2, 270.3]),
             pressure_msl_min: Some(vec![1001.1, 1002.2, 1003.3]),
             pressure_msl_max: Some(vec![1011.1, 1012.2, 1013.3]),
+            uv_index_max: Some(vec![Some(1.5), Some(4.0), None]),
         }
     }
 
@@ -743,6 +922,9 @@ mod tests {
 
     fn generate_daily_response() -> DailyResponse {
         DailyResponse {
+            elevation: None,
+            timezone: None,
+            utc_offset_seconds: None,
             daily: generate_daily_data(),
         }
     }
@@ -750,16 +932,21 @@ mod tests {
     #[test]
     fn converts_daily_response_to_daily_full_report() {
         let response = generate_daily_response();
+        let coordinates = Coordinates::new(1.23, 45.67);
         let units = Units {
             temperature: TemperatureUnit::Celsius,
             speed: SpeedUnit::MetersPerSecond,
         };
-        let report = response.to_daily_full_report(3, &units);
+        let report = response.to_daily_full_report(&coordinates, 3, &units);
+
+        assert_eq!(report.location, Location::new(coordinates));
 
         let expected = DailyFullData {
             date: Date::from_ymd_opt(2025, 9, 1).unwrap(),
             kind: Kind::Clouds(Clouds::Dense),
             temperature_range: TemperatureRange::new_celsius(11.1, 21.1),
+            temperature_normal: None,
+            uv_index: Some(1.5),
             cloud_coverage_range: PercentageRange::new(11, 21),
             humidity_range: PercentageRange::new(31, 41),
             wind: WindScope {
@@ -774,6 +961,8 @@ mod tests {
             date: Date::from_ymd_opt(2025, 9, 2).unwrap(),
             kind: Kind::Clouds(Clouds::Moderate),
             temperature_range: TemperatureRange::new_celsius(12.2, 22.2),
+            temperature_normal: None,
+            uv_index: Some(4.0),
             cloud_coverage_range: PercentageRange::new(12, 22),
             humidity_range: PercentageRange::new(32, 42),
             wind: WindScope {
@@ -788,6 +977,8 @@ mod tests {
             date: Date::from_ymd_opt(2025, 9, 3).unwrap(),
             kind: Kind::Clouds(Clouds::Light),
             temperature_range: TemperatureRange::new_celsius(13.3, 23.3),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: PercentageRange::new(13, 23),
             humidity_range: PercentageRange::new(33, 43),
             wind: WindScope {
@@ -814,7 +1005,9 @@ mod tests {
                 temperature: TemperatureUnit::Celsius,
                 speed: SpeedUnit::MetersPerSecond,
             };
-            let result = std::panic::catch_unwind(|| response.to_daily_full_report(3, &units));
+            let coordinates = Coordinates::new(1.23, 45.67);
+            let result =
+                std::panic::catch_unwind(|| response.to_daily_full_report(&coordinates, 3, &units));
             assert!(result.is_err());
         };
         expect_panic(generate_daily_response_without!(time));
@@ -844,12 +1037,14 @@ mod tests {
         };
         let report = response.to_daily_partial_report(&coordinates, 3, &units);
 
-        assert_eq!(report.coordinates, coordinates);
+        assert_eq!(report.location, Location::new(coordinates));
 
         let expected = DailyPartialData {
             date: Date::from_ymd_opt(2025, 9, 1).unwrap(),
             kind: Some(Kind::Clouds(Clouds::Dense)),
             temperature_range: Some(TemperatureRange::new_celsius(11.1, 21.1)),
+            temperature_normal: None,
+            uv_index: Some(1.5),
             cloud_coverage_range: Some(PercentageRange::new(11, 21)),
             humidity_range: Some(PercentageRange::new(31, 41)),
             wind: Some(WindScope {
@@ -864,6 +1059,8 @@ mod tests {
             date: Date::from_ymd_opt(2025, 9, 2).unwrap(),
             kind: Some(Kind::Clouds(Clouds::Moderate)),
             temperature_range: Some(TemperatureRange::new_celsius(12.2, 22.2)),
+            temperature_normal: None,
+            uv_index: Some(4.0),
             cloud_coverage_range: Some(PercentageRange::new(12, 22)),
             humidity_range: Some(PercentageRange::new(32, 42)),
             wind: Some(WindScope {
@@ -878,6 +1075,8 @@ mod tests {
             date: Date::from_ymd_opt(2025, 9, 3).unwrap(),
             kind: Some(Kind::Clouds(Clouds::Light)),
             temperature_range: Some(TemperatureRange::new_celsius(13.3, 23.3)),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: Some(PercentageRange::new(13, 23)),
             humidity_range: Some(PercentageRange::new(33, 43)),
             wind: Some(WindScope {
@@ -899,6 +1098,7 @@ mod tests {
         response.daily.temperature_2m_max = None;
         response.daily.cloud_cover_min = None;
         response.daily.cloud_cover_max = None;
+        response.daily.uv_index_max = None;
         let coordinates = Coordinates::new(1.23, 45.67);
         let units = Units {
             temperature: TemperatureUnit::Celsius,
@@ -906,12 +1106,14 @@ mod tests {
         };
         let report = response.to_daily_partial_report(&coordinates, 3, &units);
 
-        assert_eq!(report.coordinates, coordinates);
+        assert_eq!(report.location, Location::new(coordinates));
 
         let expected = DailyPartialData {
             date: Date::from_ymd_opt(2025, 9, 1).unwrap(),
             kind: Some(Kind::Clouds(Clouds::Dense)),
             temperature_range: None,
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: None,
             humidity_range: Some(PercentageRange::new(31, 41)),
             wind: None,
@@ -923,6 +1125,8 @@ mod tests {
             date: Date::from_ymd_opt(2025, 9, 2).unwrap(),
             kind: Some(Kind::Clouds(Clouds::Moderate)),
             temperature_range: None,
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: None,
             humidity_range: Some(PercentageRange::new(32, 42)),
             wind: None,
@@ -934,6 +1138,8 @@ mod tests {
             date: Date::from_ymd_opt(2025, 9, 3).unwrap(),
             kind: Some(Kind::Clouds(Clouds::Light)),
             temperature_range: None,
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: None,
             humidity_range: Some(PercentageRange::new(33, 43)),
             wind: None,
@@ -941,4 +1147,79 @@ mod tests {
         };
         assert_eq!(report.data[2], expected);
     }
+
+    #[test]
+    fn prepends_archived_days_without_uv_index() {
+        let mut response = generate_daily_response();
+        let mut older = generate_daily_response();
+        older.daily.time = Some(vec![
+            "2025-08-29".into(),
+            "2025-08-30".into(),
+            "2025-08-31".into(),
+        ]);
+        older.daily.uv_index_max = None;
+        response.prepend(older);
+        let units = Units {
+            temperature: TemperatureUnit::Celsius,
+            speed: SpeedUnit::MetersPerSecond,
+        };
+        let report = response.to_daily_full_report(&Coordinates::new(1.23, 45.67), 6, &units);
+        let uv_indexes: Vec<Option<f32>> = report.data.iter().map(|day| day.uv_index).collect();
+        assert_eq!(uv_indexes, [None, None, None, Some(1.5), Some(4.0), None]);
+    }
+
+    #[test]
+    fn decodes_elevation_and_timezone_into_location_and_time() {
+        let json = r#"{
+            "elevation": 110.0,
+            "timezone": "Europe/Warsaw",
+            "utc_offset_seconds": 7200,
+            "current": {"time": "2025-06-10T23:45", "temperature_2m": 12.3}
+        }"#;
+        let response: CurrentResponse = serde_json::from_str(json).unwrap();
+        let units = Units {
+            temperature: TemperatureUnit::Celsius,
+            speed: SpeedUnit::MetersPerSecond,
+        };
+        let coordinates = Coordinates::new(52.23, 21.01);
+        let report = response.to_current_partial_report(&coordinates, &units);
+        let expected = Location {
+            coordinates,
+            name: None,
+            country: None,
+            elevation: Some(Meters::from(110.0)),
+            requested_elevation: None,
+            timezone: Some("Europe/Warsaw".into()),
+            utc_offset: FixedOffset::east_opt(7200),
+        };
+        assert_eq!(report.location, expected);
+        let today = Date::from_ymd_opt(2025, 6, 10).unwrap();
+        let now = "2025-06-10T21:45:00Z".parse().unwrap();
+        assert_eq!(report.location.today(now), today);
+        assert_eq!(report.time.unwrap().date_naive(), today);
+    }
+
+    #[test]
+    fn decodes_single_response_as_batch() {
+        let json = r#"{"current": {"temperature_2m": 12.3}}"#;
+        let response: BatchResponse<CurrentResponse> = serde_json::from_str(json).unwrap();
+        let responses = response.into_vec();
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].current.temperature_2m, Some(12.3));
+    }
+
+    #[test]
+    fn decodes_array_response_as_batch() {
+        let json = r#"[
+            {"current": {"temperature_2m": 12.3}},
+            {"current": {"temperature_2m": 23.4}}
+        ]"#;
+        let response: BatchResponse<CurrentResponse> = serde_json::from_str(json).unwrap();
+        let temperatures: Vec<Option<f32>> = response
+            .into_vec()
+            .iter()
+            .map(|response| response.current.temperature_2m)
+            .collect();
+        assert_eq!(temperatures, vec![Some(12.3), Some(23.4)]);
+    }
 }
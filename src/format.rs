@@ -1,18 +1,99 @@
+mod activity;
+mod alert;
 mod common;
+mod compare;
+mod csv;
 mod current;
 mod daily;
+mod degree_days;
 mod forecast;
+mod json;
+mod list;
 mod past;
+mod statistics;
+mod summary;
+mod verification;
+mod watch;
 
+pub use activity::ActivityFormatter;
+pub use alert::AlertFormatter;
+pub use compare::ComparisonFormatter;
+pub use csv::CsvFormatter;
+pub use degree_days::DegreeDaysFormatter;
+pub use json::JsonFormatter;
+pub use list::ListFormatter;
+pub use statistics::StatisticsFormatter;
+pub use summary::SummaryFormatter;
+pub use verification::VerificationFormatter;
+pub use watch::{Refresh, draw_screen, highlight_changes};
+
+use crate::external::SystemClock;
+use crate::types::activity::ActivityProfile;
+use crate::types::gear::GearRule;
 use crate::types::report::Report;
+use crate::types::units::Temperature;
+use crate::types::watch::Change;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutputFormat {
+    Summary,
+    List,
+    Json,
+    Csv,
+    Comparison,
+}
+
+/// What is reported about the weather, independently of the output format
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ReportContent {
+    #[default]
+    Weather,
+    /// Weather with what to wear, which only summaries describe
+    WeatherWithGear(Vec<GearRule>),
+    Statistics,
+    DegreeDays(Temperature),
+    Activity(ActivityProfile),
+}
+
+#[mockall::automock]
+pub trait Formatter {
+    fn format(&self, report: &Report) -> String;
+    fn format_many(&self, reports: &[Report]) -> String;
+    /// Formats reports with the changes of each report since the previous refresh, formats
+    /// describing single values mark them with trends
+    fn format_changes(&self, reports: &[Report], changes: &[Vec<Change>]) -> String {
+        let _ = changes;
+        match reports {
+            [report] => self.format(report),
+            _ => self.format_many(reports),
+        }
+    }
+}
+
+/// Formats a single report alone and several reports together
+pub fn format_reports(formatter: &dyn Formatter, reports: &[Report]) -> String {
+    match reports {
+        [report] => formatter.format(report),
+        _ => formatter.format_many(reports),
+    }
+}
 
-pub fn describe(report: &Report) -> String {
-    match report {
-        Report::PastFull(inner) => past::summary::describe(inner),
-        Report::PastPartial(inner) => daily::list::describe(inner),
-        Report::CurrentFull(inner) => current::summary::describe(inner),
-        Report::CurrentPartial(inner) => current::list::describe(inner),
-        Report::ForecastFull(inner) => forecast::summary::describe(inner),
-        Report::ForecastPartial(inner) => daily::list::describe(inner),
+pub fn make_formatter(output_format: OutputFormat, content: ReportContent) -> Box<dyn Formatter> {
+    match (output_format, content) {
+        (OutputFormat::Json, content) => Box::new(JsonFormatter::new(content)),
+        (OutputFormat::Csv, content) => Box::new(CsvFormatter::new(content)),
+        (OutputFormat::Comparison, _) => Box::new(ComparisonFormatter),
+        (_, ReportContent::Statistics) => Box::new(StatisticsFormatter),
+        (_, ReportContent::DegreeDays(base)) => Box::new(DegreeDaysFormatter::new(base)),
+        (_, ReportContent::Activity(profile)) => {
+            Box::new(ActivityFormatter::new(profile, SystemClock))
+        }
+        (OutputFormat::List, _) => Box::new(ListFormatter),
+        (OutputFormat::Summary, ReportContent::Weather) => {
+            Box::new(SummaryFormatter::new(SystemClock))
+        }
+        (OutputFormat::Summary, ReportContent::WeatherWithGear(rules)) => {
+            Box::new(SummaryFormatter::with_gear(SystemClock, rules))
+        }
     }
 }
@@ -0,0 +1,134 @@
+use crate::format::Formatter;
+use crate::format::common::list_builder::write_param;
+use crate::format::common::location::{describe_sections, write_location_param};
+use crate::format::common::relative_date::describe_date;
+use crate::port::clock::Clock;
+use crate::types::activity::{ActivityProfile, MAX_SCORE};
+use crate::types::report::*;
+
+pub struct ActivityFormatter<C: Clock> {
+    profile: ActivityProfile,
+    clock: C,
+}
+
+impl<C: Clock> ActivityFormatter<C> {
+    pub fn new(profile: ActivityProfile, clock: C) -> Self {
+        Self { profile, clock }
+    }
+
+    fn describe(&self, report: &Report) -> String {
+        let name = &self.profile.name;
+        let mut result = String::new();
+        write_param(&mut result, "Activity", name);
+        let (Report::ForecastFull(inner) | Report::PastFull(inner)) = report else {
+            write_param(
+                &mut result,
+                "Error",
+                "scores need all attributes of each day, current or partial reports cannot be scored",
+            );
+            return result;
+        };
+        let today = report.location().today(self.clock.now());
+        for data in &inner.data {
+            write_param(
+                &mut result,
+                describe_date(&data.date, &today),
+                format!("{}/{MAX_SCORE}", self.profile.score(data)),
+            );
+        }
+        if inner.data.len() > 1
+            && let Some((data, score)) = self.profile.best_day(&inner.data)
+        {
+            write_param(
+                &mut result,
+                format!("Best day for {name}"),
+                format!(
+                    "{} ({score}/{MAX_SCORE})",
+                    describe_date(&data.date, &today)
+                ),
+            );
+        }
+        result
+    }
+}
+
+impl<C: Clock> Formatter for ActivityFormatter<C> {
+    fn format(&self, report: &Report) -> String {
+        let mut result = String::new();
+        write_location_param(&mut result, report.location());
+        result + &self.describe(report)
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        describe_sections(reports, |report| self.describe(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::mocks::MockClock;
+    use crate::types::activity::builtin_profiles;
+    use crate::types::units::*;
+    use crate::types::weather::*;
+
+    fn make_formatter(now: &str) -> ActivityFormatter<MockClock> {
+        let mut clock = MockClock::new();
+        clock
+            .expect_now()
+            .return_const(now.parse::<chrono::DateTime<chrono::Utc>>().unwrap());
+        let profile = builtin_profiles()
+            .into_iter()
+            .find(|profile| profile.name == "running")
+            .unwrap();
+        ActivityFormatter::new(profile, clock)
+    }
+
+    fn make_day(day: u32, kind: Kind) -> DailyFullData {
+        DailyFullData {
+            date: Date::from_ymd_opt(2025, 6, day).unwrap(),
+            kind,
+            temperature_range: TemperatureRange::new_celsius(10.0, 16.0),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: PercentageRange::new(0, 20),
+            humidity_range: PercentageRange::new(30, 50),
+            wind: WindScope {
+                speed_range: SpeedRange::new_meters_per_second(0.0, 6.0),
+                dominant_direction: Azimuth::from(180.0),
+            },
+            pressure_range: PressureRange::new_hpa(1010.0, 1015.0),
+        }
+    }
+
+    #[test]
+    fn scores_days_and_picks_best_one() {
+        let report = Report::ForecastFull(DailyFullReport {
+            location: Coordinates::new(52.23, 21.01).into(),
+            data: vec![
+                make_day(2, Kind::Thunderstorm),
+                make_day(3, Kind::Clouds(Clouds::Light)),
+                make_day(4, Kind::Fog(Fog::Normal)),
+            ],
+        });
+        let result = make_formatter("2025-06-02T08:00:00Z").format(&report);
+        let expected = "Coordinates: 52.23000°, 21.01000°\n\
+                        Activity: running\n\
+                        Today: 6/10\n\
+                        Tomorrow: 9/10\n\
+                        On Wednesday: 8/10\n\
+                        Best day for running: Tomorrow (9/10)\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn reports_error_for_reports_without_all_daily_attributes() {
+        let report = Report::CurrentPartial(CurrentPartialReport::new_empty(Coordinates::new(
+            52.23, 21.01,
+        )));
+        let result = make_formatter("2025-06-02T08:00:00Z").format(&report);
+        let expected = "Coordinates: 52.23000°, 21.01000°\n\
+                        Activity: running\n\
+                        Error: scores need all attributes of each day, current or partial reports cannot be scored\n";
+        assert_eq!(result, expected);
+    }
+}
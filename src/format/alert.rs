@@ -0,0 +1,108 @@
+use crate::format::Formatter;
+use crate::format::common::list_builder::write_param;
+use crate::format::common::location::{describe_location, write_location_param};
+use crate::types::condition::*;
+use crate::types::report::Report;
+
+pub struct AlertFormatter {
+    conditions: Vec<Condition>,
+}
+
+impl AlertFormatter {
+    pub fn new(conditions: Vec<Condition>) -> Self {
+        Self { conditions }
+    }
+}
+
+impl Formatter for AlertFormatter {
+    fn format(&self, report: &Report) -> String {
+        let triggers = evaluate(&self.conditions, report);
+        if triggers.is_empty() {
+            return String::new();
+        }
+        let mut result = String::new();
+        write_location_param(&mut result, report.location());
+        describe_triggers(&mut result, &triggers);
+        result
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        let sections: Vec<String> = reports
+            .iter()
+            .filter_map(|report| {
+                let triggers = evaluate(&self.conditions, report);
+                if triggers.is_empty() {
+                    return None;
+                }
+                let mut section = format!("{}:\n", describe_location(report.location()));
+                describe_triggers(&mut section, &triggers);
+                Some(section)
+            })
+            .collect();
+        sections.join("\n")
+    }
+}
+
+fn describe_triggers(result: &mut String, triggers: &[Trigger]) {
+    for trigger in triggers {
+        let extreme = match trigger.condition.comparison {
+            Comparison::Less | Comparison::LessOrEqual => "lowest",
+            Comparison::Greater | Comparison::GreaterOrEqual => "highest",
+        };
+        write_param(
+            result,
+            trigger.date.format("%d.%m.%Y"),
+            format!("{} ({extreme} {})", trigger.condition, trigger.measurement),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::attributes::WeatherAttribute;
+    use crate::types::report::*;
+    use crate::types::units::*;
+
+    fn make_report(latitude: f32, min: f32) -> Report {
+        Report::ForecastPartial(DailyPartialReport {
+            location: Coordinates::new(latitude, 21.0).into(),
+            data: vec![DailyPartialData {
+                date: Date::from_ymd_opt(2025, 1, 2).unwrap(),
+                kind: None,
+                temperature_range: Some(TemperatureRange::new_celsius(min, 4.0)),
+                temperature_normal: None,
+                uv_index: None,
+                cloud_coverage_range: None,
+                humidity_range: None,
+                wind: None,
+                pressure_range: None,
+            }],
+        })
+    }
+
+    fn make_formatter() -> AlertFormatter {
+        AlertFormatter::new(vec![Condition {
+            attribute: WeatherAttribute::Temperature,
+            comparison: Comparison::Less,
+            threshold: 0.0,
+            unit: None,
+        }])
+    }
+
+    #[test]
+    fn lists_triggered_conditions() {
+        let result = make_formatter().format(&make_report(52.0, -3.5));
+        let expected = "Coordinates: 52.00000°, 21.00000°\n\
+                        02.01.2025: temperature<0 (lowest -3.5°C)\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn skips_locations_without_triggered_conditions() {
+        assert_eq!(make_formatter().format(&make_report(52.0, 1.0)), "");
+        let reports = [make_report(52.0, 1.0), make_report(50.0, -1.0)];
+        let expected = "50.0000°, 21.0000°:\n\
+                        02.01.2025: temperature<0 (lowest -1.0°C)\n";
+        assert_eq!(make_formatter().format_many(&reports), expected);
+    }
+}
@@ -1,3 +1,6 @@
 pub mod list_builder;
 pub mod list_format;
+pub mod location;
+pub mod relative_date;
+pub mod statistics;
 pub mod summary;
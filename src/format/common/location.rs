@@ -0,0 +1,123 @@
+use crate::format::common::list_builder::write_param;
+use crate::types::location::Location;
+use crate::types::report::Report;
+
+const ELEVATION_MISMATCH_THRESHOLD_METERS: f32 = 100.0;
+
+pub fn describe_location(location: &Location) -> String {
+    match location.place_name() {
+        Some(place_name) => place_name.to_string(),
+        None => format!("{:.4}", location.coordinates),
+    }
+}
+
+pub fn write_location_param(result: &mut String, location: &Location) {
+    match location.place_name() {
+        Some(place_name) => write_param(result, "Location", place_name),
+        None => write_param(
+            result,
+            "Coordinates",
+            format!("{:.5}", location.coordinates),
+        ),
+    }
+}
+
+pub fn describe_elevation_mismatch(location: &Location) -> Option<String> {
+    let requested = location.requested_elevation?;
+    let model = location.elevation?;
+    let difference = (requested.value - model.value).abs();
+    (difference >= ELEVATION_MISMATCH_THRESHOLD_METERS).then(|| {
+        format!(
+            "requested elevation {requested} differs from model elevation {model} by {difference:.0} m"
+        )
+    })
+}
+
+pub fn describe_sections<F>(reports: &[Report], describe: F) -> String
+where
+    F: Fn(&Report) -> String,
+{
+    join_sections(reports.iter().map(|report| (report, describe(report))))
+}
+
+/// Joins descriptions of reports labeled with their locations
+pub fn join_sections<'a>(sections: impl IntoIterator<Item = (&'a Report, String)>) -> String {
+    let sections: Vec<String> = sections
+        .into_iter()
+        .map(|(report, description)| {
+            let label = describe_location(report.location());
+            format!("{label}:\n{description}")
+        })
+        .collect();
+    sections.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::location::PlaceName;
+    use crate::types::report::CurrentPartialReport;
+    use crate::types::units::{Coordinates, Meters};
+
+    fn make_named_location(name: &str, country: &str) -> Location {
+        let mut location = Location::new(Coordinates::new(52.22977, 21.01178));
+        location.set_place_name(PlaceName {
+            name: name.into(),
+            country: Some(country.into()),
+        });
+        location
+    }
+
+    #[test]
+    fn describes_location_by_coordinates() {
+        let location = Location::new(Coordinates::new(52.22977, 21.01178));
+        assert_eq!(describe_location(&location), "52.2298°, 21.0118°");
+    }
+
+    #[test]
+    fn describes_location_by_place_name() {
+        let location = make_named_location("Warsaw", "Poland");
+        assert_eq!(describe_location(&location), "Warsaw, Poland");
+    }
+
+    #[test]
+    fn writes_location_param() {
+        let mut result = String::new();
+        write_location_param(&mut result, &make_named_location("Warsaw", "Poland"));
+        assert_eq!(result, "Location: Warsaw, Poland\n");
+
+        let mut result = String::new();
+        let location = Location::new(Coordinates::new(52.2297, 21.0122));
+        write_location_param(&mut result, &location);
+        assert_eq!(result, "Coordinates: 52.22970°, 21.01220°\n");
+    }
+
+    #[test]
+    fn separates_sections_of_each_location() {
+        let reports = [
+            Report::CurrentPartial(CurrentPartialReport::new_empty(Coordinates::new(1.0, 2.0))),
+            Report::CurrentPartial(CurrentPartialReport::new_empty(make_named_location(
+                "Warsaw", "Poland",
+            ))),
+        ];
+        let result = describe_sections(&reports, |_| "Report.\n".into());
+        let expected = "1.0000°, 2.0000°:\nReport.\n\nWarsaw, Poland:\nReport.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn describes_large_elevation_mismatch() {
+        let mut location = Location::new(Coordinates::new(49.2, 19.9));
+        location.requested_elevation = Some(Meters::from(1780.0));
+        location.elevation = Some(Meters::from(1250.0));
+        assert_eq!(
+            describe_elevation_mismatch(&location),
+            Some("requested elevation 1780 m differs from model elevation 1250 m by 530 m".into())
+        );
+        location.elevation = Some(Meters::from(1700.0));
+        assert_eq!(describe_elevation_mismatch(&location), None);
+        location.requested_elevation = None;
+        location.elevation = Some(Meters::from(250.0));
+        assert_eq!(describe_elevation_mismatch(&location), None);
+    }
+}
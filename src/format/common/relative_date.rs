@@ -0,0 +1,45 @@
+use crate::types::units::Date;
+
+const DAYS_IN_WEEK: i64 = 7;
+
+pub fn describe_date(date: &Date, today: &Date) -> String {
+    match (*date - *today).num_days() {
+        0 => "Today".into(),
+        1 => "Tomorrow".into(),
+        -1 => "Yesterday".into(),
+        days if days.abs() < DAYS_IN_WEEK => format!("On {}", date.format("%A")),
+        _ => format!("On {}", date.format("%d.%m.%Y")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_date(day: u32) -> Date {
+        Date::from_ymd_opt(2025, 8, day).unwrap()
+    }
+
+    #[test]
+    fn describes_neighbouring_days() {
+        let today = make_date(25);
+        assert_eq!(describe_date(&make_date(25), &today), "Today");
+        assert_eq!(describe_date(&make_date(26), &today), "Tomorrow");
+        assert_eq!(describe_date(&make_date(24), &today), "Yesterday");
+    }
+
+    #[test]
+    fn describes_days_within_week_by_weekday() {
+        let today = make_date(25);
+        assert_eq!(describe_date(&make_date(28), &today), "On Thursday");
+        assert_eq!(describe_date(&make_date(31), &today), "On Sunday");
+        assert_eq!(describe_date(&make_date(19), &today), "On Tuesday");
+    }
+
+    #[test]
+    fn describes_distant_days_by_date() {
+        let today = make_date(25);
+        assert_eq!(describe_date(&make_date(18), &today), "On 18.08.2025");
+        assert_eq!(describe_date(&make_date(1), &today), "On 01.08.2025");
+    }
+}
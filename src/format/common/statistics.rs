@@ -0,0 +1,14 @@
+use crate::types::report::Report;
+use crate::types::statistics::{DailyStatistics, PressureTrend};
+
+pub fn compute_statistics(report: &Report) -> Option<DailyStatistics> {
+    DailyStatistics::new(&report.to_daily_partial()?)
+}
+
+pub fn describe_pressure_trend(trend: PressureTrend) -> &'static str {
+    match trend {
+        PressureTrend::Rising => "rising",
+        PressureTrend::Falling => "falling",
+        PressureTrend::Steady => "steady",
+    }
+}
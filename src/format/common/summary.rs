@@ -1,3 +1,4 @@
+use crate::types::climate::{NORMAL_PERIOD_YEARS, temperature_anomaly};
 use crate::types::units::*;
 use crate::types::weather::*;
 
@@ -156,10 +157,202 @@ pub fn describe_pressure_adjective(pressure: &Pressure) -> String {
     }
 }
 
+pub fn describe_temperature_anomaly(range: &TemperatureRange, normal: &Temperature) -> String {
+    const NEGLIGIBLE_DIFFERENCE: f32 = 0.5;
+
+    let anomaly = temperature_anomaly(range, normal);
+    let difference = anomaly.value();
+    if difference.abs() < NEGLIGIBLE_DIFFERENCE {
+        return format!("close to the {NORMAL_PERIOD_YEARS}-year average");
+    }
+    let direction = if difference > 0.0 { "above" } else { "below" };
+    format!(
+        "{:.1}{} {direction} the {NORMAL_PERIOD_YEARS}-year average",
+        difference.abs(),
+        anomaly.unit_symbol()
+    )
+}
+
+pub fn add_temperature_anomaly(
+    description: String,
+    range: &TemperatureRange,
+    normal: Option<&Temperature>,
+) -> String {
+    match normal {
+        Some(normal) => format!(
+            "{description}, {}",
+            describe_temperature_anomaly(range, normal)
+        ),
+        None => description,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tense {
+    Past,
+    Future,
+}
+
+#[derive(Debug)]
+pub struct DayDescription {
+    pub temperature: Option<String>,
+    pub kind: Option<String>,
+    pub cloud_coverage: Option<String>,
+    pub humidity: Option<String>,
+    pub wind: Option<String>,
+    pub pressure: Option<String>,
+    pub gear: Option<String>,
+}
+
+pub fn describe_day_sentences(
+    date_desc: &str,
+    description: DayDescription,
+    tense: Tense,
+) -> String {
+    let sentences: Vec<String> = [
+        description.temperature,
+        describe_daily_sky(description.kind, description.cloud_coverage),
+        describe_daily_air(description.humidity, description.wind, tense),
+        description.pressure,
+        description.gear,
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let mut result = String::new();
+    for (index, sentence) in sentences.iter().enumerate() {
+        if index == 0 {
+            result.push_str(&format!("{date_desc} {}.\n", decapitalize(sentence)));
+        } else {
+            result.push_str(&format!("{}.\n", capitalize(sentence)));
+        }
+    }
+    result
+}
+
+fn describe_daily_sky(
+    kind_desc: Option<String>,
+    cloud_coverage_desc: Option<String>,
+) -> Option<String> {
+    match (kind_desc, cloud_coverage_desc) {
+        (None, None) => None,
+        (Some(kind), None) => Some(kind),
+        (None, Some(cloud_coverage)) => Some(cloud_coverage),
+        (Some(kind), Some(cloud_coverage)) => Some(format!("{kind} and {cloud_coverage}")),
+    }
+}
+
+fn describe_daily_air(
+    humidity_desc: Option<String>,
+    wind_desc: Option<String>,
+    tense: Tense,
+) -> Option<String> {
+    let there_is = match tense {
+        Tense::Past => "there was",
+        Tense::Future => "there will be",
+    };
+    match (humidity_desc, wind_desc) {
+        (None, None) => None,
+        (Some(humidity), None) => Some(humidity),
+        (None, Some(wind)) => Some(format!("{there_is} {wind}")),
+        (Some(humidity), Some(wind)) => Some(format!("{humidity} with {wind}")),
+    }
+}
+
+pub fn describe_gear(items: &[&str]) -> Option<String> {
+    let (last, rest) = items.split_last()?;
+    let list = if rest.is_empty() {
+        last.to_string()
+    } else {
+        format!("{} and {last}", rest.join(", "))
+    };
+    Some(format!("What to wear: {list}"))
+}
+
+pub fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+pub fn decapitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn describes_temperature_anomaly() {
+        let range = TemperatureRange::new_celsius(10.0, 20.0);
+        assert_eq!(
+            describe_temperature_anomaly(&range, &Temperature::new_celsius(11.8)),
+            "3.2°C above the 30-year average"
+        );
+        assert_eq!(
+            describe_temperature_anomaly(&range, &Temperature::new_celsius(17.5)),
+            "2.5°C below the 30-year average"
+        );
+        assert_eq!(
+            describe_temperature_anomaly(&range, &Temperature::new_celsius(15.3)),
+            "close to the 30-year average"
+        );
+    }
+
+    #[test]
+    fn describes_daily_air_with_selected_attributes() {
+        let result = describe_daily_air(
+            Some("The air will be humid at 30% to 60%".into()),
+            None,
+            Tense::Future,
+        );
+        assert_eq!(result, Some("The air will be humid at 30% to 60%".into()));
+
+        let result = describe_daily_air(None, Some("mostly no wind".into()), Tense::Future);
+        assert_eq!(result, Some("there will be mostly no wind".into()));
+
+        let result = describe_daily_air(None, Some("mostly no wind".into()), Tense::Past);
+        assert_eq!(result, Some("there was mostly no wind".into()));
+
+        let result = describe_daily_air(None, None, Tense::Past);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn describes_gear_as_list() {
+        assert_eq!(describe_gear(&[]), None);
+        assert_eq!(
+            describe_gear(&["an umbrella"]),
+            Some("What to wear: an umbrella".into())
+        );
+        assert_eq!(
+            describe_gear(&["warm layers", "a hat and gloves", "ice grips"]),
+            Some("What to wear: warm layers, a hat and gloves and ice grips".into())
+        );
+    }
+
+    #[test]
+    fn capitalizes_text() {
+        assert_eq!(capitalize("the sky is clear"), "The sky is clear");
+        assert_eq!(capitalize("The sky is clear"), "The sky is clear");
+        assert_eq!(capitalize(""), "");
+    }
+
+    #[test]
+    fn decapitalizes_text() {
+        assert_eq!(decapitalize("The sky is clear"), "the sky is clear");
+        assert_eq!(decapitalize("the sky is clear"), "the sky is clear");
+        assert_eq!(decapitalize(""), "");
+    }
+
     #[test]
     fn describes_temperature_in_celsius_as_adjectives() {
         let describe = |value| describe_temperature_adjective(&Temperature::new_celsius(value));
@@ -0,0 +1,372 @@
+use crate::format::Formatter;
+use crate::format::common::list_format::describe_kind;
+use crate::format::common::location::describe_location;
+use crate::types::attributes::WeatherAttribute;
+use crate::types::location::Location;
+use crate::types::report::*;
+use strum::IntoEnumIterator;
+
+const WINNER_MARK: &str = " *";
+const COLUMN_GAP: &str = "  ";
+const MISSING_VALUE: &str = "-";
+
+pub struct ComparisonFormatter;
+
+impl Formatter for ComparisonFormatter {
+    fn format(&self, report: &Report) -> String {
+        self.format_many(std::slice::from_ref(report))
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        let current_reports: Vec<CurrentPartialReport> =
+            reports.iter().filter_map(to_current_report).collect();
+        if current_reports.len() == reports.len() {
+            describe_current(&current_reports)
+        } else {
+            let daily_reports: Vec<DailyPartialReport> =
+                reports.iter().filter_map(to_daily_report).collect();
+            describe_daily(&daily_reports)
+        }
+    }
+}
+
+fn to_current_report(report: &Report) -> Option<CurrentPartialReport> {
+    match report {
+        Report::CurrentFull(inner) => Some(inner.clone().into()),
+        Report::CurrentPartial(inner) => Some(inner.clone()),
+        _ => None,
+    }
+}
+
+fn to_daily_report(report: &Report) -> Option<DailyPartialReport> {
+    match report {
+        Report::PastFull(inner) | Report::ForecastFull(inner) => Some(inner.clone().into()),
+        Report::PastPartial(inner) | Report::ForecastPartial(inner) => Some(inner.clone()),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Preference {
+    Highest,
+    Lowest,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Cell {
+    text: String,
+    score: Option<f32>,
+}
+
+impl Cell {
+    fn new(text: String, score: Option<f32>) -> Self {
+        Self { text, score }
+    }
+}
+
+fn describe_attribute(attribute: &WeatherAttribute) -> &'static str {
+    match attribute {
+        WeatherAttribute::WeatherKind => "Weather",
+        WeatherAttribute::Temperature => "Temperature",
+        WeatherAttribute::CloudCoverage => "Cloud coverage",
+        WeatherAttribute::Humidity => "Humidity",
+        WeatherAttribute::Wind => "Wind",
+        WeatherAttribute::Pressure => "Pressure",
+    }
+}
+
+fn select_preference(attribute: &WeatherAttribute) -> Option<(Preference, &'static str)> {
+    match attribute {
+        WeatherAttribute::Temperature => Some((Preference::Highest, "warmest")),
+        WeatherAttribute::CloudCoverage => Some((Preference::Lowest, "clearest")),
+        WeatherAttribute::Humidity => Some((Preference::Lowest, "driest")),
+        WeatherAttribute::Wind => Some((Preference::Highest, "windiest")),
+        WeatherAttribute::WeatherKind | WeatherAttribute::Pressure => None,
+    }
+}
+
+fn make_current_cell(report: &CurrentPartialReport, attribute: &WeatherAttribute) -> Option<Cell> {
+    match attribute {
+        WeatherAttribute::WeatherKind => report
+            .kind
+            .map(|kind| Cell::new(describe_kind(&kind), None)),
+        WeatherAttribute::Temperature => report
+            .temperature
+            .map(|temperature| Cell::new(format!("{temperature:.1}"), Some(temperature.value()))),
+        WeatherAttribute::CloudCoverage => report
+            .cloud_coverage
+            .map(|coverage| Cell::new(format!("{coverage}"), Some(coverage.value.into()))),
+        WeatherAttribute::Humidity => report
+            .humidity
+            .map(|humidity| Cell::new(format!("{humidity}"), Some(humidity.value.into()))),
+        WeatherAttribute::Wind => report.wind.as_ref().map(|wind| {
+            let symbol = wind.direction.to_cardinal_direction().to_symbol();
+            Cell::new(
+                format!("{:.1} {symbol}", wind.speed),
+                Some(wind.speed.value()),
+            )
+        }),
+        WeatherAttribute::Pressure => report
+            .pressure
+            .map(|pressure| Cell::new(format!("{pressure}"), None)),
+    }
+}
+
+fn make_range_cell<T: std::fmt::Display>(min: T, max: T, score: Option<f32>) -> Cell {
+    Cell::new(format!("{min:.1} - {max:.1}"), score)
+}
+
+fn average(min: f32, max: f32) -> f32 {
+    (min + max) / 2.0
+}
+
+fn make_daily_cell(data: &DailyPartialData, attribute: &WeatherAttribute) -> Option<Cell> {
+    match attribute {
+        WeatherAttribute::WeatherKind => {
+            data.kind.map(|kind| Cell::new(describe_kind(&kind), None))
+        }
+        WeatherAttribute::Temperature => data.temperature_range.as_ref().map(|range| {
+            let score = average(range.min().value(), range.max().value());
+            make_range_cell(range.min(), range.max(), Some(score))
+        }),
+        WeatherAttribute::CloudCoverage => data.cloud_coverage_range.as_ref().map(|range| {
+            let score = average(range.min.value.into(), range.max.value.into());
+            make_range_cell(range.min, range.max, Some(score))
+        }),
+        WeatherAttribute::Humidity => data.humidity_range.as_ref().map(|range| {
+            let score = average(range.min.value.into(), range.max.value.into());
+            make_range_cell(range.min, range.max, Some(score))
+        }),
+        WeatherAttribute::Wind => data.wind.as_ref().map(|scope| {
+            let min = scope.speed_range.min();
+            let max = scope.speed_range.max();
+            let symbol = scope.dominant_direction.to_cardinal_direction().to_symbol();
+            let score = average(min.value(), max.value());
+            Cell::new(format!("{min:.1} - {max:.1} {symbol}"), Some(score))
+        }),
+        WeatherAttribute::Pressure => data
+            .pressure_range
+            .as_ref()
+            .map(|range| make_range_cell(range.min(), range.max(), None)),
+    }
+}
+
+fn mark_winners(cells: &mut [Option<Cell>], preference: Preference) {
+    let scores: Vec<f32> = cells
+        .iter()
+        .flatten()
+        .filter_map(|cell| cell.score)
+        .collect();
+    if scores.len() < 2 {
+        return;
+    }
+    let best = match preference {
+        Preference::Highest => scores.iter().copied().fold(f32::MIN, f32::max),
+        Preference::Lowest => scores.iter().copied().fold(f32::MAX, f32::min),
+    };
+    if scores.iter().all(|score| *score == best) {
+        return;
+    }
+    for cell in cells.iter_mut().flatten() {
+        if cell.score == Some(best) {
+            cell.text.push_str(WINNER_MARK);
+        }
+    }
+}
+
+fn make_row(attribute: &WeatherAttribute, mut cells: Vec<Option<Cell>>) -> Option<Vec<String>> {
+    if cells.iter().all(Option::is_none) {
+        return None;
+    }
+    let mut label = describe_attribute(attribute).to_string();
+    if let Some((preference, description)) = select_preference(attribute) {
+        mark_winners(&mut cells, preference);
+        label = format!("{label} ({description})");
+    }
+    let mut row = vec![label];
+    row.extend(cells.into_iter().map(|cell| match cell {
+        Some(cell) => cell.text,
+        None => MISSING_VALUE.into(),
+    }));
+    Some(row)
+}
+
+fn write_table(header: Vec<String>, rows: Vec<Vec<String>>) -> String {
+    let mut widths = vec![0; header.len()];
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        for (width, text) in widths.iter_mut().zip(row) {
+            *width = (*width).max(text.chars().count());
+        }
+    }
+    let mut result = String::new();
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(text, width)| format!("{text:<width$}"))
+            .collect();
+        result.push_str(cells.join(COLUMN_GAP).trim_end());
+        result.push('\n');
+    }
+    result
+}
+
+fn make_header<'a>(locations: impl Iterator<Item = &'a Location>) -> Vec<String> {
+    std::iter::once(String::new())
+        .chain(locations.map(describe_location))
+        .collect()
+}
+
+fn describe_current(reports: &[CurrentPartialReport]) -> String {
+    let header = make_header(reports.iter().map(|report| &report.location));
+    let rows = WeatherAttribute::iter()
+        .filter_map(|attribute| {
+            let cells = reports
+                .iter()
+                .map(|report| make_current_cell(report, &attribute))
+                .collect();
+            make_row(&attribute, cells)
+        })
+        .collect();
+    write_table(header, rows)
+}
+
+fn describe_daily(reports: &[DailyPartialReport]) -> String {
+    let Some(first_report) = reports.first() else {
+        return String::new();
+    };
+    let header = make_header(reports.iter().map(|report| &report.location));
+    let tables: Vec<String> = first_report
+        .data
+        .iter()
+        .map(|first_data| {
+            let date = first_data.date;
+            let rows = WeatherAttribute::iter()
+                .filter_map(|attribute| {
+                    let cells = reports
+                        .iter()
+                        .map(|report| {
+                            report
+                                .data
+                                .iter()
+                                .find(|data| data.date == date)
+                                .and_then(|data| make_daily_cell(data, &attribute))
+                        })
+                        .collect();
+                    make_row(&attribute, cells)
+                })
+                .collect();
+            format!(
+                "Date: {}\n{}",
+                date.format("%d.%m.%Y"),
+                write_table(header.clone(), rows)
+            )
+        })
+        .collect();
+    tables.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::units::*;
+    use crate::types::weather::*;
+
+    fn make_current_report(latitude: f32, temperature: f32, humidity: i8) -> Report {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(latitude, 21.0));
+        report.kind = Some(Kind::Clouds(Clouds::Clear));
+        report.temperature = Some(Temperature::new_celsius(temperature));
+        report.humidity = Some(Percentage::from(humidity));
+        Report::CurrentPartial(report)
+    }
+
+    #[test]
+    fn compares_current_reports_and_marks_winners() {
+        let reports = [
+            make_current_report(52.0, 12.5, 80),
+            make_current_report(50.0, 14.5, 60),
+        ];
+        let result = ComparisonFormatter.format_many(&reports);
+        let expected = "                       52.0000°, 21.0000°  50.0000°, 21.0000°
+Weather                clear sky           clear sky
+Temperature (warmest)  12.5°C              14.5°C *
+Humidity (driest)      80%                 60% *
+";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn does_not_mark_winner_for_equal_values() {
+        let mut cells = vec![
+            Some(Cell::new("1".into(), Some(1.0))),
+            Some(Cell::new("1".into(), Some(1.0))),
+        ];
+        mark_winners(&mut cells, Preference::Highest);
+        assert!(cells.iter().flatten().all(|cell| cell.text == "1"));
+    }
+
+    #[test]
+    fn does_not_mark_winner_for_single_location() {
+        let result = ComparisonFormatter.format(&make_current_report(52.0, 12.5, 80));
+        assert!(!result.contains(WINNER_MARK));
+    }
+
+    #[test]
+    fn fills_missing_values() {
+        let row = make_row(
+            &WeatherAttribute::Humidity,
+            vec![None, Some(Cell::new("60%".into(), Some(60.0)))],
+        );
+        assert_eq!(
+            row,
+            Some(vec![
+                "Humidity (driest)".into(),
+                MISSING_VALUE.into(),
+                "60%".into()
+            ])
+        );
+        assert_eq!(
+            make_row(&WeatherAttribute::Humidity, vec![None, None]),
+            None
+        );
+    }
+
+    #[test]
+    fn compares_daily_reports_day_by_day() {
+        let make_report = |latitude, max_temperatures: [f32; 2]| {
+            let data = max_temperatures
+                .iter()
+                .enumerate()
+                .map(|(index, max)| DailyPartialData {
+                    date: Date::from_ymd_opt(2025, 8, 26 + index as u32).unwrap(),
+                    kind: None,
+                    temperature_range: Some(TemperatureRange::new_celsius(10.0, *max)),
+                    temperature_normal: None,
+                    uv_index: None,
+                    cloud_coverage_range: None,
+                    humidity_range: None,
+                    wind: None,
+                    pressure_range: None,
+                })
+                .collect();
+            Report::ForecastPartial(DailyPartialReport {
+                location: Coordinates::new(latitude, 21.0).into(),
+                data,
+            })
+        };
+        let reports = [
+            make_report(52.0, [20.0, 15.0]),
+            make_report(50.0, [18.0, 16.0]),
+        ];
+        let result = ComparisonFormatter.format_many(&reports);
+        let expected = "\
+Date: 26.08.2025
+                       52.0000°, 21.0000°  50.0000°, 21.0000°
+Temperature (warmest)  10.0°C - 20.0°C *   10.0°C - 18.0°C
+
+Date: 27.08.2025
+                       52.0000°, 21.0000°  50.0000°, 21.0000°
+Temperature (warmest)  10.0°C - 15.0°C     10.0°C - 16.0°C *
+";
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,561 @@
+use crate::format::common::list_format::describe_kind;
+use crate::format::common::location::describe_location;
+use crate::format::common::statistics::{compute_statistics, describe_pressure_trend};
+use crate::format::{Formatter, ReportContent};
+use crate::types::activity::{ActivityProfile, MAX_SCORE};
+use crate::types::climate::temperature_anomaly;
+use crate::types::degree_days::DegreeDays;
+use crate::types::report::*;
+use crate::types::statistics::*;
+use crate::types::units::*;
+
+const SEPARATOR: &str = ",";
+
+pub struct CsvFormatter {
+    content: ReportContent,
+}
+
+impl CsvFormatter {
+    pub fn new(content: ReportContent) -> Self {
+        Self { content }
+    }
+
+    fn make_rows(&self, report: &Report) -> Vec<Row> {
+        match &self.content {
+            ReportContent::Weather | ReportContent::WeatherWithGear(_) => make_weather_rows(report),
+            ReportContent::Statistics => make_statistics_row(report).into_iter().collect(),
+            ReportContent::DegreeDays(base) => make_degree_days_rows(report, *base),
+            ReportContent::Activity(profile) => make_activity_rows(report, profile),
+        }
+    }
+}
+
+impl Formatter for CsvFormatter {
+    fn format(&self, report: &Report) -> String {
+        write_table(&self.make_rows(report))
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        let mut rows = Vec::new();
+        for report in reports {
+            let location = describe_location(report.location());
+            for mut row in self.make_rows(report) {
+                row.insert(0, ("location".into(), location.clone()));
+                rows.push(row);
+            }
+        }
+        write_table(&rows)
+    }
+}
+
+type Row = Vec<(String, String)>;
+
+fn make_weather_rows(report: &Report) -> Vec<Row> {
+    match report {
+        Report::PastFull(inner) => make_daily_rows(&inner.clone().into()),
+        Report::PastPartial(inner) => make_daily_rows(inner),
+        Report::CurrentFull(inner) => vec![make_current_row(&inner.clone().into())],
+        Report::CurrentPartial(inner) => vec![make_current_row(inner)],
+        Report::ForecastFull(inner) => make_daily_rows(&inner.clone().into()),
+        Report::ForecastPartial(inner) => make_daily_rows(inner),
+    }
+}
+
+fn make_daily_rows(report: &DailyPartialReport) -> Vec<Row> {
+    report
+        .data
+        .iter()
+        .map(|data| make_daily_row(&report.location.coordinates, data))
+        .collect()
+}
+
+fn write_table(rows: &[Row]) -> String {
+    let Some(first_row) = rows.first() else {
+        return String::new();
+    };
+    let mut result = String::new();
+    let header: Vec<&str> = first_row.iter().map(|(label, _)| label.as_str()).collect();
+    result.push_str(&header.join(SEPARATOR));
+    result.push('\n');
+    for row in rows {
+        let values: Vec<String> = row.iter().map(|(_, value)| escape(value)).collect();
+        result.push_str(&values.join(SEPARATOR));
+        result.push('\n');
+    }
+    result
+}
+
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.into()
+    }
+}
+
+fn make_label(name: &str, unit: &str) -> String {
+    format!("{name} [{unit}]")
+}
+
+fn push_coordinates(row: &mut Row, coordinates: &Coordinates) {
+    row.push(("latitude".into(), coordinates.latitude.raw().to_string()));
+    row.push(("longitude".into(), coordinates.longitude.raw().to_string()));
+}
+
+fn make_current_row(report: &CurrentPartialReport) -> Row {
+    let mut row = Row::new();
+    push_coordinates(&mut row, &report.location.coordinates);
+    if let Some(time) = &report.time {
+        row.push(("time".into(), time.to_rfc3339()));
+    }
+    if let Some(kind) = &report.kind {
+        row.push(("weather".into(), describe_kind(kind)));
+    }
+    if let Some(temperature) = &report.temperature {
+        row.push((
+            make_label("temperature", temperature.unit_symbol()),
+            temperature.value().to_string(),
+        ));
+    }
+    if let Some(cloud_coverage) = &report.cloud_coverage {
+        row.push((
+            make_label("cloud_coverage", "%"),
+            cloud_coverage.value.to_string(),
+        ));
+    }
+    if let Some(humidity) = &report.humidity {
+        row.push((make_label("humidity", "%"), humidity.value.to_string()));
+    }
+    if let Some(wind) = &report.wind {
+        row.push((
+            make_label("wind_speed", wind.speed.unit_symbol()),
+            wind.speed.value().to_string(),
+        ));
+        row.push((
+            make_label("wind_direction", "°"),
+            f32::from(wind.direction).to_string(),
+        ));
+    }
+    if let Some(pressure) = &report.pressure {
+        row.push((
+            make_label("pressure", pressure.unit_symbol()),
+            pressure.value().to_string(),
+        ));
+    }
+    row
+}
+
+fn make_daily_row(coordinates: &Coordinates, data: &DailyPartialData) -> Row {
+    let mut row = Row::new();
+    push_coordinates(&mut row, coordinates);
+    row.push(("date".into(), data.date.format("%Y-%m-%d").to_string()));
+    if let Some(kind) = &data.kind {
+        row.push(("weather".into(), describe_kind(kind)));
+    }
+    if let Some(range) = &data.temperature_range {
+        let unit = range.min().unit_symbol();
+        row.push((
+            make_label("temperature_min", unit),
+            range.min().value().to_string(),
+        ));
+        row.push((
+            make_label("temperature_max", unit),
+            range.max().value().to_string(),
+        ));
+        if let Some(normal) = &data.temperature_normal {
+            row.push((
+                make_label("temperature_anomaly", unit),
+                format!("{:.1}", temperature_anomaly(range, normal).value()),
+            ));
+        }
+    }
+    if let Some(range) = &data.cloud_coverage_range {
+        row.push((
+            make_label("cloud_coverage_min", "%"),
+            range.min.value.to_string(),
+        ));
+        row.push((
+            make_label("cloud_coverage_max", "%"),
+            range.max.value.to_string(),
+        ));
+    }
+    if let Some(range) = &data.humidity_range {
+        row.push((make_label("humidity_min", "%"), range.min.value.to_string()));
+        row.push((make_label("humidity_max", "%"), range.max.value.to_string()));
+    }
+    if let Some(wind) = &data.wind {
+        let unit = wind.speed_range.min().unit_symbol();
+        row.push((
+            make_label("wind_speed_min", unit),
+            wind.speed_range.min().value().to_string(),
+        ));
+        row.push((
+            make_label("wind_speed_max", unit),
+            wind.speed_range.max().value().to_string(),
+        ));
+        row.push((
+            make_label("wind_direction", "°"),
+            f32::from(wind.dominant_direction).to_string(),
+        ));
+    }
+    if let Some(range) = &data.pressure_range {
+        let unit = range.min().unit_symbol();
+        row.push((
+            make_label("pressure_min", unit),
+            range.min().value().to_string(),
+        ));
+        row.push((
+            make_label("pressure_max", unit),
+            range.max().value().to_string(),
+        ));
+    }
+    row
+}
+
+fn push_attribute_statistics<T>(
+    row: &mut Row,
+    name: &str,
+    statistics: &AttributeStatistics<T>,
+    value: impl Fn(&T) -> String,
+    unit: &str,
+) {
+    row.push((
+        make_label(&format!("{name}_mean"), unit),
+        value(&statistics.mean),
+    ));
+    for (extreme, suffix) in [(&statistics.min, "min"), (&statistics.max, "max")] {
+        row.push((
+            make_label(&format!("{name}_{suffix}"), unit),
+            value(&extreme.value),
+        ));
+        row.push((
+            format!("{name}_{suffix}_date"),
+            extreme.date.format("%Y-%m-%d").to_string(),
+        ));
+    }
+}
+
+fn make_statistics_row(report: &Report) -> Option<Row> {
+    let statistics = compute_statistics(report)?;
+    let mut row = Row::new();
+    push_coordinates(&mut row, report.coordinates());
+    row.push((
+        "first_date".into(),
+        statistics.first_date.format("%Y-%m-%d").to_string(),
+    ));
+    row.push((
+        "last_date".into(),
+        statistics.last_date.format("%Y-%m-%d").to_string(),
+    ));
+    row.push(("day_count".into(), statistics.day_count.to_string()));
+    if let Some(temperature) = &statistics.temperature {
+        push_attribute_statistics(
+            &mut row,
+            "temperature",
+            temperature,
+            |value| format!("{:.1}", value.value()),
+            temperature.mean.unit_symbol(),
+        );
+    }
+    if let Some(coverage) = &statistics.cloud_coverage {
+        push_attribute_statistics(
+            &mut row,
+            "cloud_coverage",
+            coverage,
+            |value| value.value.to_string(),
+            "%",
+        );
+    }
+    if let Some(humidity) = &statistics.humidity {
+        push_attribute_statistics(
+            &mut row,
+            "humidity",
+            humidity,
+            |value| value.value.to_string(),
+            "%",
+        );
+    }
+    if let Some(speed) = &statistics.wind_speed {
+        push_attribute_statistics(
+            &mut row,
+            "wind_speed",
+            speed,
+            |value| format!("{:.1}", value.value()),
+            speed.mean.unit_symbol(),
+        );
+    }
+    if let Some(direction) = statistics.prevailing_wind_direction {
+        row.push((
+            make_label("prevailing_wind_direction", "°"),
+            format!("{:.1}", f32::from(direction)),
+        ));
+    }
+    if let Some(pressure) = &statistics.pressure {
+        push_attribute_statistics(
+            &mut row,
+            "pressure",
+            pressure,
+            |value| format!("{:.1}", value.value()),
+            pressure.mean.unit_symbol(),
+        );
+    }
+    if let Some(trend) = statistics.pressure_trend {
+        row.push((
+            "pressure_trend".into(),
+            describe_pressure_trend(trend).into(),
+        ));
+    }
+    if let Some(rainy_days) = statistics.rainy_days {
+        row.push(("rainy_days".into(), rainy_days.to_string()));
+    }
+    Some(row)
+}
+
+fn make_degree_days_rows(report: &Report, base: Temperature) -> Vec<Row> {
+    let Some(daily_report) = report.to_daily_partial() else {
+        return Vec::new();
+    };
+    let degree_days = DegreeDays::new(&daily_report, base);
+    let unit = degree_days.base.unit_symbol();
+    degree_days
+        .days
+        .iter()
+        .map(|day| {
+            let mut row = Row::new();
+            push_coordinates(&mut row, report.coordinates());
+            row.push(("date".into(), day.date.format("%Y-%m-%d").to_string()));
+            row.push((
+                make_label("base_temperature", unit),
+                format!("{:.1}", degree_days.base.value()),
+            ));
+            row.push((
+                make_label("temperature_mean", unit),
+                format!("{:.1}", day.mean.value()),
+            ));
+            row.push((
+                make_label("heating_degree_days", unit),
+                format!("{:.1}", day.heating),
+            ));
+            row.push((
+                make_label("cooling_degree_days", unit),
+                format!("{:.1}", day.cooling),
+            ));
+            row
+        })
+        .collect()
+}
+
+fn make_activity_rows(report: &Report, profile: &ActivityProfile) -> Vec<Row> {
+    let (Report::ForecastFull(inner) | Report::PastFull(inner)) = report else {
+        return Vec::new();
+    };
+    inner
+        .data
+        .iter()
+        .map(|data| {
+            let mut row = Row::new();
+            push_coordinates(&mut row, &inner.location.coordinates);
+            row.push(("date".into(), data.date.format("%Y-%m-%d").to_string()));
+            row.push(("activity".into(), profile.name.clone()));
+            row.push(("score".into(), profile.score(data).to_string()));
+            row.push(("max_score".into(), MAX_SCORE.to_string()));
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::activity::builtin_profiles;
+    use crate::types::weather::*;
+
+    #[test]
+    fn formats_current_report_with_all_attributes() {
+        let report = Report::CurrentFull(CurrentFullReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            time: "2025-09-18T14:15:00+02:00".parse().unwrap(),
+            kind: Kind::Clouds(Clouds::Light),
+            temperature: Temperature::new_celsius(22.4),
+            cloud_coverage: Percentage::from(43),
+            humidity: Percentage::from(81),
+            wind: Wind {
+                speed: Speed::new_meters_per_second(1.07),
+                direction: Azimuth::from(155.5),
+            },
+            pressure: Pressure::new_hpa(1009.3),
+            uv_index: None,
+        });
+        let result = CsvFormatter::new(ReportContent::Weather).format(&report);
+        let expected = "latitude,longitude,time,weather,temperature [°C],cloud_coverage [%],\
+                        humidity [%],wind_speed [m/s],wind_direction [°],pressure [hPa]\n\
+                        1.23,45.67,2025-09-18T14:15:00+02:00,light clouds,22.4,43,81,1.07,155.5,1009.3\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_daily_report_with_selected_attributes() {
+        let make_data = |day, min, max| DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, day).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_fahrenheit(min, max)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: Some(WindScope {
+                speed_range: SpeedRange::new_knots(1.5, 3.5),
+                dominant_direction: Azimuth::from(90.0),
+            }),
+            pressure_range: None,
+        };
+        let report = Report::PastPartial(DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![make_data(25, 50.5, 60.5), make_data(26, 52.0, 61.0)],
+        });
+        let result = CsvFormatter::new(ReportContent::Weather).format(&report);
+        let expected = "latitude,longitude,date,temperature_min [°F],temperature_max [°F],\
+                        wind_speed_min [kn],wind_speed_max [kn],wind_direction [°]\n\
+                        1.23,45.67,2025-08-25,50.5,60.5,1.5,3.5,90\n\
+                        1.23,45.67,2025-08-26,52,61,1.5,3.5,90\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_temperature_anomaly_column() {
+        let data = DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, 25).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(10.0, 20.0)),
+            temperature_normal: Some(Temperature::new_celsius(11.8)),
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        let report = Report::ForecastPartial(DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![data],
+        });
+        let result = CsvFormatter::new(ReportContent::Weather).format(&report);
+        let expected = "latitude,longitude,date,temperature_min [°C],temperature_max [°C],\
+                        temperature_anomaly [°C]\n\
+                        1.23,45.67,2025-08-25,10,20,3.2\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn escapes_values_with_separators() {
+        assert_eq!(escape("light rain"), "light rain");
+        assert_eq!(escape("rain, snow"), "\"rain, snow\"");
+        assert_eq!(escape("\"heavy\""), "\"\"\"heavy\"\"\"");
+    }
+
+    #[test]
+    fn formats_reports_of_multiple_locations() {
+        let make_report = |latitude, temperature| {
+            let mut report = CurrentPartialReport::new_empty(Coordinates::new(latitude, 21.0));
+            report.temperature = Some(Temperature::new_celsius(temperature));
+            Report::CurrentPartial(report)
+        };
+        let reports = [make_report(52.0, 12.5), make_report(50.0, 14.5)];
+        let result = CsvFormatter::new(ReportContent::Weather).format_many(&reports);
+        let expected = "location,latitude,longitude,temperature [°C]\n\
+                        \"52.0000°, 21.0000°\",52,21,12.5\n\
+                        \"50.0000°, 21.0000°\",50,21,14.5\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_statistics_row() {
+        let make_day = |day, kind, min, max| DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, day).unwrap(),
+            kind: Some(kind),
+            temperature_range: Some(TemperatureRange::new_celsius(min, max)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        let report = Report::PastPartial(DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![
+                make_day(1, Kind::Thunderstorm, 10.0, 20.0),
+                make_day(2, Kind::Clouds(Clouds::Clear), 8.0, 16.0),
+            ],
+        });
+        let result = CsvFormatter::new(ReportContent::Statistics).format(&report);
+        let expected = "latitude,longitude,first_date,last_date,day_count,\
+                        temperature_mean [°C],temperature_min [°C],temperature_min_date,\
+                        temperature_max [°C],temperature_max_date,rainy_days\n\
+                        1.23,45.67,2025-08-01,2025-08-02,2,13.5,8.0,2025-08-02,20.0,2025-08-01,1\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_degree_days_rows() {
+        let make_day = |day, min, max| DailyPartialData {
+            date: Date::from_ymd_opt(2025, 1, day).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(min, max)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        let report = Report::PastPartial(DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![make_day(1, -2.0, 6.0), make_day(2, 16.0, 30.0)],
+        });
+        let formatter =
+            CsvFormatter::new(ReportContent::DegreeDays(Temperature::new_celsius(18.0)));
+        let expected = "latitude,longitude,date,base_temperature [°C],temperature_mean [°C],\
+                        heating_degree_days [°C],cooling_degree_days [°C]\n\
+                        1.23,45.67,2025-01-01,18.0,2.0,16.0,0.0\n\
+                        1.23,45.67,2025-01-02,18.0,23.0,0.0,5.0\n";
+        assert_eq!(formatter.format(&report), expected);
+    }
+
+    fn make_activity_report() -> (Report, ActivityProfile) {
+        let make_day = |day, kind| DailyFullData {
+            date: Date::from_ymd_opt(2025, 6, day).unwrap(),
+            kind,
+            temperature_range: TemperatureRange::new_celsius(10.0, 16.0),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: PercentageRange::new(0, 20),
+            humidity_range: PercentageRange::new(30, 50),
+            wind: WindScope {
+                speed_range: SpeedRange::new_meters_per_second(0.0, 6.0),
+                dominant_direction: Azimuth::from(180.0),
+            },
+            pressure_range: PressureRange::new_hpa(1010.0, 1015.0),
+        };
+        let report = Report::ForecastFull(DailyFullReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![
+                make_day(2, Kind::Thunderstorm),
+                make_day(3, Kind::Clouds(Clouds::Light)),
+            ],
+        });
+        let profile = builtin_profiles()
+            .into_iter()
+            .find(|profile| profile.name == "running")
+            .unwrap();
+        (report, profile)
+    }
+
+    #[test]
+    fn formats_activity_rows() {
+        let (report, profile) = make_activity_report();
+        let result = CsvFormatter::new(ReportContent::Activity(profile)).format(&report);
+        let expected = "latitude,longitude,date,activity,score,max_score\n\
+                        1.23,45.67,2025-06-02,running,6,10\n\
+                        1.23,45.67,2025-06-03,running,9,10\n";
+        assert_eq!(result, expected);
+    }
+}
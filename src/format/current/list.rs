@@ -1,21 +1,31 @@
 use crate::format::common::list_builder::write_param;
 use crate::format::common::list_format::describe_kind;
+use crate::format::common::location::write_location_param;
+use crate::format::watch::mark_trend;
+use crate::types::attributes::WeatherAttribute;
 use crate::types::report::CurrentPartialReport;
+use crate::types::watch::Change;
 use crate::types::weather::*;
 
-pub fn describe(report: &CurrentPartialReport) -> String {
+pub fn describe(report: &CurrentPartialReport, changes: &[Change]) -> String {
     let mut result = String::default();
 
-    write_param(
-        &mut result,
-        "Coordinates",
-        format!("{:.5}", report.coordinates),
-    );
+    write_location_param(&mut result, &report.location);
+    if let Some(time) = report.time {
+        write_param(&mut result, "Time", time.format("%Y-%m-%d %H:%M UTC%:z"));
+    }
     if let Some(kind) = report.kind {
         write_param(&mut result, "Weather", describe_kind(&kind));
     }
     if let Some(temperature) = report.temperature {
-        write_param(&mut result, "Temperature", format!("{temperature:.1}"));
+        write_param(
+            &mut result,
+            "Temperature",
+            format!(
+                "{temperature:.1}{}",
+                mark_trend(changes, WeatherAttribute::Temperature)
+            ),
+        );
     }
     if let Some(coverage) = report.cloud_coverage {
         write_param(&mut result, "Cloud coverage", format!("{coverage}"));
@@ -27,7 +37,14 @@ pub fn describe(report: &CurrentPartialReport) -> String {
         write_param(&mut result, "Wind", describe_wind(wind));
     }
     if let Some(pressure) = report.pressure {
-        write_param(&mut result, "Pressure", format!("{pressure}"));
+        write_param(
+            &mut result,
+            "Pressure",
+            format!(
+                "{pressure}{}",
+                mark_trend(changes, WeatherAttribute::Pressure)
+            ),
+        );
     }
     result
 }
@@ -150,7 +167,8 @@ mod tests {
     fn describes_all_attributes() {
         let coordinates = Coordinates::new(1.2345, 67.89);
         let report = CurrentPartialReport {
-            coordinates,
+            location: coordinates.into(),
+            time: Some("2025-09-18T14:15:00+02:00".parse().unwrap()),
             kind: Some(Kind::Clouds(Clouds::Light)),
             temperature: Some(Temperature::new_celsius(22.4)),
             cloud_coverage: Some(Percentage::from(43)),
@@ -160,9 +178,11 @@ mod tests {
                 direction: Azimuth::from(155.5),
             }),
             pressure: Some(Pressure::new_hpa(1009.3)),
+            uv_index: None,
         };
-        let result = describe(&report);
+        let result = describe(&report, &[]);
         let expected = "Coordinates: 1.23450°, 67.89000°\n\
+            Time: 2025-09-18 14:15 UTC+02:00\n\
             Weather: light clouds\n\
             Temperature: 22.4°C\n\
             Cloud coverage: 43%\n\
@@ -176,7 +196,8 @@ mod tests {
     fn describes_only_selected_attributes() {
         let coordinates = Coordinates::new(1.2345, 67.89);
         let report = CurrentPartialReport {
-            coordinates,
+            location: coordinates.into(),
+            time: None,
             kind: None,
             temperature: Some(Temperature::new_celsius(22.4)),
             cloud_coverage: None,
@@ -186,8 +207,9 @@ mod tests {
                 direction: Azimuth::from(155.5),
             }),
             pressure: None,
+            uv_index: None,
         };
-        let result = describe(&report);
+        let result = describe(&report, &[]);
         let expected = "Coordinates: 1.23450°, 67.89000°\n\
             Temperature: 22.4°C\n\
             Humidity: 81%\n\
@@ -1,27 +1,65 @@
 use crate::format::common::summary::*;
-use crate::types::report::CurrentFullReport;
+use crate::format::watch::mark_trend;
+use crate::types::attributes::WeatherAttribute;
+use crate::types::gear::{self, GearConditions, GearRule};
+use crate::types::report::CurrentPartialReport;
 use crate::types::units::*;
+use crate::types::watch::Change;
 use crate::types::weather::*;
 
-pub fn describe(report: &CurrentFullReport) -> String {
-    let temperature_desc = describe_temperature(&report.temperature);
-    let weather_kind_desc = describe_weather_kind(&report.kind);
-    let clouds_desc = describe_cloud_coverage(&report.cloud_coverage);
-    let humidity_desc = describe_humidity(&report.humidity);
-    let wind_desc = describe_wind(&report.wind);
-    let pressure_desc = describe_pressure(&report.pressure);
-
-    #[allow(clippy::uninlined_format_args)]
-    {
-        format!(
-            "{} and {} with {}.\n{} with {}.\n{}.\n",
-            temperature_desc,
-            weather_kind_desc,
-            clouds_desc,
-            humidity_desc,
-            wind_desc,
-            pressure_desc,
-        )
+pub fn describe(
+    report: &CurrentPartialReport,
+    gear_rules: &[GearRule],
+    changes: &[Change],
+) -> String {
+    let temperature_desc = report.temperature.as_ref().map(|temperature| {
+        describe_temperature(temperature) + mark_trend(changes, WeatherAttribute::Temperature)
+    });
+    let weather_kind_desc = report.kind.as_ref().map(describe_weather_kind);
+    let clouds_desc = report.cloud_coverage.as_ref().map(describe_cloud_coverage);
+    let humidity_desc = report.humidity.as_ref().map(describe_humidity);
+    let wind_desc = report.wind.as_ref().map(describe_wind);
+    let pressure_desc = report.pressure.as_ref().map(|pressure| {
+        describe_pressure(pressure) + mark_trend(changes, WeatherAttribute::Pressure)
+    });
+    let gear_desc = describe_gear(&gear::recommend(gear_rules, &GearConditions::from(report)));
+
+    let sentences = [
+        describe_sky(temperature_desc, weather_kind_desc, clouds_desc),
+        describe_air(humidity_desc, wind_desc),
+        pressure_desc,
+        gear_desc,
+    ];
+    sentences
+        .into_iter()
+        .flatten()
+        .map(|sentence| format!("{}.\n", capitalize(&sentence)))
+        .collect()
+}
+
+fn describe_sky(
+    temperature_desc: Option<String>,
+    weather_kind_desc: Option<String>,
+    clouds_desc: Option<String>,
+) -> Option<String> {
+    let main_clauses: Vec<String> = [temperature_desc, weather_kind_desc]
+        .into_iter()
+        .flatten()
+        .collect();
+    match (main_clauses.is_empty(), clouds_desc) {
+        (true, None) => None,
+        (true, Some(clouds)) => Some(format!("there are {clouds}")),
+        (false, None) => Some(main_clauses.join(" and ")),
+        (false, Some(clouds)) => Some(format!("{} with {clouds}", main_clauses.join(" and "))),
+    }
+}
+
+fn describe_air(humidity_desc: Option<String>, wind_desc: Option<String>) -> Option<String> {
+    match (humidity_desc, wind_desc) {
+        (None, None) => None,
+        (None, Some(wind)) => Some(format!("there is {wind}")),
+        (Some(humidity), None) => Some(humidity),
+        (Some(humidity), Some(wind)) => Some(format!("{humidity} with {wind}")),
     }
 }
 
@@ -79,6 +117,7 @@ fn describe_pressure(pressure: &Pressure) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::watch::Trend;
 
     #[test]
     fn describes_values_of_clouds_kind() {
@@ -160,19 +199,22 @@ mod tests {
 
     #[test]
     fn describes_full_report() {
-        let report = CurrentFullReport {
-            kind: Kind::Clouds(Clouds::Light),
-            temperature: Temperature::new_celsius(22.4),
-            cloud_coverage: Percentage::from(43),
-            humidity: Percentage::from(81),
-            wind: Wind {
+        let report = CurrentPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            time: None,
+            kind: Some(Kind::Clouds(Clouds::Light)),
+            temperature: Some(Temperature::new_celsius(22.4)),
+            cloud_coverage: Some(Percentage::from(43)),
+            humidity: Some(Percentage::from(81)),
+            wind: Some(Wind {
                 speed: Speed::new_meters_per_second(1.12),
                 direction: Azimuth::from(140.3),
-            },
-            pressure: Pressure::new_hpa(1009.3),
+            }),
+            pressure: Some(Pressure::new_hpa(1009.3)),
+            uv_index: None,
         };
 
-        let result = describe(&report);
+        let result = describe(&report, &[], &[]);
         let expected: String = "It's warm at 22.4°C \
              and the sky is mostly clear \
              with clouds covering 43% of the sky.\n\
@@ -182,4 +224,103 @@ mod tests {
             .into();
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn describes_only_selected_attributes() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(1.23, 45.67));
+        report.temperature = Some(Temperature::new_celsius(22.4));
+        report.wind = Some(Wind {
+            speed: Speed::new_meters_per_second(1.12),
+            direction: Azimuth::from(140.3),
+        });
+
+        let result = describe(&report, &[], &[]);
+        let expected = "It's warm at 22.4°C.\n\
+             There is gentle southeast breeze blowing at 1.1 m/s.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn adds_gear_recommendation_when_rules_are_given() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(1.23, 45.67));
+        report.temperature = Some(Temperature::new_celsius(-4.0));
+        report.kind = Some(Kind::Precipitation(Precipitation {
+            kind: PrecipitationKind::Snow,
+            intensity: PrecipitationIntensity::Moderate,
+            heat: PrecipitationHeat::Normal,
+        }));
+
+        let result = describe(&report, &gear::default_rules(), &[]);
+        let expected = "It's freezing at -4.0°C and moderate snow is falling.\n\
+             What to wear: warm layers, a hat and gloves and waterproof boots.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn describes_sky_without_temperature() {
+        let result = describe_sky(None, Some("the sky is cloudy".into()), None);
+        assert_eq!(result, Some("the sky is cloudy".into()));
+
+        let result = describe_sky(None, None, Some("no clouds".into()));
+        assert_eq!(result, Some("there are no clouds".into()));
+
+        let result = describe_sky(None, None, None);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn describes_sky_with_temperature() {
+        let result = describe_sky(Some("It's cool at 9.5°C".into()), None, None);
+        assert_eq!(result, Some("It's cool at 9.5°C".into()));
+
+        let result = describe_sky(
+            Some("It's cool at 9.5°C".into()),
+            None,
+            Some("no clouds".into()),
+        );
+        assert_eq!(result, Some("It's cool at 9.5°C with no clouds".into()));
+    }
+
+    #[test]
+    fn describes_air_with_selected_attributes() {
+        let result = describe_air(Some("The air is humid at 60%".into()), None);
+        assert_eq!(result, Some("The air is humid at 60%".into()));
+
+        let result = describe_air(None, Some("no wind".into()));
+        assert_eq!(result, Some("there is no wind".into()));
+
+        let result = describe_air(None, None);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn marks_trends_after_values() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(1.23, 45.67));
+        report.temperature = Some(Temperature::new_celsius(22.4));
+        report.pressure = Some(Pressure::new_hpa(1009.3));
+        let changes = [
+            Change {
+                attribute: WeatherAttribute::Temperature,
+                trend: Trend::Down,
+            },
+            Change {
+                attribute: WeatherAttribute::Pressure,
+                trend: Trend::Up,
+            },
+        ];
+
+        let result = describe(&report, &[], &changes);
+        let expected = "It's warm at 22.4°C ↓.\n\
+             Low pressure stands at 1009.3 hPa ↑.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn describes_pressure_only() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(1.23, 45.67));
+        report.pressure = Some(Pressure::new_hpa(1021.0));
+
+        let result = describe(&report, &[], &[]);
+        assert_eq!(result, "High pressure stands at 1021.0 hPa.\n");
+    }
 }
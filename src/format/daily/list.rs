@@ -1,5 +1,7 @@
 use crate::format::common::list_builder::write_param;
 use crate::format::common::list_format::describe_kind;
+use crate::format::common::location::write_location_param;
+use crate::types::climate::{NORMAL_PERIOD_YEARS, temperature_anomaly};
 use crate::types::report::*;
 use crate::types::units::*;
 use crate::types::weather::*;
@@ -10,10 +12,10 @@ pub fn describe(report: &DailyPartialReport) -> String {
 }
 
 fn describe_report(report: &DailyPartialReport) -> Result<String, std::fmt::Error> {
-    let DailyPartialReport { coordinates, data } = &report;
+    let DailyPartialReport { location, data } = &report;
     let mut result = String::new();
 
-    write_param(&mut result, "Coordinates", format!("{coordinates:.5}"));
+    write_location_param(&mut result, location);
     writeln!(&mut result)?;
 
     let mut day_iter = data.iter();
@@ -35,6 +37,9 @@ fn describe_day(result: &mut String, data: &DailyPartialData) {
     }
     if let Some(range) = &data.temperature_range {
         write_temperature(result, range);
+        if let Some(normal) = &data.temperature_normal {
+            write_temperature_anomaly(result, range, normal);
+        }
     }
     if let Some(PercentageRange { min, max }) = data.cloud_coverage_range {
         write_param(result, "Cloud coverage", format_range(min, max));
@@ -65,6 +70,16 @@ fn write_temperature(result: &mut String, range: &TemperatureRange) {
     write_param(result, "Temperature", value);
 }
 
+fn write_temperature_anomaly(result: &mut String, range: &TemperatureRange, normal: &Temperature) {
+    let anomaly = temperature_anomaly(range, normal);
+    let value = format!("{:+.1}{}", anomaly.value(), anomaly.unit_symbol());
+    write_param(
+        result,
+        format!("Anomaly vs {NORMAL_PERIOD_YEARS}-year average"),
+        value,
+    );
+}
+
 fn write_wind(result: &mut String, scope: &WindScope) {
     let WindScope {
         speed_range,
@@ -160,6 +175,8 @@ mod tests {
                 date: generate_start_date(0),
                 kind: Some(Kind::Clouds(Clouds::Light)),
                 temperature_range: Some(TemperatureRange::new_celsius(24.5, 27.1)),
+                temperature_normal: None,
+                uv_index: None,
                 cloud_coverage_range: None,
                 humidity_range: Some(PercentageRange::new(33, 46)),
                 wind: None,
@@ -169,6 +186,8 @@ mod tests {
                 date: generate_start_date(1),
                 kind: Some(Kind::Clouds(Clouds::Moderate)),
                 temperature_range: Some(TemperatureRange::new_celsius(26.5, 29.1)),
+                temperature_normal: None,
+                uv_index: None,
                 cloud_coverage_range: Some(PercentageRange::new(56, 79)),
                 humidity_range: Some(PercentageRange::new(34, 47)),
                 wind: Some(WindScope {
@@ -181,6 +200,8 @@ mod tests {
                 date: generate_start_date(2),
                 kind: Some(Kind::Clouds(Clouds::Dense)),
                 temperature_range: Some(TemperatureRange::new_celsius(28.5, 31.1)),
+                temperature_normal: None,
+                uv_index: None,
                 cloud_coverage_range: None,
                 humidity_range: Some(PercentageRange::new(35, 48)),
                 wind: None,
@@ -188,7 +209,7 @@ mod tests {
             },
         ];
         DailyPartialReport {
-            coordinates: generate_coordinates(),
+            location: generate_coordinates().into(),
             data,
         }
     }
@@ -218,4 +239,16 @@ mod tests {
                         Humidity: 35% - 48%\n";
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn writes_temperature_anomaly() {
+        let mut report = generate_report_for_3_days();
+        report.data.truncate(1);
+        report.data[0].temperature_normal = Some(Temperature::new_celsius(22.6));
+        let result = describe(&report);
+        assert!(result.contains(
+            "Temperature: 24.5°C - 27.1°C\n\
+             Anomaly vs 30-year average: +3.2°C\n"
+        ));
+    }
 }
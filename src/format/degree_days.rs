@@ -0,0 +1,94 @@
+use crate::format::Formatter;
+use crate::format::common::list_builder::write_param;
+use crate::format::common::location::{describe_sections, write_location_param};
+use crate::types::degree_days::DegreeDays;
+use crate::types::report::Report;
+use crate::types::units::Temperature;
+
+pub struct DegreeDaysFormatter {
+    base: Temperature,
+}
+
+impl DegreeDaysFormatter {
+    pub fn new(base: Temperature) -> Self {
+        Self { base }
+    }
+}
+
+impl Formatter for DegreeDaysFormatter {
+    fn format(&self, report: &Report) -> String {
+        let Some(daily_report) = report.to_daily_partial() else {
+            return String::new();
+        };
+        let degree_days = DegreeDays::new(&daily_report, self.base);
+        let mut result = String::new();
+        write_location_param(&mut result, report.location());
+        describe(&mut result, &degree_days);
+        result
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        describe_sections(reports, |report| self.format(report))
+    }
+}
+
+fn describe(result: &mut String, degree_days: &DegreeDays) {
+    let unit = degree_days.base.unit_symbol();
+    write_param(result, "Base temperature", degree_days.base);
+    write_param(
+        result,
+        "Heating degree-days",
+        format!("{:.1} {unit}", degree_days.total_heating()),
+    );
+    write_param(
+        result,
+        "Cooling degree-days",
+        format!("{:.1} {unit}", degree_days.total_cooling()),
+    );
+    for day in &degree_days.days {
+        write_param(
+            result,
+            day.date.format("%d.%m.%Y"),
+            format!(
+                "mean {}, heating {:.1}, cooling {:.1}",
+                day.mean, day.heating, day.cooling
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::*;
+    use crate::types::units::*;
+
+    fn make_day(day: u32, min: f32, max: f32) -> DailyPartialData {
+        DailyPartialData {
+            date: Date::from_ymd_opt(2025, 1, day).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(min, max)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        }
+    }
+
+    #[test]
+    fn formats_totals_and_daily_values() {
+        let report = Report::PastPartial(DailyPartialReport {
+            location: Coordinates::new(52.23, 21.01).into(),
+            data: vec![make_day(1, -2.0, 6.0), make_day(2, 16.0, 30.0)],
+        });
+        let formatter = DegreeDaysFormatter::new(Temperature::new_celsius(18.0));
+        let expected = "Coordinates: 52.23000°, 21.01000°\n\
+                        Base temperature: 18.0°C\n\
+                        Heating degree-days: 16.0 °C\n\
+                        Cooling degree-days: 5.0 °C\n\
+                        01.01.2025: mean 2.0°C, heating 16.0, cooling 0.0\n\
+                        02.01.2025: mean 23.0°C, heating 0.0, cooling 5.0\n";
+        assert_eq!(formatter.format(&report), expected);
+    }
+}
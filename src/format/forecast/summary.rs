@@ -1,57 +1,41 @@
 use std::fmt::Display;
 
+use crate::format::common::relative_date::describe_date;
 use crate::format::common::summary::*;
-use crate::types::report::{DailyFullData, DailyFullReport};
+use crate::types::gear::{self, GearConditions, GearRule};
+use crate::types::report::{DailyPartialData, DailyPartialReport};
 use crate::types::units::*;
 use crate::types::weather::*;
 
-pub fn describe(report: &DailyFullReport) -> String {
-    let mut data_iter = report.data.iter();
-    let mut result = String::new();
-    if let Some(data) = data_iter.next() {
-        let date_desc = String::from("Today");
-        let day_summary = describe_day(date_desc, data);
-        result.push_str(&day_summary);
-    }
-    if let Some(data) = data_iter.next() {
-        let date_desc = String::from("Tomorrow");
-        let day_summary = describe_day(date_desc, data);
-        result.push('\n');
-        result.push_str(&day_summary);
-    }
-    for data in data_iter {
-        let date_desc = describe_date(&data.date);
-        let day_summary = describe_day(date_desc, data);
-        result.push('\n');
-        result.push_str(&day_summary);
-    }
-    result
-}
-
-fn describe_date(date: &Date) -> String {
-    format!("On {}", date.format("%d.%m.%Y"))
+pub fn describe(report: &DailyPartialReport, today: &Date, gear_rules: &[GearRule]) -> String {
+    let days: Vec<String> = report
+        .data
+        .iter()
+        .map(|data| describe_day(describe_date(&data.date, today), data, gear_rules))
+        .collect();
+    days.join("\n")
 }
 
-fn describe_day(date_desc: String, data: &DailyFullData) -> String {
-    let temperature_desc = describe_temperature_range(&data.temperature_range);
-    let kind_desc = describe_kind(&data.kind);
-    let cloud_coverage_desc = describe_cloud_coverage_range(&data.cloud_coverage_range);
-    let humidity_desc = describe_humidity_range(&data.humidity_range);
-    let wind_desc = describe_wind_scope(&data.wind);
-    let pressure_desc = describe_pressure_range(&data.pressure_range);
-    #[allow(clippy::uninlined_format_args)]
-    {
-        format!(
-            "{} {}.\n{} and {}.\n{} with {}.\n{}.\n",
-            date_desc,
-            temperature_desc,
-            kind_desc,
-            cloud_coverage_desc,
-            humidity_desc,
-            wind_desc,
-            pressure_desc,
-        )
-    }
+fn describe_day(date_desc: String, data: &DailyPartialData, gear_rules: &[GearRule]) -> String {
+    let description = DayDescription {
+        temperature: data.temperature_range.as_ref().map(|range| {
+            add_temperature_anomaly(
+                describe_temperature_range(range),
+                range,
+                data.temperature_normal.as_ref(),
+            )
+        }),
+        kind: data.kind.as_ref().map(describe_kind),
+        cloud_coverage: data
+            .cloud_coverage_range
+            .as_ref()
+            .map(describe_cloud_coverage_range),
+        humidity: data.humidity_range.as_ref().map(describe_humidity_range),
+        wind: data.wind.as_ref().map(describe_wind_scope),
+        pressure: data.pressure_range.as_ref().map(describe_pressure_range),
+        gear: describe_gear(&gear::recommend(gear_rules, &GearConditions::from(data))),
+    };
+    describe_day_sentences(&date_desc, description, Tense::Future)
 }
 
 fn describe_kind(kind: &Kind) -> String {
@@ -137,13 +121,15 @@ fn describe_pressure_range(pressure_range: &PressureRange) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::report::DailyFullData;
+    use crate::types::report::{DailyFullData, DailyFullReport};
 
-    fn generate_report_for_3_days() -> DailyFullReport {
+    fn generate_report_for_3_days() -> DailyPartialReport {
         let daily_data_1 = DailyFullData {
-            date: Date::from_ymd_opt(2025, 08, 24).unwrap(),
+            date: Date::from_ymd_opt(2025, 8, 24).unwrap(),
             kind: Kind::Clouds(Clouds::Light),
             temperature_range: TemperatureRange::new_celsius(20.6, 26.8),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: PercentageRange {
                 min: Percentage::from(27),
                 max: Percentage::from(29),
@@ -159,9 +145,11 @@ mod tests {
             pressure_range: PressureRange::new_hpa(995.8, 1019.8),
         };
         let daily_data_2 = DailyFullData {
-            date: Date::from_ymd_opt(2025, 08, 25).unwrap(),
+            date: Date::from_ymd_opt(2025, 8, 25).unwrap(),
             kind: Kind::Clouds(Clouds::Clear),
             temperature_range: TemperatureRange::new_celsius(3.4, 9.0),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: PercentageRange {
                 min: Percentage::from(19),
                 max: Percentage::from(96),
@@ -177,13 +165,15 @@ mod tests {
             pressure_range: PressureRange::new_hpa(990.3, 1014.3),
         };
         let daily_data_3 = DailyFullData {
-            date: Date::from_ymd_opt(2025, 08, 26).unwrap(),
+            date: Date::from_ymd_opt(2025, 8, 26).unwrap(),
             kind: Kind::Precipitation(Precipitation {
                 kind: PrecipitationKind::Snow,
                 intensity: PrecipitationIntensity::Light,
                 heat: PrecipitationHeat::Normal,
             }),
             temperature_range: TemperatureRange::new_celsius(9.5, 15.5),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: PercentageRange {
                 min: Percentage::from(0),
                 max: Percentage::from(1),
@@ -200,15 +190,10 @@ mod tests {
         };
 
         DailyFullReport {
+            location: Coordinates::new(1.23, 45.67).into(),
             data: vec![daily_data_1, daily_data_2, daily_data_3],
         }
-    }
-
-    #[test]
-    fn describes_dates() {
-        let date = Date::from_ymd_opt(2025, 07, 18).unwrap();
-        let expected = "On 18.07.2025";
-        assert_eq!(describe_date(&date), expected);
+        .into()
     }
 
     #[test]
@@ -377,7 +362,7 @@ mod tests {
     #[test]
     fn describes_entire_report() {
         let report = generate_report_for_3_days();
-        let result = describe(&report);
+        let result = describe(&report, &Date::from_ymd_opt(2025, 8, 24).unwrap(), &[]);
         let expected_day1 = "Today it will be hot \
             with temperatures starting at 20.6°C and reaching 26.8°C.\n\
             The sky will be mostly clear \
@@ -392,7 +377,7 @@ mod tests {
             The air will be heavy at 29% to 86% humidity \
             with mostly gentle north breeze blowing at maximum 2.3 m/s.\n\
             Normal pressure will reach 990.3 hPa at lowest up to 1014.3 hPa.\n";
-        let expected_day3 = "On 26.08.2025 it will be warm \
+        let expected_day3 = "On Tuesday it will be warm \
             with temperatures starting at 9.5°C and reaching 15.5°C.\n\
             There will be light snow falling \
             and clouds will cover from 0% to 1% of the sky.\n\
@@ -402,4 +387,88 @@ mod tests {
         let expected = format!("{expected_day1}\n{expected_day2}\n{expected_day3}");
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn describes_only_selected_attributes() {
+        let data = DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, 24).unwrap(),
+            kind: Some(Kind::Clouds(Clouds::Dense)),
+            temperature_range: None,
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: Some(WindScope {
+                speed_range: SpeedRange::new_meters_per_second(5.3, 9.7),
+                dominant_direction: Azimuth::from(273.3),
+            }),
+            pressure_range: None,
+        };
+        let report = DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![data],
+        };
+        let result = describe(&report, &Date::from_ymd_opt(2025, 8, 24).unwrap(), &[]);
+        let expected = "Today the sky will be overcast.\n\
+            There will be mostly strong west wind blowing at maximum 9.7 m/s.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn adds_gear_recommendation_for_each_day() {
+        let make_data = |day, kind| DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, day).unwrap(),
+            kind: Some(kind),
+            temperature_range: None,
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        let rain = Kind::Precipitation(Precipitation {
+            kind: PrecipitationKind::Rain,
+            intensity: PrecipitationIntensity::Light,
+            heat: PrecipitationHeat::Normal,
+        });
+        let report = DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![
+                make_data(24, rain),
+                make_data(25, Kind::Clouds(Clouds::Dense)),
+            ],
+        };
+        let today = Date::from_ymd_opt(2025, 8, 24).unwrap();
+        let result = describe(&report, &today, &gear::default_rules());
+        let expected = "Today there will be light rain falling.\n\
+            What to wear: an umbrella.\n\
+            \n\
+            Tomorrow the sky will be overcast.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn describes_day_starting_with_any_attribute() {
+        let mut data = DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, 24).unwrap(),
+            kind: None,
+            temperature_range: None,
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: Some(PercentageRange::new(26, 57)),
+            humidity_range: None,
+            wind: None,
+            pressure_range: Some(PressureRange::new_hpa(1011.9, 1020.5)),
+        };
+        let result = describe_day("Tomorrow".into(), &data, &[]);
+        let expected = "Tomorrow clouds will cover from 26% to 57% of the sky.\n\
+            High pressure will reach 1011.9 hPa at lowest up to 1020.5 hPa.\n";
+        assert_eq!(result, expected);
+
+        data.cloud_coverage_range = None;
+        let result = describe_day("Tomorrow".into(), &data, &[]);
+        let expected = "Tomorrow high pressure will reach 1011.9 hPa at lowest up to 1020.5 hPa.\n";
+        assert_eq!(result, expected);
+    }
 }
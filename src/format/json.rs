@@ -0,0 +1,814 @@
+use crate::format::common::list_format::describe_kind;
+use crate::format::common::location::describe_location;
+use crate::format::common::statistics::{compute_statistics, describe_pressure_trend};
+use crate::format::{Formatter, ReportContent};
+use crate::types::activity::{ActivityProfile, MAX_SCORE};
+use crate::types::degree_days::DegreeDays;
+use crate::types::location::*;
+use crate::types::report::*;
+use crate::types::statistics::*;
+use crate::types::units::*;
+use crate::types::weather::*;
+use serde::Serialize;
+
+pub struct JsonFormatter {
+    content: ReportContent,
+}
+
+impl JsonFormatter {
+    pub fn new(content: ReportContent) -> Self {
+        Self { content }
+    }
+
+    fn make_entry(&self, report: &Report) -> Option<ReportEntry> {
+        match &self.content {
+            ReportContent::Weather | ReportContent::WeatherWithGear(_) => {
+                Some(make_weather_entry(report))
+            }
+            ReportContent::Statistics => {
+                make_statistics_entry(report).map(|entry| ReportEntry::Statistics(Box::new(entry)))
+            }
+            ReportContent::DegreeDays(base) => {
+                make_degree_days_entry(report, *base).map(ReportEntry::DegreeDays)
+            }
+            ReportContent::Activity(profile) => {
+                make_activity_entry(report, profile).map(ReportEntry::Activity)
+            }
+        }
+    }
+}
+
+impl Formatter for JsonFormatter {
+    fn format(&self, report: &Report) -> String {
+        match self.make_entry(report) {
+            Some(entry) => format!("{}\n", serialize(&entry)),
+            None => String::new(),
+        }
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        let entries: Vec<LocatedEntry> = reports
+            .iter()
+            .filter_map(|report| {
+                Some(LocatedEntry {
+                    location: describe_location(report.location()),
+                    report: self.make_entry(report)?,
+                })
+            })
+            .collect();
+        format!("{}\n", serialize(&entries))
+    }
+}
+
+fn serialize<T: Serialize>(value: &T) -> String {
+    serde_json::to_string_pretty(value).expect("Failed to serialize report")
+}
+
+fn make_weather_entry(report: &Report) -> ReportEntry {
+    match report {
+        Report::PastFull(inner) => ReportEntry::Daily(make_daily(&inner.clone().into())),
+        Report::PastPartial(inner) => ReportEntry::Daily(make_daily(inner)),
+        Report::CurrentFull(inner) => ReportEntry::Current(make_current(&inner.clone().into())),
+        Report::CurrentPartial(inner) => ReportEntry::Current(make_current(inner)),
+        Report::ForecastFull(inner) => ReportEntry::Daily(make_daily(&inner.clone().into())),
+        Report::ForecastPartial(inner) => ReportEntry::Daily(make_daily(inner)),
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+enum ReportEntry {
+    Current(CurrentEntry),
+    Daily(DailyEntry),
+    Statistics(Box<StatisticsEntry>),
+    DegreeDays(DegreeDaysEntry),
+    Activity(ActivityEntry),
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct LocatedEntry {
+    location: String,
+    #[serde(flatten)]
+    report: ReportEntry,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct CoordinatesEntry {
+    latitude: f32,
+    longitude: f32,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct PlaceEntry {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct ValueEntry<T> {
+    value: T,
+    unit: &'static str,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct RangeEntry<T> {
+    min: T,
+    max: T,
+    unit: &'static str,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct WindEntry {
+    speed: ValueEntry<f32>,
+    direction: f32,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct WindScopeEntry {
+    speed: RangeEntry<f32>,
+    dominant_direction: f32,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct CurrentEntry {
+    coordinates: CoordinatesEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place: Option<PlaceEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weather: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<ValueEntry<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cloud_coverage: Option<ValueEntry<i8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    humidity: Option<ValueEntry<i8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind: Option<WindEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pressure: Option<ValueEntry<f32>>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct DayEntry {
+    date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weather: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<RangeEntry<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cloud_coverage: Option<RangeEntry<i8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    humidity: Option<RangeEntry<i8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind: Option<WindScopeEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pressure: Option<RangeEntry<f32>>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct DailyEntry {
+    coordinates: CoordinatesEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place: Option<PlaceEntry>,
+    days: Vec<DayEntry>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct ExtremeEntry<T> {
+    value: T,
+    date: String,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct AttributeStatisticsEntry<T> {
+    mean: T,
+    min: ExtremeEntry<T>,
+    max: ExtremeEntry<T>,
+    unit: &'static str,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct StatisticsEntry {
+    coordinates: CoordinatesEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place: Option<PlaceEntry>,
+    first_date: String,
+    last_date: String,
+    day_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<AttributeStatisticsEntry<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cloud_coverage: Option<AttributeStatisticsEntry<i8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    humidity: Option<AttributeStatisticsEntry<i8>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind_speed: Option<AttributeStatisticsEntry<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prevailing_wind_direction: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pressure: Option<AttributeStatisticsEntry<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pressure_trend: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rainy_days: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct DegreeDayEntry {
+    date: String,
+    mean: f32,
+    heating: f32,
+    cooling: f32,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct DegreeDaysEntry {
+    coordinates: CoordinatesEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place: Option<PlaceEntry>,
+    base: ValueEntry<f32>,
+    heating_total: f32,
+    cooling_total: f32,
+    days: Vec<DegreeDayEntry>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct ActivityDayEntry {
+    date: String,
+    score: u8,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct ActivityEntry {
+    coordinates: CoordinatesEntry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place: Option<PlaceEntry>,
+    activity: String,
+    max_score: u8,
+    days: Vec<ActivityDayEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    best_day: Option<String>,
+}
+
+fn make_coordinates(coordinates: &Coordinates) -> CoordinatesEntry {
+    CoordinatesEntry {
+        latitude: coordinates.latitude.raw(),
+        longitude: coordinates.longitude.raw(),
+    }
+}
+
+fn make_place(location: &Location) -> Option<PlaceEntry> {
+    location.place_name().map(|place_name| PlaceEntry {
+        name: place_name.name,
+        country: place_name.country,
+    })
+}
+
+fn make_percentage(percentage: &Percentage) -> ValueEntry<i8> {
+    ValueEntry {
+        value: percentage.value,
+        unit: "%",
+    }
+}
+
+fn make_percentage_range(range: &PercentageRange) -> RangeEntry<i8> {
+    RangeEntry {
+        min: range.min.value,
+        max: range.max.value,
+        unit: "%",
+    }
+}
+
+fn make_temperature_range(range: &TemperatureRange) -> RangeEntry<f32> {
+    RangeEntry {
+        min: range.min().value(),
+        max: range.max().value(),
+        unit: range.min().unit_symbol(),
+    }
+}
+
+fn make_wind(wind: &Wind) -> WindEntry {
+    WindEntry {
+        speed: ValueEntry {
+            value: wind.speed.value(),
+            unit: wind.speed.unit_symbol(),
+        },
+        direction: wind.direction.into(),
+    }
+}
+
+fn make_wind_scope(scope: &WindScope) -> WindScopeEntry {
+    WindScopeEntry {
+        speed: RangeEntry {
+            min: scope.speed_range.min().value(),
+            max: scope.speed_range.max().value(),
+            unit: scope.speed_range.min().unit_symbol(),
+        },
+        dominant_direction: scope.dominant_direction.into(),
+    }
+}
+
+fn make_pressure_range(range: &PressureRange) -> RangeEntry<f32> {
+    RangeEntry {
+        min: range.min().value(),
+        max: range.max().value(),
+        unit: range.min().unit_symbol(),
+    }
+}
+
+fn make_current(report: &CurrentPartialReport) -> CurrentEntry {
+    CurrentEntry {
+        coordinates: make_coordinates(&report.location.coordinates),
+        place: make_place(&report.location),
+        time: report.time.map(|time| time.to_rfc3339()),
+        weather: report.kind.as_ref().map(describe_kind),
+        temperature: report.temperature.map(|temperature| ValueEntry {
+            value: temperature.value(),
+            unit: temperature.unit_symbol(),
+        }),
+        cloud_coverage: report.cloud_coverage.as_ref().map(make_percentage),
+        humidity: report.humidity.as_ref().map(make_percentage),
+        wind: report.wind.as_ref().map(make_wind),
+        pressure: report.pressure.map(|pressure| ValueEntry {
+            value: pressure.value(),
+            unit: pressure.unit_symbol(),
+        }),
+    }
+}
+
+fn make_day(data: &DailyPartialData) -> DayEntry {
+    DayEntry {
+        date: data.date.format("%Y-%m-%d").to_string(),
+        weather: data.kind.as_ref().map(describe_kind),
+        temperature: data.temperature_range.as_ref().map(make_temperature_range),
+        cloud_coverage: data
+            .cloud_coverage_range
+            .as_ref()
+            .map(make_percentage_range),
+        humidity: data.humidity_range.as_ref().map(make_percentage_range),
+        wind: data.wind.as_ref().map(make_wind_scope),
+        pressure: data.pressure_range.as_ref().map(make_pressure_range),
+    }
+}
+
+fn make_daily(report: &DailyPartialReport) -> DailyEntry {
+    DailyEntry {
+        coordinates: make_coordinates(&report.location.coordinates),
+        place: make_place(&report.location),
+        days: report.data.iter().map(make_day).collect(),
+    }
+}
+
+fn format_date(date: &Date) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn make_attribute_statistics<T, V>(
+    statistics: &AttributeStatistics<T>,
+    value: impl Fn(&T) -> V,
+    unit: &'static str,
+) -> AttributeStatisticsEntry<V> {
+    let make_extreme = |extreme: &Extreme<T>| ExtremeEntry {
+        value: value(&extreme.value),
+        date: format_date(&extreme.date),
+    };
+    AttributeStatisticsEntry {
+        mean: value(&statistics.mean),
+        min: make_extreme(&statistics.min),
+        max: make_extreme(&statistics.max),
+        unit,
+    }
+}
+
+fn make_statistics_entry(report: &Report) -> Option<StatisticsEntry> {
+    let statistics = compute_statistics(report)?;
+    let location = report.location();
+    Some(StatisticsEntry {
+        coordinates: make_coordinates(&location.coordinates),
+        place: make_place(location),
+        first_date: format_date(&statistics.first_date),
+        last_date: format_date(&statistics.last_date),
+        day_count: statistics.day_count,
+        temperature: statistics.temperature.as_ref().map(|temperature| {
+            make_attribute_statistics(
+                temperature,
+                Temperature::value,
+                temperature.mean.unit_symbol(),
+            )
+        }),
+        cloud_coverage: statistics
+            .cloud_coverage
+            .as_ref()
+            .map(|coverage| make_attribute_statistics(coverage, |value| value.value, "%")),
+        humidity: statistics
+            .humidity
+            .as_ref()
+            .map(|humidity| make_attribute_statistics(humidity, |value| value.value, "%")),
+        wind_speed: statistics
+            .wind_speed
+            .as_ref()
+            .map(|speed| make_attribute_statistics(speed, Speed::value, speed.mean.unit_symbol())),
+        prevailing_wind_direction: statistics.prevailing_wind_direction.map(f32::from),
+        pressure: statistics.pressure.as_ref().map(|pressure| {
+            make_attribute_statistics(pressure, Pressure::value, pressure.mean.unit_symbol())
+        }),
+        pressure_trend: statistics.pressure_trend.map(describe_pressure_trend),
+        rainy_days: statistics.rainy_days,
+    })
+}
+
+fn make_degree_days_entry(report: &Report, base: Temperature) -> Option<DegreeDaysEntry> {
+    let degree_days = DegreeDays::new(&report.to_daily_partial()?, base);
+    let location = report.location();
+    Some(DegreeDaysEntry {
+        coordinates: make_coordinates(&location.coordinates),
+        place: make_place(location),
+        base: ValueEntry {
+            value: degree_days.base.value(),
+            unit: degree_days.base.unit_symbol(),
+        },
+        heating_total: degree_days.total_heating(),
+        cooling_total: degree_days.total_cooling(),
+        days: degree_days
+            .days
+            .iter()
+            .map(|day| DegreeDayEntry {
+                date: format_date(&day.date),
+                mean: day.mean.value(),
+                heating: day.heating,
+                cooling: day.cooling,
+            })
+            .collect(),
+    })
+}
+
+fn make_activity_entry(report: &Report, profile: &ActivityProfile) -> Option<ActivityEntry> {
+    let (Report::ForecastFull(inner) | Report::PastFull(inner)) = report else {
+        return None;
+    };
+    let best_day = (inner.data.len() > 1)
+        .then(|| profile.best_day(&inner.data))
+        .flatten()
+        .map(|(data, _)| format_date(&data.date));
+    Some(ActivityEntry {
+        coordinates: make_coordinates(&inner.location.coordinates),
+        place: make_place(&inner.location),
+        activity: profile.name.clone(),
+        max_score: MAX_SCORE,
+        days: inner
+            .data
+            .iter()
+            .map(|data| ActivityDayEntry {
+                date: format_date(&data.date),
+                score: profile.score(data),
+            })
+            .collect(),
+        best_day,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::activity::builtin_profiles;
+
+    #[test]
+    fn formats_current_report_with_all_attributes() {
+        let report = Report::CurrentFull(CurrentFullReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            time: "2025-09-18T14:15:00+02:00".parse().unwrap(),
+            kind: Kind::Clouds(Clouds::Light),
+            temperature: Temperature::new_celsius(22.4),
+            cloud_coverage: Percentage::from(43),
+            humidity: Percentage::from(81),
+            wind: Wind {
+                speed: Speed::new_meters_per_second(1.07),
+                direction: Azimuth::from(155.5),
+            },
+            pressure: Pressure::new_hpa(1009.3),
+            uv_index: None,
+        });
+        let result = JsonFormatter::new(ReportContent::Weather).format(&report);
+        let expected = r#"{
+  "coordinates": {
+    "latitude": 1.23,
+    "longitude": 45.67
+  },
+  "time": "2025-09-18T14:15:00+02:00",
+  "weather": "light clouds",
+  "temperature": {
+    "value": 22.4,
+    "unit": "°C"
+  },
+  "cloud_coverage": {
+    "value": 43,
+    "unit": "%"
+  },
+  "humidity": {
+    "value": 81,
+    "unit": "%"
+  },
+  "wind": {
+    "speed": {
+      "value": 1.07,
+      "unit": "m/s"
+    },
+    "direction": 155.5
+  },
+  "pressure": {
+    "value": 1009.3,
+    "unit": "hPa"
+  }
+}
+"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn skips_attributes_missing_from_current_report() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(1.23, 45.67));
+        report.temperature = Some(Temperature::new_fahrenheit(71.5));
+        let result =
+            JsonFormatter::new(ReportContent::Weather).format(&Report::CurrentPartial(report));
+        let expected = r#"{
+  "coordinates": {
+    "latitude": 1.23,
+    "longitude": 45.67
+  },
+  "temperature": {
+    "value": 71.5,
+    "unit": "°F"
+  }
+}
+"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn makes_day_entry_with_all_attributes() {
+        let data = DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, 26).unwrap(),
+            kind: Some(Kind::Thunderstorm),
+            temperature_range: Some(TemperatureRange::new_celsius(12.5, 20.5)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: Some(PercentageRange::new(10, 20)),
+            humidity_range: Some(PercentageRange::new(30, 40)),
+            wind: Some(WindScope {
+                speed_range: SpeedRange::new_knots(1.5, 3.5),
+                dominant_direction: Azimuth::from(90.5),
+            }),
+            pressure_range: Some(PressureRange::new_hpa(1001.5, 1010.5)),
+        };
+        let expected = DayEntry {
+            date: "2025-08-26".into(),
+            weather: Some("thunderstorm".into()),
+            temperature: Some(RangeEntry {
+                min: 12.5,
+                max: 20.5,
+                unit: "°C",
+            }),
+            cloud_coverage: Some(RangeEntry {
+                min: 10,
+                max: 20,
+                unit: "%",
+            }),
+            humidity: Some(RangeEntry {
+                min: 30,
+                max: 40,
+                unit: "%",
+            }),
+            wind: Some(WindScopeEntry {
+                speed: RangeEntry {
+                    min: 1.5,
+                    max: 3.5,
+                    unit: "kn",
+                },
+                dominant_direction: 90.5,
+            }),
+            pressure: Some(RangeEntry {
+                min: 1001.5,
+                max: 1010.5,
+                unit: "hPa",
+            }),
+        };
+        assert_eq!(make_day(&data), expected);
+    }
+
+    #[test]
+    fn formats_daily_report_with_selected_attributes() {
+        let report = Report::ForecastPartial(DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![DailyPartialData {
+                date: Date::from_ymd_opt(2025, 8, 26).unwrap(),
+                kind: None,
+                temperature_range: None,
+                temperature_normal: None,
+                uv_index: None,
+                cloud_coverage_range: None,
+                humidity_range: Some(PercentageRange::new(33, 46)),
+                wind: None,
+                pressure_range: None,
+            }],
+        });
+        let result = JsonFormatter::new(ReportContent::Weather).format(&report);
+        let expected = r#"{
+  "coordinates": {
+    "latitude": 1.23,
+    "longitude": 45.67
+  },
+  "days": [
+    {
+      "date": "2025-08-26",
+      "humidity": {
+        "min": 33,
+        "max": 46,
+        "unit": "%"
+      }
+    }
+  ]
+}
+"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_reports_of_multiple_locations() {
+        let make_report = |latitude, temperature| {
+            let mut report = CurrentPartialReport::new_empty(Coordinates::new(latitude, 21.0));
+            report.temperature = Some(Temperature::new_celsius(temperature));
+            Report::CurrentPartial(report)
+        };
+        let reports = [make_report(52.0, 12.5), make_report(50.0, 14.5)];
+        let result = JsonFormatter::new(ReportContent::Weather).format_many(&reports);
+        let expected = r#"[
+  {
+    "location": "52.0000°, 21.0000°",
+    "coordinates": {
+      "latitude": 52.0,
+      "longitude": 21.0
+    },
+    "temperature": {
+      "value": 12.5,
+      "unit": "°C"
+    }
+  },
+  {
+    "location": "50.0000°, 21.0000°",
+    "coordinates": {
+      "latitude": 50.0,
+      "longitude": 21.0
+    },
+    "temperature": {
+      "value": 14.5,
+      "unit": "°C"
+    }
+  }
+]
+"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_statistics_of_daily_report() {
+        let make_day = |day, min, max| DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, day).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(min, max)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        let report = Report::PastPartial(DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![make_day(1, 10.0, 20.0), make_day(2, 8.0, 16.0)],
+        });
+        let result = JsonFormatter::new(ReportContent::Statistics).format(&report);
+        let expected = r#"{
+  "coordinates": {
+    "latitude": 1.23,
+    "longitude": 45.67
+  },
+  "first_date": "2025-08-01",
+  "last_date": "2025-08-02",
+  "day_count": 2,
+  "temperature": {
+    "mean": 13.5,
+    "min": {
+      "value": 8.0,
+      "date": "2025-08-02"
+    },
+    "max": {
+      "value": 20.0,
+      "date": "2025-08-01"
+    },
+    "unit": "°C"
+  }
+}
+"#;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn formats_degree_days() {
+        let report = Report::ForecastPartial(DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![DailyPartialData {
+                date: Date::from_ymd_opt(2025, 1, 2).unwrap(),
+                kind: None,
+                temperature_range: Some(TemperatureRange::new_fahrenheit(40.0, 50.0)),
+                temperature_normal: None,
+                uv_index: None,
+                cloud_coverage_range: None,
+                humidity_range: None,
+                wind: None,
+                pressure_range: None,
+            }],
+        });
+        let formatter =
+            JsonFormatter::new(ReportContent::DegreeDays(Temperature::new_fahrenheit(65.0)));
+        let expected = r#"{
+  "coordinates": {
+    "latitude": 1.23,
+    "longitude": 45.67
+  },
+  "base": {
+    "value": 65.0,
+    "unit": "°F"
+  },
+  "heating_total": 20.0,
+  "cooling_total": 0.0,
+  "days": [
+    {
+      "date": "2025-01-02",
+      "mean": 45.0,
+      "heating": 20.0,
+      "cooling": 0.0
+    }
+  ]
+}
+"#;
+        assert_eq!(formatter.format(&report), expected);
+    }
+
+    fn make_activity_report() -> (Report, ActivityProfile) {
+        let make_day = |day, kind| DailyFullData {
+            date: Date::from_ymd_opt(2025, 6, day).unwrap(),
+            kind,
+            temperature_range: TemperatureRange::new_celsius(10.0, 16.0),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: PercentageRange::new(0, 20),
+            humidity_range: PercentageRange::new(30, 50),
+            wind: WindScope {
+                speed_range: SpeedRange::new_meters_per_second(0.0, 6.0),
+                dominant_direction: Azimuth::from(180.0),
+            },
+            pressure_range: PressureRange::new_hpa(1010.0, 1015.0),
+        };
+        let report = Report::ForecastFull(DailyFullReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![
+                make_day(2, Kind::Thunderstorm),
+                make_day(3, Kind::Clouds(Clouds::Light)),
+            ],
+        });
+        let profile = builtin_profiles()
+            .into_iter()
+            .find(|profile| profile.name == "running")
+            .unwrap();
+        (report, profile)
+    }
+
+    #[test]
+    fn formats_activity_scores() {
+        let (report, profile) = make_activity_report();
+        let result = JsonFormatter::new(ReportContent::Activity(profile)).format(&report);
+        let expected = r#"{
+  "coordinates": {
+    "latitude": 1.23,
+    "longitude": 45.67
+  },
+  "activity": "running",
+  "max_score": 10,
+  "days": [
+    {
+      "date": "2025-06-02",
+      "score": 6
+    },
+    {
+      "date": "2025-06-03",
+      "score": 9
+    }
+  ],
+  "best_day": "2025-06-03"
+}
+"#;
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::format::Formatter;
+use crate::format::common::list_builder::write_param;
+use crate::format::common::location::{
+    describe_elevation_mismatch, describe_sections, join_sections,
+};
+use crate::format::{current, daily};
+use crate::types::report::Report;
+use crate::types::watch::Change;
+
+pub struct ListFormatter;
+
+impl ListFormatter {
+    fn describe(&self, report: &Report, changes: &[Change]) -> String {
+        let list = match report {
+            Report::PastFull(inner) => daily::list::describe(&inner.clone().into()),
+            Report::PastPartial(inner) => daily::list::describe(inner),
+            Report::CurrentFull(inner) => current::list::describe(&inner.clone().into(), changes),
+            Report::CurrentPartial(inner) => current::list::describe(inner, changes),
+            Report::ForecastFull(inner) => daily::list::describe(&inner.clone().into()),
+            Report::ForecastPartial(inner) => daily::list::describe(inner),
+        };
+        match describe_elevation_mismatch(report.location()) {
+            Some(mismatch) => {
+                let mut warning = String::new();
+                write_param(&mut warning, "Warning", mismatch);
+                warning + &list
+            }
+            None => list,
+        }
+    }
+}
+
+impl Formatter for ListFormatter {
+    fn format(&self, report: &Report) -> String {
+        self.describe(report, &[])
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        describe_sections(reports, |report| self.format(report))
+    }
+    fn format_changes(&self, reports: &[Report], changes: &[Vec<Change>]) -> String {
+        match (reports, changes) {
+            ([report], [changes]) => self.describe(report, changes),
+            _ => join_sections(
+                reports
+                    .iter()
+                    .zip(changes)
+                    .map(|(report, changes)| (report, self.describe(report, changes))),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::attributes::WeatherAttribute;
+    use crate::types::report::CurrentPartialReport;
+    use crate::types::units::*;
+    use crate::types::watch::Trend;
+
+    #[test]
+    fn warns_about_elevation_mismatch() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(49.2, 19.9));
+        report.location.requested_elevation = Some(Meters::from(1780.0));
+        report.location.elevation = Some(Meters::from(1250.0));
+        let result = ListFormatter.format(&Report::CurrentPartial(report));
+        let expected = "Warning: requested elevation 1780 m differs from model elevation 1250 m by 530 m\n\
+                        Coordinates: 49.20000°, 19.90000°\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn marks_trends_of_each_location() {
+        let make_report = |latitude, temperature| {
+            let mut report = CurrentPartialReport::new_empty(Coordinates::new(latitude, 21.0));
+            report.temperature = Some(Temperature::new_celsius(temperature));
+            report.pressure = Some(Pressure::new_hpa(1008.0));
+            Report::CurrentPartial(report)
+        };
+        let reports = [make_report(52.25, 8.0), make_report(50.06, 8.0)];
+        let changes = [
+            vec![
+                Change {
+                    attribute: WeatherAttribute::Temperature,
+                    trend: Trend::Up,
+                },
+                Change {
+                    attribute: WeatherAttribute::Pressure,
+                    trend: Trend::Down,
+                },
+            ],
+            vec![Change {
+                attribute: WeatherAttribute::Temperature,
+                trend: Trend::Down,
+            }],
+        ];
+        let result = ListFormatter.format_changes(&reports, &changes);
+        let expected = "52.2500°, 21.0000°:\n\
+                        Coordinates: 52.25000°, 21.00000°\n\
+                        Temperature: 8.0°C ↑\n\
+                        Pressure: 1008.0 hPa ↓\n\
+                        \n\
+                        50.0600°, 21.0000°:\n\
+                        Coordinates: 50.06000°, 21.00000°\n\
+                        Temperature: 8.0°C ↓\n\
+                        Pressure: 1008.0 hPa\n";
+        assert_eq!(result, expected);
+        assert_eq!(
+            ListFormatter.format_changes(&reports[..1], &changes[1..]),
+            ListFormatter
+                .format(&reports[0])
+                .replace("8.0°C", "8.0°C ↓")
+        );
+    }
+}
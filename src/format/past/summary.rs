@@ -1,49 +1,38 @@
+use crate::format::common::relative_date::describe_date;
 use crate::format::common::summary::*;
-use crate::types::report::DailyFullReport;
 use crate::types::report::*;
 use crate::types::units::*;
 use crate::types::weather::*;
 
-pub fn describe(report: &DailyFullReport) -> String {
-    let mut result = String::new();
-    for data in report.data.iter().rev().skip(1).rev() {
-        let date_desc = describe_date(&data.date);
-        let day_summary = describe_day(date_desc, data);
-        result.push_str(&day_summary);
-        result.push('\n');
-    }
-    if let Some(data) = report.data.last() {
-        let date_desc = String::from("Yesterday");
-        let day_summary = describe_day(date_desc, data);
-        result.push_str(&day_summary);
-    }
-    result
-}
-
-fn describe_date(date: &Date) -> String {
-    format!("On {}", date.format("%d.%m.%Y"))
+pub fn describe(report: &DailyPartialReport, today: &Date) -> String {
+    let days: Vec<String> = report
+        .data
+        .iter()
+        .map(|data| describe_day(describe_date(&data.date, today), data))
+        .collect();
+    days.join("\n")
 }
 
-fn describe_day(date_desc: String, data: &DailyFullData) -> String {
-    let temperature_desc = describe_temperature_range(&data.temperature_range);
-    let kind_desc = describe_kind(&data.kind);
-    let cloud_coverage_desc = describe_cloud_coverage_range(&data.cloud_coverage_range);
-    let humidity_desc = describe_humidity_range(&data.humidity_range);
-    let wind_desc = describe_wind_scope(&data.wind);
-    let pressure_desc = describe_pressure_range(&data.pressure_range);
-    #[allow(clippy::uninlined_format_args)]
-    {
-        format!(
-            "{} {}.\n{} and {}.\n{} with {}.\n{}.\n",
-            date_desc,
-            temperature_desc,
-            kind_desc,
-            cloud_coverage_desc,
-            humidity_desc,
-            wind_desc,
-            pressure_desc,
-        )
-    }
+fn describe_day(date_desc: String, data: &DailyPartialData) -> String {
+    let description = DayDescription {
+        temperature: data.temperature_range.as_ref().map(|range| {
+            add_temperature_anomaly(
+                describe_temperature_range(range),
+                range,
+                data.temperature_normal.as_ref(),
+            )
+        }),
+        kind: data.kind.as_ref().map(describe_kind),
+        cloud_coverage: data
+            .cloud_coverage_range
+            .as_ref()
+            .map(describe_cloud_coverage_range),
+        humidity: data.humidity_range.as_ref().map(describe_humidity_range),
+        wind: data.wind.as_ref().map(describe_wind_scope),
+        pressure: data.pressure_range.as_ref().map(describe_pressure_range),
+        gear: None,
+    };
+    describe_day_sentences(&date_desc, description, Tense::Past)
 }
 
 fn describe_kind(kind: &Kind) -> String {
@@ -124,13 +113,6 @@ fn describe_pressure_range(pressure_range: &PressureRange) -> String {
 mod tests {
     use super::*;
 
-    #[test]
-    fn describes_dates() {
-        let date = Date::from_ymd_opt(2025, 07, 18).unwrap();
-        let expected = "On 18.07.2025";
-        assert_eq!(describe_date(&date), expected);
-    }
-
     #[test]
     fn describes_temperature_range_in_celsius() {
         let range = TemperatureRange::new_celsius(15.1, 33.3);
@@ -291,12 +273,14 @@ mod tests {
         );
     }
 
-    fn generate_report_for_3_days() -> DailyFullReport {
-        let date = Date::from_ymd_opt(2025, 08, 22).unwrap();
+    fn generate_report_for_3_days() -> DailyPartialReport {
+        let date = Date::from_ymd_opt(2025, 8, 22).unwrap();
         let daily_data_1 = DailyFullData {
             date,
             kind: Kind::Clouds(Clouds::Light),
             temperature_range: TemperatureRange::new_celsius(20.6, 26.8),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: PercentageRange {
                 min: Percentage::from(27),
                 max: Percentage::from(29),
@@ -316,6 +300,8 @@ mod tests {
             date,
             kind: Kind::Clouds(Clouds::Clear),
             temperature_range: TemperatureRange::new_celsius(3.4, 9.0),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: PercentageRange {
                 min: Percentage::from(19),
                 max: Percentage::from(96),
@@ -339,6 +325,8 @@ mod tests {
                 heat: PrecipitationHeat::Normal,
             }),
             temperature_range: TemperatureRange::new_celsius(9.5, 15.5),
+            temperature_normal: None,
+            uv_index: None,
             cloud_coverage_range: PercentageRange {
                 min: Percentage::from(0),
                 max: Percentage::from(1),
@@ -355,21 +343,23 @@ mod tests {
         };
 
         DailyFullReport {
+            location: Coordinates::new(1.23, 45.67).into(),
             data: vec![daily_data_1, daily_data_2, daily_data_3],
         }
+        .into()
     }
     #[test]
     fn describe_full_report() {
         let report = generate_report_for_3_days();
-        let result = describe(&report);
-        let expected_day1 = "On 22.08.2025 it was hot \
+        let result = describe(&report, &Date::from_ymd_opt(2025, 8, 25).unwrap());
+        let expected_day1 = "On Friday it was hot \
             with temperatures starting at 20.6°C and reaching 26.8°C.\n\
             The sky was mostly clear \
             and clouds covered from 27% to 29% of the sky.\n\
             The air was dry at 14% to 19% humidity \
             with mostly southeast wind blowing at maximum 3.3 m/s.\n\
             Normal pressure reached 995.8 hPa at lowest up to 1019.8 hPa.\n";
-        let expected_day2 = "On 23.08.2025 it was cool \
+        let expected_day2 = "On Saturday it was cool \
             with temperatures starting at 3.4°C and reaching 9.0°C.\n\
             The sky was clear \
             and clouds covered from 19% to 96% of the sky.\n\
@@ -386,4 +376,70 @@ mod tests {
         let expected = format!("{expected_day1}\n{expected_day2}\n{expected_day3}");
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn describes_only_selected_attributes() {
+        let data = DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, 24).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(9.5, 15.5)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: Some(WindScope {
+                speed_range: SpeedRange::new_meters_per_second(0.05, 0.15),
+                dominant_direction: Azimuth::from(273.3),
+            }),
+            pressure_range: None,
+        };
+        let report = DailyPartialReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            data: vec![data],
+        };
+        let result = describe(&report, &Date::from_ymd_opt(2025, 8, 25).unwrap());
+        let expected = "Yesterday it was warm \
+            with temperatures starting at 9.5°C and reaching 15.5°C.\n\
+            There was mostly no wind.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn describes_temperature_anomaly() {
+        let data = DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, 24).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(9.5, 15.5)),
+            temperature_normal: Some(Temperature::new_celsius(16.0)),
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        let result = describe_day("Yesterday".into(), &data);
+        let expected = "Yesterday it was warm \
+            with temperatures starting at 9.5°C and reaching 15.5°C, \
+            3.5°C below the 30-year average.\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn describes_day_starting_with_any_attribute() {
+        let data = DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, 24).unwrap(),
+            kind: Some(Kind::Thunderstorm),
+            temperature_range: None,
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: Some(PercentageRange::new(30, 60)),
+            wind: None,
+            pressure_range: None,
+        };
+        let result = describe_day("On 24.08.2025".into(), &data);
+        let expected = "On 24.08.2025 a thunderstorm was raging.\n\
+            The air was humid at 30% to 60%.\n";
+        assert_eq!(result, expected);
+    }
 }
@@ -0,0 +1,146 @@
+use crate::format::Formatter;
+use crate::format::common::list_builder::write_param;
+use crate::format::common::location::{describe_sections, write_location_param};
+use crate::format::common::statistics::{compute_statistics, describe_pressure_trend};
+use crate::types::report::Report;
+use crate::types::statistics::*;
+use crate::types::units::*;
+use std::fmt::Display;
+
+const DATE_FORMAT: &str = "%d.%m.%Y";
+
+pub struct StatisticsFormatter;
+
+impl Formatter for StatisticsFormatter {
+    fn format(&self, report: &Report) -> String {
+        let Some(statistics) = compute_statistics(report) else {
+            return String::new();
+        };
+        let mut result = String::new();
+        write_location_param(&mut result, report.location());
+        describe(&mut result, &statistics);
+        result
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        describe_sections(reports, |report| self.format(report))
+    }
+}
+
+fn describe(result: &mut String, statistics: &DailyStatistics) {
+    write_param(
+        result,
+        "Period",
+        format!(
+            "{} - {} ({} days)",
+            statistics.first_date.format(DATE_FORMAT),
+            statistics.last_date.format(DATE_FORMAT),
+            statistics.day_count
+        ),
+    );
+    if let Some(temperature) = &statistics.temperature {
+        write_param(result, "Temperature", describe_attribute(temperature));
+    }
+    if let Some(cloud_coverage) = &statistics.cloud_coverage {
+        write_param(result, "Cloud coverage", describe_attribute(cloud_coverage));
+    }
+    if let Some(humidity) = &statistics.humidity {
+        write_param(result, "Humidity", describe_attribute(humidity));
+    }
+    if let Some(wind_speed) = &statistics.wind_speed {
+        write_param(result, "Wind speed", describe_attribute(wind_speed));
+    }
+    if let Some(direction) = &statistics.prevailing_wind_direction {
+        write_param(result, "Prevailing wind", describe_direction(direction));
+    }
+    if let Some(pressure) = &statistics.pressure {
+        write_param(result, "Pressure", describe_attribute(pressure));
+    }
+    if let Some(trend) = statistics.pressure_trend {
+        write_param(result, "Pressure trend", describe_pressure_trend(trend));
+    }
+    if let Some(rainy_days) = statistics.rainy_days {
+        write_param(
+            result,
+            "Rainy days",
+            format!("{rainy_days} of {}", statistics.day_count),
+        );
+    }
+}
+
+fn describe_attribute<T: Display>(statistics: &AttributeStatistics<T>) -> String {
+    let AttributeStatistics { mean, min, max } = statistics;
+    format!(
+        "mean {mean}, lowest {} on {}, highest {} on {}",
+        min.value,
+        min.date.format(DATE_FORMAT),
+        max.value,
+        max.date.format(DATE_FORMAT)
+    )
+}
+
+fn describe_direction(direction: &Azimuth) -> String {
+    format!(
+        "{} ({direction})",
+        direction.to_cardinal_direction().to_name()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::report::*;
+    use crate::types::weather::*;
+
+    fn make_day(day: u32, temperature: (f32, f32), pressure: f32) -> DailyFullData {
+        DailyFullData {
+            date: Date::from_ymd_opt(2025, 8, day).unwrap(),
+            kind: Kind::Precipitation(Precipitation {
+                kind: PrecipitationKind::Rain,
+                intensity: PrecipitationIntensity::Moderate,
+                heat: PrecipitationHeat::Normal,
+            }),
+            temperature_range: TemperatureRange::new_celsius(temperature.0, temperature.1),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: PercentageRange::new(20, 80),
+            humidity_range: PercentageRange::new(50, 90),
+            wind: WindScope {
+                speed_range: SpeedRange::new_meters_per_second(2.0, 6.0),
+                dominant_direction: Azimuth::from(225.0),
+            },
+            pressure_range: PressureRange::new_hpa(pressure - 2.0, pressure + 2.0),
+        }
+    }
+
+    #[test]
+    fn formats_statistics_of_past_report() {
+        let report = Report::PastFull(DailyFullReport {
+            location: Coordinates::new(52.23, 21.01).into(),
+            data: vec![
+                make_day(1, (12.0, 22.0), 1012.0),
+                make_day(2, (9.5, 18.0), 1008.0),
+                make_day(3, (14.0, 26.5), 1004.0),
+            ],
+        });
+        let result = StatisticsFormatter.format(&report);
+        let expected = "Coordinates: 52.23000°, 21.01000°\n\
+                        Period: 01.08.2025 - 03.08.2025 (3 days)\n\
+                        Temperature: mean 17.0°C, lowest 9.5°C on 02.08.2025, highest 26.5°C on 03.08.2025\n\
+                        Cloud coverage: mean 50%, lowest 20% on 01.08.2025, highest 80% on 01.08.2025\n\
+                        Humidity: mean 70%, lowest 50% on 01.08.2025, highest 90% on 01.08.2025\n\
+                        Wind speed: mean 4.0 m/s, lowest 2.0 m/s on 01.08.2025, highest 6.0 m/s on 01.08.2025\n\
+                        Prevailing wind: southwest (225.0°)\n\
+                        Pressure: mean 1008.0 hPa, lowest 1002.0 hPa on 03.08.2025, highest 1014.0 hPa on 01.08.2025\n\
+                        Pressure trend: falling\n\
+                        Rainy days: 3 of 3\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn skips_current_report() {
+        let report = Report::CurrentPartial(CurrentPartialReport::new_empty(Coordinates::new(
+            52.23, 21.01,
+        )));
+        assert_eq!(StatisticsFormatter.format(&report), "");
+    }
+}
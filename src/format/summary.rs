@@ -0,0 +1,160 @@
+use crate::format::Formatter;
+use crate::format::common::location::{
+    describe_elevation_mismatch, describe_sections, join_sections,
+};
+use crate::format::{current, forecast, past};
+use crate::port::clock::Clock;
+use crate::types::gear::GearRule;
+use crate::types::report::Report;
+use crate::types::units::Date;
+use crate::types::watch::Change;
+
+pub struct SummaryFormatter<C: Clock> {
+    clock: C,
+    gear_rules: Vec<GearRule>,
+}
+
+impl<C: Clock> SummaryFormatter<C> {
+    pub fn new(clock: C) -> Self {
+        Self::with_gear(clock, Vec::new())
+    }
+
+    pub fn with_gear(clock: C, gear_rules: Vec<GearRule>) -> Self {
+        Self { clock, gear_rules }
+    }
+
+    fn describe(&self, report: &Report, changes: &[Change]) -> String {
+        let today = report.location().today(self.clock.now());
+        let mut summary = describe_weather(report, &today, &self.gear_rules, changes);
+        if let Some(mismatch) = describe_elevation_mismatch(report.location()) {
+            summary.push_str(&format!("Note: {mismatch}, so values may be inaccurate.\n"));
+        }
+        summary
+    }
+
+    fn label(&self, report: &Report, summary: String) -> String {
+        match report.location().place_name() {
+            Some(place_name) => format!("{place_name}:\n{summary}"),
+            None => summary,
+        }
+    }
+}
+
+impl<C: Clock> Formatter for SummaryFormatter<C> {
+    fn format(&self, report: &Report) -> String {
+        self.label(report, self.describe(report, &[]))
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        describe_sections(reports, |report| self.describe(report, &[]))
+    }
+    fn format_changes(&self, reports: &[Report], changes: &[Vec<Change>]) -> String {
+        match (reports, changes) {
+            ([report], [changes]) => self.label(report, self.describe(report, changes)),
+            _ => join_sections(
+                reports
+                    .iter()
+                    .zip(changes)
+                    .map(|(report, changes)| (report, self.describe(report, changes))),
+            ),
+        }
+    }
+}
+
+fn describe_weather(
+    report: &Report,
+    today: &Date,
+    gear_rules: &[GearRule],
+    changes: &[Change],
+) -> String {
+    match report {
+        Report::PastFull(inner) => past::summary::describe(&inner.clone().into(), today),
+        Report::PastPartial(inner) => past::summary::describe(inner, today),
+        Report::CurrentFull(inner) => {
+            current::summary::describe(&inner.clone().into(), gear_rules, changes)
+        }
+        Report::CurrentPartial(inner) => current::summary::describe(inner, gear_rules, changes),
+        Report::ForecastFull(inner) => {
+            forecast::summary::describe(&inner.clone().into(), today, gear_rules)
+        }
+        Report::ForecastPartial(inner) => forecast::summary::describe(inner, today, gear_rules),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::mocks::MockClock;
+    use crate::types::location::{Location, PlaceName};
+    use crate::types::report::*;
+    use crate::types::units::*;
+    use chrono::FixedOffset;
+
+    fn make_formatter(now: &str) -> SummaryFormatter<MockClock> {
+        let mut clock = MockClock::new();
+        clock
+            .expect_now()
+            .return_const(now.parse::<chrono::DateTime<chrono::Utc>>().unwrap());
+        SummaryFormatter::new(clock)
+    }
+
+    #[test]
+    fn prefixes_summary_with_place_name() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(52.23, 21.01));
+        report.temperature = Some(Temperature::new_celsius(12.5));
+        let unnamed = Report::CurrentPartial(report.clone());
+        report.location.set_place_name(PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        });
+        let named = Report::CurrentPartial(report);
+        let formatter = make_formatter("2025-08-25T12:00:00Z");
+        let summary = formatter.format(&unnamed);
+        assert_eq!(
+            formatter.format(&named),
+            format!("Warsaw, Poland:\n{summary}")
+        );
+    }
+
+    #[test]
+    fn notes_elevation_mismatch() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(49.2, 19.9));
+        report.temperature = Some(Temperature::new_celsius(2.5));
+        report.location.requested_elevation = Some(Meters::from(1780.0));
+        report.location.elevation = Some(Meters::from(1250.0));
+        let formatter = make_formatter("2025-08-25T12:00:00Z");
+        let result = formatter.format(&Report::CurrentPartial(report));
+        assert!(result.ends_with(
+            "Note: requested elevation 1780 m differs from model elevation 1250 m by 530 m, \
+             so values may be inaccurate.\n"
+        ));
+    }
+
+    #[test]
+    fn labels_days_relative_to_local_date_of_location() {
+        let make_data = |day| DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, day).unwrap(),
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(9.5, 15.5)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        let mut location = Location::new(Coordinates::new(35.68, 139.65));
+        location.utc_offset = FixedOffset::east_opt(9 * 3600);
+        let report = Report::ForecastPartial(DailyPartialReport {
+            location,
+            data: vec![make_data(25), make_data(26), make_data(28)],
+        });
+        let formatter = make_formatter("2025-08-24T16:30:00Z");
+        let result = formatter.format(&report);
+        let labels: Vec<&str> = result
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split(" it will").next().unwrap())
+            .collect();
+        assert_eq!(labels, ["Today", "Tomorrow", "On Thursday"]);
+    }
+}
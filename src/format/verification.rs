@@ -0,0 +1,166 @@
+use crate::format::Formatter;
+use crate::format::common::list_builder::write_param;
+use crate::format::common::location::{describe_sections, write_location_param};
+use crate::port::clock::Clock;
+use crate::types::report::Report;
+use crate::types::verification::{self, ForecastRecord, LeadTimeAccuracy, VerifiedAttribute};
+
+pub struct VerificationFormatter<C: Clock> {
+    records: Vec<ForecastRecord>,
+    clock: C,
+}
+
+impl<C: Clock> VerificationFormatter<C> {
+    pub fn new(records: Vec<ForecastRecord>, clock: C) -> Self {
+        Self { records, clock }
+    }
+
+    fn describe(&self, report: &Report) -> String {
+        let Some(observed) = report.to_daily_partial() else {
+            return String::new();
+        };
+        let today = report.location().today(self.clock.now());
+        let accuracy = verification::verify(&self.records, &observed, &today);
+        if accuracy.is_empty() {
+            return "No stored forecasts of these days to verify\n".into();
+        }
+        accuracy
+            .iter()
+            .map(describe_accuracy)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<C: Clock> Formatter for VerificationFormatter<C> {
+    fn format(&self, report: &Report) -> String {
+        let mut result = String::new();
+        write_location_param(&mut result, report.location());
+        result + &self.describe(report)
+    }
+    fn format_many(&self, reports: &[Report]) -> String {
+        describe_sections(reports, |report| self.describe(report))
+    }
+}
+
+fn describe_accuracy(accuracy: &LeadTimeAccuracy) -> String {
+    let mut result = String::new();
+    let days = accuracy.lead_days;
+    let unit = if days == 1 { "day" } else { "days" };
+    write_param(&mut result, "Lead time", format!("{days} {unit}"));
+    write_param(&mut result, "Verified forecasts", accuracy.forecast_count);
+    for (attribute, error) in &accuracy.mean_absolute_errors {
+        let (label, unit) = describe_attribute(*attribute);
+        write_param(&mut result, label, format!("{error:.1}{unit}"));
+    }
+    if accuracy.kind_count > 0 {
+        let rate = accuracy.kind_hits as f32 / accuracy.kind_count as f32 * 100.0;
+        write_param(
+            &mut result,
+            "Weather kind hit rate",
+            format!(
+                "{rate:.0}% ({} of {})",
+                accuracy.kind_hits, accuracy.kind_count
+            ),
+        );
+    }
+    result
+}
+
+fn describe_attribute(attribute: VerifiedAttribute) -> (&'static str, &'static str) {
+    match attribute {
+        VerifiedAttribute::LowestTemperature => ("Lowest temperature error", "°C"),
+        VerifiedAttribute::HighestTemperature => ("Highest temperature error", "°C"),
+        VerifiedAttribute::CloudCoverage => ("Cloud coverage error", "%"),
+        VerifiedAttribute::Humidity => ("Humidity error", "%"),
+        VerifiedAttribute::WindSpeed => ("Wind speed error", " m/s"),
+        VerifiedAttribute::Pressure => ("Pressure error", " hPa"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::mocks::MockClock;
+    use crate::types::report::*;
+    use crate::types::units::*;
+    use crate::types::verification::{DailyValues, KindCategory};
+    use crate::types::weather::*;
+
+    fn make_formatter(records: Vec<ForecastRecord>) -> VerificationFormatter<MockClock> {
+        let mut clock = MockClock::new();
+        clock.expect_now().return_const(
+            "2025-03-04T12:00:00Z"
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap(),
+        );
+        VerificationFormatter::new(records, clock)
+    }
+
+    fn make_record(issued: u32, date: u32, temperature: (f32, f32)) -> ForecastRecord {
+        ForecastRecord {
+            coordinates: Coordinates::new(52.25, 21.0),
+            issued: Date::from_ymd_opt(2025, 3, issued).unwrap(),
+            date: Date::from_ymd_opt(2025, 3, date).unwrap(),
+            values: DailyValues {
+                kind: Some(KindCategory::Clear),
+                lowest_temperature_celsius: Some(temperature.0),
+                highest_temperature_celsius: Some(temperature.1),
+                pressure_hpa: Some(1015.0),
+                ..DailyValues::default()
+            },
+        }
+    }
+
+    fn make_report() -> Report {
+        let make_data = |day, kind| DailyPartialData {
+            date: Date::from_ymd_opt(2025, 3, day).unwrap(),
+            kind: Some(kind),
+            temperature_range: Some(TemperatureRange::new_celsius(1.0, 7.0)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: Some(PressureRange::new_hpa(1010.0, 1012.0)),
+        };
+        Report::PastPartial(DailyPartialReport {
+            location: Coordinates::new(52.25, 21.0).into(),
+            data: vec![
+                make_data(2, Kind::Clouds(Clouds::Light)),
+                make_data(3, Kind::Fog(Fog::Normal)),
+            ],
+        })
+    }
+
+    #[test]
+    fn describes_accuracy_per_lead_time() {
+        let formatter = make_formatter(vec![
+            make_record(1, 2, (0.0, 6.0)),
+            make_record(2, 3, (2.0, 9.0)),
+            make_record(1, 3, (4.0, 7.0)),
+        ]);
+        let result = formatter.format(&make_report());
+        let expected = "Coordinates: 52.25000°, 21.00000°\n\
+            Lead time: 1 day\n\
+            Verified forecasts: 2\n\
+            Lowest temperature error: 1.0°C\n\
+            Highest temperature error: 1.5°C\n\
+            Pressure error: 4.0 hPa\n\
+            Weather kind hit rate: 50% (1 of 2)\n\
+            \n\
+            Lead time: 2 days\n\
+            Verified forecasts: 1\n\
+            Lowest temperature error: 3.0°C\n\
+            Highest temperature error: 0.0°C\n\
+            Pressure error: 4.0 hPa\n\
+            Weather kind hit rate: 0% (0 of 1)\n";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn notes_missing_forecasts() {
+        let result = make_formatter(Vec::new()).format(&make_report());
+        assert!(result.ends_with("No stored forecasts of these days to verify\n"));
+    }
+}
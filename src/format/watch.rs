@@ -0,0 +1,124 @@
+use crate::types::attributes::WeatherAttribute;
+use crate::types::watch::{Change, Trend};
+use chrono::NaiveTime;
+
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+const HIGHLIGHT: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+const TIME_FORMAT: &str = "%H:%M:%S";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Refresh {
+    Updated,
+    Failed(String),
+}
+
+pub fn draw_screen(
+    report: &str,
+    refresh: &Refresh,
+    time: NaiveTime,
+    next_time: NaiveTime,
+    styled: bool,
+) -> String {
+    let status = match refresh {
+        Refresh::Updated => format!(
+            "Updated at {}, next refresh at {}",
+            time.format(TIME_FORMAT),
+            next_time.format(TIME_FORMAT)
+        ),
+        Refresh::Failed(error) => format!(
+            "Refresh failed at {}: {error}, retrying at {}",
+            time.format(TIME_FORMAT),
+            next_time.format(TIME_FORMAT)
+        ),
+    };
+    let clear_screen = if styled { CLEAR_SCREEN } else { "" };
+    format!("{clear_screen}{report}\n{status}\n")
+}
+
+/// Highlights lines that changed since the previous refresh, the previous report text is
+/// formatted without trends, so lines marked with a trend are always highlighted
+pub fn highlight_changes(previous_text: &str, marked_text: &str, styled: bool) -> String {
+    if previous_text.is_empty() || !styled {
+        return marked_text.into();
+    }
+    let previous_lines: Vec<&str> = previous_text.lines().collect();
+    let mut result = String::new();
+    for (index, line) in marked_text.split_inclusive('\n').enumerate() {
+        let (content, ending) = match line.strip_suffix('\n') {
+            Some(content) => (content, "\n"),
+            None => (line, ""),
+        };
+        if previous_lines.get(index) == Some(&content) {
+            result.push_str(line);
+        } else {
+            result += &format!("{HIGHLIGHT}{content}{RESET}{ending}");
+        }
+    }
+    result
+}
+
+/// Describes whether the attribute went up or down, to be appended to its value
+pub fn mark_trend(changes: &[Change], attribute: WeatherAttribute) -> &'static str {
+    let trend = changes
+        .iter()
+        .find(|change| change.attribute == attribute)
+        .map(|change| change.trend);
+    match trend {
+        Some(Trend::Up) => " ↑",
+        Some(Trend::Down) => " ↓",
+        Some(Trend::Changed) | None => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_changed_lines_in_place() {
+        let previous = "Weather: clear sky\nTemperature: 12.0°C\nPressure: 1013.2 hPa\n";
+        let marked = "Weather: fog\nTemperature: 12.5°C ↑\nPressure: 1013.2 hPa\n";
+        let result = highlight_changes(previous, marked, true);
+        let expected = "\x1b[1mWeather: fog\x1b[0m\n\
+                        \x1b[1mTemperature: 12.5°C ↑\x1b[0m\n\
+                        Pressure: 1013.2 hPa\n";
+        assert_eq!(result, expected);
+        assert_eq!(highlight_changes(previous, marked, false), marked);
+        assert_eq!(highlight_changes("", marked, true), marked);
+    }
+
+    #[test]
+    fn marks_trend_of_attribute() {
+        let changes = [
+            Change {
+                attribute: WeatherAttribute::Temperature,
+                trend: Trend::Up,
+            },
+            Change {
+                attribute: WeatherAttribute::WeatherKind,
+                trend: Trend::Changed,
+            },
+        ];
+        assert_eq!(mark_trend(&changes, WeatherAttribute::Temperature), " ↑");
+        assert_eq!(mark_trend(&changes, WeatherAttribute::WeatherKind), "");
+        assert_eq!(mark_trend(&changes, WeatherAttribute::Pressure), "");
+    }
+
+    #[test]
+    fn draws_screen_with_refresh_status() {
+        let time = NaiveTime::from_hms_opt(14, 5, 0).unwrap();
+        let next_time = NaiveTime::from_hms_opt(14, 20, 0).unwrap();
+        let result = draw_screen("Report\n", &Refresh::Updated, time, next_time, true);
+        assert_eq!(
+            result,
+            "\x1b[H\x1b[2JReport\n\nUpdated at 14:05:00, next refresh at 14:20:00\n"
+        );
+        let refresh = Refresh::Failed("Failed to fetch weather report".into());
+        let result = draw_screen("Report\n", &refresh, time, next_time, false);
+        assert_eq!(
+            result,
+            "Report\n\nRefresh failed at 14:05:00: Failed to fetch weather report, retrying at 14:20:00\n"
+        );
+    }
+}
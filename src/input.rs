@@ -1 +1,5 @@
 pub mod cli;
+pub mod condition;
+pub mod config;
+pub mod coordinates;
+pub mod timezone;
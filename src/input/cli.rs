@@ -1,10 +1,29 @@
+use crate::external::{LocationSource, PlaceNameSource};
+use crate::format::{OutputFormat, ReportContent};
+use crate::input::config::{self, Config, GearRuleConfig, PlaceNames};
+use crate::input::coordinates;
+use crate::location_manager::{LocationAction, LocationParameters, Place};
+use crate::output::{Destination, Verbosity};
 use crate::port::weather::*;
+use crate::store::file;
+use crate::store::forecasts::FORECASTS_FILE_NAME;
+use crate::store::history::HISTORY_FILE_NAME;
+use crate::store::locations::{self, LOCATIONS_FILE_NAME, LocationStore};
+use crate::store::places::PLACES_FILE_NAME;
+use crate::types::activity::{ActivityProfile, builtin_profiles};
 use crate::types::attributes::*;
+use crate::types::condition::Condition;
+use crate::types::gear::{self, GearRule};
+use crate::types::history::{HistoryFilter, HistoryQuery};
+use crate::types::timezone::Timezone;
 use crate::types::units::*;
+use crate::types::verification::KindCategory;
+use crate::types::watch;
 use crate::weather_reporter::Parameters;
 use clap::builder::PossibleValue;
-use clap::{Parser, Subcommand, ValueEnum};
-use std::str::FromStr;
+use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use strum::{IntoEnumIterator, VariantArray};
 
 impl ValueEnum for WeatherAttribute {
@@ -24,41 +43,58 @@ impl ValueEnum for WeatherAttribute {
     }
 }
 
-impl FromStr for Coordinates {
-    type Err = String;
-
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let values: Vec<&str> = string.split(',').collect();
-        if values.len() != 2 {
-            return Err("Coordinates must be in format 'latitude,longitude'".into());
-        }
-        let latitude: f32 = values[0].trim().parse().map_err(|_| "Invalid latitude")?;
-        let longitude: f32 = values[1].trim().parse().map_err(|_| "Invalid longitude")?;
-        Ok(Self::new(latitude, longitude))
-    }
-}
-
 #[derive(Subcommand)]
 enum Command {
     /// Report current weather
     Now {
-        /// Format report as summary
+        /// Format report as summary, also when attributes are selected with list
         #[arg(long, group = "now_format")]
         summary: bool,
 
+        /// Format report as JSON
+        #[arg(long, group = "now_format")]
+        json: bool,
+
+        /// Format report as CSV
+        #[arg(long, group = "now_format")]
+        csv: bool,
+
         /// Format report as list of all or selected attributes
-        #[arg(long, group = "now_format", value_delimiter=',', num_args=0..)]
+        #[arg(long, value_delimiter=',', num_args=0..)]
         list: Option<Vec<WeatherAttribute>>,
+
+        /// Add what to wear to summary, using gear rules from config file or built-in ones
+        #[arg(long, conflicts_with_all = ["json", "csv", "list"])]
+        gear: bool,
+
+        /// Refresh report in place and highlight changes, every interval like 90s, 5m or 1h;
+        /// defaults to Open-Meteo's update cadence of 15 minutes
+        #[arg(
+            long,
+            value_name = "INTERVAL",
+            num_args = 0..=1,
+            require_equals = true,
+            value_parser = parse_watch_interval
+        )]
+        watch: Option<Option<Duration>>,
     },
 
     /// Report forecast
     Forecast {
-        /// Format report as summary
+        /// Format report as summary, also when attributes are selected with list
         #[arg(long, group = "forecast_format")]
         summary: bool,
 
+        /// Format report as JSON
+        #[arg(long, group = "forecast_format")]
+        json: bool,
+
+        /// Format report as CSV
+        #[arg(long, group = "forecast_format")]
+        csv: bool,
+
         /// Format report as list of all or selected attributes
-        #[arg(long, group = "forecast_format", value_delimiter=',', num_args=0..)]
+        #[arg(long, value_delimiter=',', num_args=0..)]
         list: Option<Vec<WeatherAttribute>>,
 
         /// Report for today
@@ -68,21 +104,168 @@ enum Command {
         /// Number of days to report
         #[arg(long, group = "forecast_time", value_parser = clap::value_parser!(u8).range(1..16))]
         days: Option<DayCount>,
+
+        /// Score days from 0 to 10 for activity: running, cycling, laundry, bbq or one from config
+        /// file, forecast covers a week unless days are given
+        #[arg(long, conflicts_with_all = ["summary", "list"])]
+        activity: Option<String>,
+
+        /// Add what to wear to summary of each day, using gear rules from config file or built-in
+        /// ones
+        #[arg(long, conflicts_with_all = ["json", "csv", "list", "activity"])]
+        gear: bool,
     },
 
     /// Report past weather
     Past {
-        /// Format report as summary
+        /// Format report as summary, also when attributes are selected with list
         #[arg(long, group = "past_format")]
         summary: bool,
 
+        /// Format report as JSON
+        #[arg(long, group = "past_format")]
+        json: bool,
+
+        /// Format report as CSV
+        #[arg(long, group = "past_format")]
+        csv: bool,
+
         /// Format report as list of all or selected attributes
-        #[arg(long, group = "past_format", value_delimiter=',', num_args=0..)]
+        #[arg(long, value_delimiter=',', num_args=0..)]
         list: Option<Vec<WeatherAttribute>>,
 
-        /// Number of days to report
-        #[arg(long)]
+        /// Report statistics of the period instead of each day, of all or listed attributes, can
+        /// be combined with JSON or CSV
+        #[arg(long, conflicts_with = "summary")]
+        stats: bool,
+
+        /// Number of days to report, days older than 92 days come from the weather archive
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..))]
+        days: DayCount,
+    },
+
+    /// Report heating and cooling degree-days of past or forecast days
+    #[command(group(ArgGroup::new("degree_days_period").required(true)))]
+    DegreeDays {
+        /// Format report as JSON
+        #[arg(long, group = "degree_days_format")]
+        json: bool,
+
+        /// Format report as CSV
+        #[arg(long, group = "degree_days_format")]
+        csv: bool,
+
+        /// Number of past days to report
+        #[arg(long, group = "degree_days_period", value_parser = clap::value_parser!(u8).range(1..))]
+        past: Option<DayCount>,
+
+        /// Number of forecast days to report
+        #[arg(long, group = "degree_days_period", value_parser = clap::value_parser!(u8).range(1..16))]
+        days: Option<DayCount>,
+
+        /// Base temperature, e.g. 15.5, 18C or 65F, in selected temperature unit when unit is omitted [default: 18C or 65F]
+        #[arg(long, value_parser = parse_base_temperature)]
+        base: Option<BaseTemperature>,
+    },
+
+    /// Check forecast against conditions and exit with status 7 when any is met
+    Alert {
+        /// Condition like "temperature<0" or "wind>15m/s", can be repeated;
+        /// the unit is optional and defaults to the selected one
+        #[arg(long = "if", value_name = "CONDITION", required = true)]
+        conditions: Vec<Condition>,
+
+        /// Number of forecast days to check
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..16))]
+        days: Option<DayCount>,
+    },
+
+    /// Compare forecasts stored when record_forecasts is enabled in config file with observed weather of past days
+    Verify {
+        /// Number of past days to verify
+        #[arg(long, default_value_t = 14, value_parser = clap::value_parser!(u8).range(1..=92))]
+        days: DayCount,
+    },
+
+    /// Query weather recorded when record_history is enabled in config file, without fetching it
+    History {
+        /// Format report as summary
+        #[arg(long, group = "history_format")]
+        summary: bool,
+
+        /// Format report as JSON
+        #[arg(long, group = "history_format")]
+        json: bool,
+
+        /// Format report as CSV
+        #[arg(long, group = "history_format")]
+        csv: bool,
+
+        /// Format report as list of attributes
+        #[arg(long, group = "history_format")]
+        list: bool,
+
+        /// Number of past days to search
+        #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u8).range(1..))]
         days: DayCount,
+
+        /// Find the coldest observation
+        #[arg(long, group = "history_query")]
+        coldest: bool,
+
+        /// Find the warmest observation
+        #[arg(long, group = "history_query")]
+        warmest: bool,
+
+        /// Find the windiest observation
+        #[arg(long, group = "history_query")]
+        windiest: bool,
+
+        /// Find the last time of weather kind: clear, cloudy, fog, rain, snow or thunderstorm
+        #[arg(long, group = "history_query", value_parser = parse_kind_category)]
+        last: Option<KindCategory>,
+
+        /// Search only current weather recorded between 5 and 12 o'clock
+        #[arg(long)]
+        morning: bool,
+    },
+
+    /// Compare weather in locations side by side
+    Compare {
+        /// Compare forecast for number of days instead of current weather
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..16))]
+        days: Option<DayCount>,
+
+        /// Compare only selected attributes
+        #[arg(long, value_delimiter=',', num_args=1..)]
+        attributes: Option<Vec<WeatherAttribute>>,
+    },
+
+    /// Manage saved locations
+    Locations {
+        #[command(subcommand)]
+        command: LocationsCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum LocationsCommand {
+    /// Save location under name
+    Add {
+        /// Name of location
+        name: String,
+
+        /// Coordinates in format 'latitude,longitude' or address
+        place: String,
+    },
+
+    /// List saved locations
+    List,
+
+    /// Remove saved location
+    Remove {
+        /// Name of location
+        name: String,
     },
 }
 
@@ -120,20 +303,151 @@ impl From<SpeedUnitArg> for SpeedUnit {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, ValueEnum)]
+pub enum LocationSourceArg {
+    Ip,
+    Gps,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BaseTemperature {
+    value: f32,
+    unit: Option<TemperatureUnit>,
+}
+
+impl BaseTemperature {
+    fn to_temperature(&self, default_unit: TemperatureUnit) -> Temperature {
+        match self.unit.unwrap_or(default_unit) {
+            TemperatureUnit::Celsius => Temperature::new_celsius(self.value),
+            TemperatureUnit::Fahrenheit => Temperature::new_fahrenheit(self.value),
+        }
+    }
+}
+
+fn parse_base_temperature(string: &str) -> Result<BaseTemperature, String> {
+    let trimmed = string.trim();
+    let (number, unit) = match trimmed.char_indices().last() {
+        Some((index, 'C' | 'c')) => (&trimmed[..index], Some(TemperatureUnit::Celsius)),
+        Some((index, 'F' | 'f')) => (&trimmed[..index], Some(TemperatureUnit::Fahrenheit)),
+        _ => (trimmed, None),
+    };
+    let number = number.trim_end_matches('°').trim_end();
+    let value: f32 = number.parse().map_err(|_| {
+        format!("Invalid base temperature '{string}', expected e.g. 18, 18C or 65F")
+    })?;
+    Ok(BaseTemperature { value, unit })
+}
+
+fn select_default_base_temperature(unit: TemperatureUnit) -> Temperature {
+    match unit {
+        TemperatureUnit::Celsius => Temperature::new_celsius(DEFAULT_BASE_TEMPERATURE_CELSIUS),
+        TemperatureUnit::Fahrenheit => {
+            Temperature::new_fahrenheit(DEFAULT_BASE_TEMPERATURE_FAHRENHEIT)
+        }
+    }
+}
+
+fn parse_watch_interval(string: &str) -> Result<Duration, String> {
+    let trimmed = string.trim();
+    let (number, seconds_per_unit) = match trimmed.char_indices().last() {
+        Some((index, 's')) => (&trimmed[..index], 1),
+        Some((index, 'm')) => (&trimmed[..index], 60),
+        Some((index, 'h')) => (&trimmed[..index], 60 * 60),
+        _ => (trimmed, 60),
+    };
+    let interval = number
+        .parse::<u64>()
+        .ok()
+        .and_then(|value| value.checked_mul(seconds_per_unit))
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("Invalid interval '{string}', expected e.g. 90s, 15m or 1h"))?;
+    if interval < watch::MIN_INTERVAL {
+        return Err(format!(
+            "Interval '{string}' is too short, must be at least {} seconds",
+            watch::MIN_INTERVAL.as_secs()
+        ));
+    }
+    Ok(interval)
+}
+
+fn parse_kind_category(string: &str) -> Result<KindCategory, String> {
+    KindCategory::ALL
+        .into_iter()
+        .find(|category| category.name().eq_ignore_ascii_case(string.trim()))
+        .ok_or_else(|| {
+            format!(
+                "Invalid weather kind '{string}', use one of clear, cloudy, fog, rain, snow or thunderstorm"
+            )
+        })
+}
+
+const ACTIVITY_DAY_COUNT: DayCount = 7;
+const DEFAULT_BASE_TEMPERATURE_CELSIUS: f32 = 18.0;
+const DEFAULT_BASE_TEMPERATURE_FAHRENHEIT: f32 = 65.0;
+const MIN_ELEVATION_METERS: f32 = -500.0;
+const MAX_ELEVATION_METERS: f32 = 9000.0;
+const DEFAULT_GPSD_ADDRESS: &str = "localhost:2947";
+const DEFAULT_GPS_TIMEOUT_SECONDS: u64 = 10;
+
+fn parse_elevation(string: &str) -> Result<Meters, String> {
+    let value: f32 = string
+        .parse()
+        .map_err(|_| format!("Invalid elevation '{string}'"))?;
+    if !(MIN_ELEVATION_METERS..=MAX_ELEVATION_METERS).contains(&value) {
+        return Err(format!(
+            "Elevation {value} is out of range, must be between {MIN_ELEVATION_METERS} and {MAX_ELEVATION_METERS} meters"
+        ));
+    }
+    Ok(Meters::from(value))
+}
+
 #[derive(Parser)]
 struct Args {
     /// Report type
     #[command(subcommand)]
     command: Option<Command>,
 
-    /// Report from location specified by coordinates
-    #[arg(long, group = "location")]
-    coords: Option<Coordinates>,
+    /// Report from location specified by coordinates, can be repeated;
+    /// accepts decimal degrees, degrees-minutes-seconds with N/S/E/W, geohash or full plus code
+    #[arg(long, global = true)]
+    coords: Vec<Coordinates>,
+
+    /// Report from saved location, can be repeated
+    #[arg(long, global = true)]
+    location: Vec<String>,
 
     /// Report from current location based on IP
-    #[arg(long, group = "location")]
+    #[arg(long, global = true, conflicts_with_all = ["coords", "location"])]
     here: bool,
 
+    /// Select how current location is found, implies --here
+    #[arg(long, global = true, conflicts_with_all = ["coords", "location"])]
+    location_source: Option<LocationSourceArg>,
+
+    /// Read GPS fix from NMEA serial device or log file instead of gpsd
+    #[arg(long, global = true)]
+    gps_device: Option<PathBuf>,
+
+    /// Address of gpsd [default: localhost:2947]
+    #[arg(long, global = true, conflicts_with = "gps_device")]
+    gpsd: Option<String>,
+
+    /// Seconds to wait for GPS fix [default: 10]
+    #[arg(long, global = true)]
+    gps_timeout: Option<u64>,
+
+    /// Elevation in meters used for downscaling, instead of terrain model elevation
+    #[arg(long, global = true, value_parser = parse_elevation)]
+    elevation: Option<Meters>,
+
+    /// Timezone for dates and times, e.g. Europe/Warsaw or GMT+2 [default: timezone of location]
+    #[arg(long, global = true)]
+    timezone: Option<Timezone>,
+
+    /// Compare daily temperatures with the 30-year average for the same calendar days
+    #[arg(long, global = true)]
+    anomaly: bool,
+
     /// Select temperature unit
     #[arg(long)]
     temp_unit: Option<TemperatureUnitArg>,
@@ -141,8 +455,76 @@ struct Args {
     /// Select speed unit
     #[arg(long)]
     speed_unit: Option<SpeedUnitArg>,
+
+    /// Path to config file
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    /// Write report to file instead of console
+    #[arg(long, group = "destination")]
+    output: Option<PathBuf>,
+
+    /// Append report to output file instead of replacing its content
+    #[arg(long, requires = "output")]
+    append: bool,
+
+    /// Pass report to standard input of shell command
+    #[arg(long, group = "destination")]
+    pipe: Option<String>,
+
+    /// Do not print diagnostics
+    #[arg(long, short, group = "verbosity")]
+    quiet: bool,
+
+    /// Print detailed diagnostics
+    #[arg(long, short, group = "verbosity")]
+    verbose: bool,
+}
+
+pub enum Action {
+    Report(Parameters),
+    Alert(Parameters, Vec<Condition>),
+    Verify(Parameters),
+    History(HistoryParameters),
+    Watch(Parameters, Duration),
+    ManageLocations(LocationParameters),
+}
+
+/// Parameters of querying recorded weather, which is never fetched
+#[derive(Debug)]
+pub struct HistoryParameters {
+    pub store: Option<PathBuf>,
+    pub filter: HistoryFilter,
+    pub units: Units,
+    pub output_format: OutputFormat,
+    pub destination: Destination,
+    pub verbosity: Verbosity,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidArguments(clap::Error),
+    Config(config::Error),
+    Locations(locations::Error),
+    UnknownActivity(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidArguments(error) => error.fmt(f),
+            Self::Config(error) => error.fmt(f),
+            Self::Locations(error) => error.fmt(f),
+            Self::UnknownActivity(name) => write!(
+                f,
+                "Unknown activity '{name}', use one of running, cycling, laundry, bbq or define it in config file"
+            ),
+        }
+    }
 }
 
+impl std::error::Error for Error {}
+
 fn convert_to_attribute_set(attributes: &[WeatherAttribute]) -> WeatherAttributeSet {
     if attributes.is_empty() {
         WeatherAttribute::iter().collect()
@@ -151,10 +533,39 @@ fn convert_to_attribute_set(attributes: &[WeatherAttribute]) -> WeatherAttribute
     }
 }
 
-fn convert_args_to_parameters(args: Args) -> Parameters {
+fn select_output_format(summary: bool, json: bool, csv: bool, list: bool) -> OutputFormat {
+    if summary {
+        OutputFormat::Summary
+    } else if json {
+        OutputFormat::Json
+    } else if csv {
+        OutputFormat::Csv
+    } else if list {
+        OutputFormat::List
+    } else {
+        OutputFormat::Summary
+    }
+}
+
+fn convert_args_to_parameters(args: Args, config: Config) -> Parameters {
+    let coordinates = select_coordinates(&args, &config);
+    let units = select_units(args.temp_unit, args.speed_unit);
+    let mut output_format = OutputFormat::Summary;
+    let mut content = ReportContent::Weather;
     let request_kind = match args.command {
         None => RequestKind::CurrentFull,
-        Some(Command::Now { summary: _, list }) => {
+        Some(Command::Now {
+            summary,
+            json,
+            csv,
+            list,
+            gear,
+            ..
+        }) => {
+            output_format = select_output_format(summary, json, csv, list.is_some());
+            if gear {
+                content = ReportContent::WeatherWithGear(select_gear_rules(&config));
+            }
             if let Some(attributes) = list {
                 let attribute_set = convert_to_attribute_set(&attributes);
                 RequestKind::CurrentPartial(attribute_set)
@@ -163,12 +574,29 @@ fn convert_args_to_parameters(args: Args) -> Parameters {
             }
         }
         Some(Command::Forecast {
-            summary: _,
+            summary,
+            json,
+            csv,
             list,
-            today: _,
+            today,
             days,
+            activity,
+            gear,
         }) => {
-            let day_count = days.unwrap_or(1);
+            output_format = select_output_format(summary, json, csv, list.is_some());
+            if gear {
+                content = ReportContent::WeatherWithGear(select_gear_rules(&config));
+            }
+            let default_day_count = if activity.is_some() {
+                ACTIVITY_DAY_COUNT
+            } else {
+                1
+            };
+            let day_count = if today {
+                1
+            } else {
+                days.unwrap_or(default_day_count)
+            };
             if let Some(attributes) = list {
                 let attribute_set = convert_to_attribute_set(&attributes);
                 RequestKind::ForecastPartial(day_count, attribute_set)
@@ -177,10 +605,17 @@ fn convert_args_to_parameters(args: Args) -> Parameters {
             }
         }
         Some(Command::Past {
-            summary: _,
+            summary,
+            json,
+            csv,
             list,
+            stats,
             days,
         }) => {
+            output_format = select_output_format(summary, json, csv, list.is_some());
+            if stats {
+                content = ReportContent::Statistics;
+            }
             if let Some(attributes) = list {
                 let attribute_set = convert_to_attribute_set(&attributes);
                 RequestKind::PastPartial(days, attribute_set)
@@ -188,43 +623,363 @@ fn convert_args_to_parameters(args: Args) -> Parameters {
                 RequestKind::PastFull(days)
             }
         }
+        Some(Command::DegreeDays {
+            json,
+            csv,
+            past,
+            days,
+            base,
+        }) => {
+            let base = match base {
+                Some(base) => base.to_temperature(units.temperature),
+                None => select_default_base_temperature(units.temperature),
+            };
+            output_format = select_output_format(false, json, csv, false);
+            content = ReportContent::DegreeDays(base);
+            let attribute_set = convert_to_attribute_set(&[WeatherAttribute::Temperature]);
+            match (past, days) {
+                (Some(day_count), _) => RequestKind::PastPartial(day_count, attribute_set),
+                (None, day_count) => {
+                    RequestKind::ForecastPartial(day_count.unwrap_or(1), attribute_set)
+                }
+            }
+        }
+        Some(Command::Alert { conditions, days }) => {
+            let attributes: Vec<WeatherAttribute> = conditions
+                .iter()
+                .map(|condition| condition.attribute)
+                .collect();
+            RequestKind::ForecastPartial(days.unwrap_or(1), convert_to_attribute_set(&attributes))
+        }
+        Some(Command::Verify { days }) => RequestKind::PastFull(days),
+        Some(Command::Compare { days, attributes }) => {
+            output_format = OutputFormat::Comparison;
+            let attribute_set = convert_to_attribute_set(&attributes.unwrap_or_default());
+            match days {
+                Some(day_count) => RequestKind::ForecastPartial(day_count, attribute_set),
+                None => RequestKind::CurrentPartial(attribute_set),
+            }
+        }
+        Some(Command::Locations { .. }) => {
+            unreachable!("Locations command does not request weather report")
+        }
+        Some(Command::History { .. }) => {
+            unreachable!("History command reads recorded weather instead of requesting it")
+        }
     };
-    let units = Units {
-        temperature: args
-            .temp_unit
+    let location_source = select_location_source(
+        args.location_source.as_ref(),
+        args.gps_device,
+        args.gpsd,
+        args.gps_timeout,
+    );
+    Parameters {
+        request_kind,
+        coordinates,
+        location_source,
+        elevation: args.elevation,
+        timezone: args.timezone.unwrap_or_default(),
+        include_normals: args.anomaly,
+        units,
+        output_format,
+        content,
+        destination: select_destination(args.output, args.append, args.pipe),
+        verbosity: select_verbosity(args.quiet, args.verbose),
+        forecast_store: config
+            .record_forecasts
+            .then(|| select_store_path(args.config.as_deref(), FORECASTS_FILE_NAME))
+            .flatten(),
+        history_store: config
+            .record_history
+            .then(|| select_store_path(args.config.as_deref(), HISTORY_FILE_NAME))
+            .flatten(),
+        place_names: match config.place_names {
+            PlaceNames::Off => PlaceNameSource::Off,
+            PlaceNames::Offline => PlaceNameSource::Offline,
+            PlaceNames::Online => PlaceNameSource::Online {
+                cache: select_store_path(args.config.as_deref(), PLACES_FILE_NAME),
+            },
+        },
+    }
+}
+
+fn select_units(temp_unit: Option<TemperatureUnitArg>, speed_unit: Option<SpeedUnitArg>) -> Units {
+    Units {
+        temperature: temp_unit
             .map(TemperatureUnitArg::into)
             .unwrap_or(TemperatureUnit::Celsius),
-        speed: args
-            .speed_unit
+        speed: speed_unit
             .map(SpeedUnitArg::into)
             .unwrap_or(SpeedUnit::MetersPerSecond),
+    }
+}
+
+/// Selects coordinates given directly or in config, named locations are resolved separately
+fn select_coordinates(args: &Args, config: &Config) -> Vec<Coordinates> {
+    if args.here || args.location_source.is_some() {
+        Vec::new()
+    } else if args.coords.is_empty() && args.location.is_empty() {
+        config.coords.into_iter().collect()
+    } else {
+        args.coords.clone()
+    }
+}
+
+fn select_location_source(
+    source: Option<&LocationSourceArg>,
+    gps_device: Option<PathBuf>,
+    gpsd: Option<String>,
+    gps_timeout: Option<u64>,
+) -> LocationSource {
+    let timeout = Duration::from_secs(gps_timeout.unwrap_or(DEFAULT_GPS_TIMEOUT_SECONDS));
+    match (source, gps_device) {
+        (None | Some(LocationSourceArg::Ip), _) => LocationSource::Ip,
+        (Some(LocationSourceArg::Gps), Some(path)) => LocationSource::Nmea { path, timeout },
+        (Some(LocationSourceArg::Gps), None) => LocationSource::Gpsd {
+            address: gpsd.unwrap_or_else(|| DEFAULT_GPSD_ADDRESS.into()),
+            timeout,
+        },
+    }
+}
+
+fn select_destination(output: Option<PathBuf>, append: bool, pipe: Option<String>) -> Destination {
+    if let Some(path) = output {
+        Destination::File { path, append }
+    } else if let Some(command) = pipe {
+        Destination::Command(command)
+    } else {
+        Destination::Console
+    }
+}
+
+fn select_verbosity(quiet: bool, verbose: bool) -> Verbosity {
+    if quiet {
+        Verbosity::Quiet
+    } else if verbose {
+        Verbosity::Verbose
+    } else {
+        Verbosity::Normal
+    }
+}
+
+fn convert_to_place(place: String) -> Place {
+    match coordinates::parse_without_geohash(&place) {
+        Ok(coordinates) => Place::Coordinates(coordinates),
+        Err(_) => Place::Address(place),
+    }
+}
+
+fn convert_to_location_action(command: LocationsCommand) -> LocationAction {
+    match command {
+        LocationsCommand::Add { name, place } => LocationAction::Add {
+            name,
+            place: convert_to_place(place),
+        },
+        LocationsCommand::List => LocationAction::List,
+        LocationsCommand::Remove { name } => LocationAction::Remove { name },
+    }
+}
+
+fn resolve_locations(names: &[String], store: &LocationStore) -> Result<Vec<Coordinates>, Error> {
+    names
+        .iter()
+        .map(|name| store.get(name).map_err(Error::Locations))
+        .collect()
+}
+
+fn convert_args_to_action(
+    args: Args,
+    config: Config,
+    load_store: impl FnOnce() -> Result<LocationStore, Error>,
+) -> Result<Action, Error> {
+    if let Some(Command::Locations { command }) = args.command {
+        return Ok(Action::ManageLocations(LocationParameters {
+            store: load_store()?,
+            action: convert_to_location_action(command),
+            destination: select_destination(args.output, args.append, args.pipe),
+            verbosity: select_verbosity(args.quiet, args.verbose),
+        }));
+    }
+    if let Some(Command::History {
+        summary,
+        json,
+        csv,
+        list,
+        days,
+        coldest,
+        warmest,
+        windiest,
+        last,
+        morning,
+    }) = args.command
+    {
+        let mut coordinates = select_coordinates(&args, &config);
+        if !args.location.is_empty() {
+            coordinates.extend(resolve_locations(&args.location, &load_store()?)?);
+        }
+        return Ok(Action::History(HistoryParameters {
+            store: select_store_path(args.config.as_deref(), HISTORY_FILE_NAME),
+            filter: HistoryFilter {
+                query: select_history_query(coldest, warmest, windiest, last),
+                day_count: days,
+                mornings_only: morning,
+                coordinates,
+            },
+            units: select_units(args.temp_unit, args.speed_unit),
+            output_format: select_output_format(summary, json, csv, list),
+            destination: select_destination(args.output, args.append, args.pipe),
+            verbosity: select_verbosity(args.quiet, args.verbose),
+        }));
+    }
+    let names = args.location.clone();
+    let activity = match &args.command {
+        Some(Command::Forecast {
+            activity: Some(name),
+            ..
+        }) => Some(select_activity(name, &config)?),
+        _ => None,
     };
-    Parameters {
-        request_kind,
-        coordinates: args.coords,
-        units,
+    let conditions = match &args.command {
+        Some(Command::Alert { conditions, .. }) => Some(conditions.clone()),
+        _ => None,
+    };
+    let verify = matches!(args.command, Some(Command::Verify { .. }))
+        .then(|| select_store_path(args.config.as_deref(), FORECASTS_FILE_NAME));
+    let watch_interval = match &args.command {
+        Some(Command::Now {
+            watch: Some(interval),
+            ..
+        }) => Some(interval.unwrap_or(watch::DEFAULT_INTERVAL)),
+        _ => None,
+    };
+    let mut parameters = convert_args_to_parameters(args, config);
+    if !names.is_empty() {
+        let store = load_store()?;
+        parameters
+            .coordinates
+            .extend(resolve_locations(&names, &store)?);
+    }
+    if let Some(profile) = activity {
+        parameters.content = ReportContent::Activity(profile);
+    }
+    if let Some(interval) = watch_interval {
+        if parameters.destination != Destination::Console {
+            return Err(Error::InvalidArguments(clap::Error::raw(
+                clap::error::ErrorKind::ArgumentConflict,
+                "Watching weather redraws the console, it cannot be combined with --output or --pipe\n",
+            )));
+        }
+        return Ok(Action::Watch(parameters, interval));
+    }
+    if let Some(path) = verify {
+        parameters.forecast_store = path;
+        return Ok(Action::Verify(parameters));
+    }
+    Ok(match conditions {
+        Some(conditions) => Action::Alert(parameters, conditions),
+        None => Action::Report(parameters),
+    })
+}
+
+fn select_history_query(
+    coldest: bool,
+    warmest: bool,
+    windiest: bool,
+    last: Option<KindCategory>,
+) -> HistoryQuery {
+    if coldest {
+        HistoryQuery::Coldest
+    } else if warmest {
+        HistoryQuery::Warmest
+    } else if windiest {
+        HistoryQuery::Windiest
+    } else if let Some(kind) = last {
+        HistoryQuery::Last(kind)
+    } else {
+        HistoryQuery::All
     }
 }
 
-pub fn parse() -> Parameters {
-    let args = Args::parse();
-    convert_args_to_parameters(args)
+fn select_gear_rules(config: &Config) -> Vec<GearRule> {
+    if config.gear.is_empty() {
+        gear::default_rules()
+    } else {
+        config.gear.iter().map(GearRuleConfig::to_rule).collect()
+    }
+}
+
+fn select_activity(name: &str, config: &Config) -> Result<ActivityProfile, Error> {
+    let builtin = builtin_profiles()
+        .into_iter()
+        .find(|profile| profile.name.eq_ignore_ascii_case(name));
+    let configured = config.activities.get(name);
+    let mut profile = match (builtin, configured) {
+        (Some(profile), _) => profile,
+        (None, Some(_)) => ActivityProfile::new(name),
+        (None, None) => return Err(Error::UnknownActivity(name.into())),
+    };
+    if let Some(activity_config) = configured {
+        activity_config.apply(&mut profile);
+    }
+    Ok(profile)
+}
+
+fn select_store_path(config_path: Option<&Path>, file_name: &str) -> Option<PathBuf> {
+    let directory = match config_path {
+        Some(path) => path.parent().map(Path::to_path_buf),
+        None => config::default_directory(),
+    };
+    directory.map(|directory| directory.join(file_name))
+}
+
+pub fn parse() -> Result<Action, Error> {
+    let args = Args::try_parse().map_err(Error::InvalidArguments)?;
+    let config = config::load(args.config.as_deref()).map_err(Error::Config)?;
+    let config_path = args.config.clone();
+    convert_args_to_action(args, config, || load_location_store(config_path.as_deref()))
+}
+
+fn load_location_store(config_path: Option<&Path>) -> Result<LocationStore, Error> {
+    let path = select_store_path(config_path, LOCATIONS_FILE_NAME).ok_or(Error::Locations(
+        locations::Error::File(file::Error::Reading(LOCATIONS_FILE_NAME.into())),
+    ))?;
+    LocationStore::load(&path).map_err(Error::Locations)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
+    use std::str::FromStr;
 
-    #[test]
-    fn parses_now_command_when_none_is_provided() {
-        let args = Args {
+    fn make_args() -> Args {
+        Args {
             command: None,
-            coords: None,
+            coords: Vec::new(),
+            location: Vec::new(),
             here: false,
+            location_source: None,
+            gps_device: None,
+            gpsd: None,
+            gps_timeout: None,
+            elevation: None,
+            timezone: None,
+            anomaly: false,
             temp_unit: None,
             speed_unit: None,
-        };
-        let params = convert_args_to_parameters(args);
+            config: None,
+            output: None,
+            append: false,
+            pipe: None,
+            quiet: false,
+            verbose: false,
+        }
+    }
+
+    #[test]
+    fn parses_now_command_when_none_is_provided() {
+        let args = make_args();
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, RequestKind::CurrentFull);
     }
 
@@ -234,29 +989,52 @@ mod tests {
             command: Some(Command::Now {
                 summary: false,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, RequestKind::CurrentFull);
     }
 
+    #[test]
+    fn parses_summary_flag() {
+        let args = Args {
+            command: Some(Command::Now {
+                summary: true,
+                list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
+            }),
+            ..make_args()
+        };
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(params.output_format, OutputFormat::Summary);
+
+        let args = make_args();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(params.output_format, OutputFormat::Summary);
+    }
+
     #[test]
     fn parses_now_command_with_summary_specified() {
         let args = Args {
             command: Some(Command::Now {
                 summary: true,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, RequestKind::CurrentFull);
     }
 
@@ -269,13 +1047,14 @@ mod tests {
             command: Some(Command::Now {
                 summary: false,
                 list: Some(Vec::new()),
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -294,13 +1073,14 @@ mod tests {
             command: Some(Command::Now {
                 summary: false,
                 list: Some(requested_attributes),
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -310,15 +1090,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: false,
                 list: None,
+                json: false,
+                csv: false,
                 today: false,
                 days: None,
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         let expected = RequestKind::ForecastFull(DayCount::from(1));
         assert_eq!(params.request_kind, expected);
     }
@@ -329,15 +1110,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: true,
                 list: None,
+                json: false,
+                csv: false,
                 today: false,
                 days: None,
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         let expected = RequestKind::ForecastFull(DayCount::from(1));
         assert_eq!(params.request_kind, expected);
     }
@@ -348,15 +1130,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: false,
                 list: None,
+                json: false,
+                csv: false,
                 today: true,
                 days: None,
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         let expected = RequestKind::ForecastFull(DayCount::from(1));
         assert_eq!(params.request_kind, expected);
     }
@@ -367,15 +1150,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: true,
                 list: None,
+                json: false,
+                csv: false,
                 today: true,
                 days: None,
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         let expected = RequestKind::ForecastFull(DayCount::from(1));
         assert_eq!(params.request_kind, expected);
     }
@@ -387,15 +1171,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: false,
                 list: None,
+                json: false,
+                csv: false,
                 today: false,
                 days: Some(DAY_COUNT),
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         let expected = RequestKind::ForecastFull(DAY_COUNT);
         assert_eq!(params.request_kind, expected);
     }
@@ -407,15 +1192,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: true,
                 list: None,
+                json: false,
+                csv: false,
                 today: false,
                 days: Some(DAY_COUNT),
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         let expected = RequestKind::ForecastFull(DAY_COUNT);
         assert_eq!(params.request_kind, expected);
     }
@@ -429,15 +1215,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: false,
                 list: Some(Vec::new()),
+                json: false,
+                csv: false,
                 today: false,
                 days: None,
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -456,15 +1243,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: false,
                 list: Some(requested_attributes),
+                json: false,
+                csv: false,
                 today: false,
                 days: None,
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -483,15 +1271,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: false,
                 list: Some(requested_attributes),
+                json: false,
+                csv: false,
                 today: true,
                 days: None,
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -511,15 +1300,16 @@ mod tests {
             command: Some(Command::Forecast {
                 summary: false,
                 list: Some(requested_attributes),
+                json: false,
+                csv: false,
                 today: false,
                 days: Some(DAY_COUNT),
+                activity: None,
+                gear: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -531,14 +1321,14 @@ mod tests {
             command: Some(Command::Past {
                 summary: false,
                 list: None,
+                json: false,
+                csv: false,
+                stats: false,
                 days: DAY_COUNT,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -550,14 +1340,14 @@ mod tests {
             command: Some(Command::Past {
                 summary: true,
                 list: None,
+                json: false,
+                csv: false,
+                stats: false,
                 days: DAY_COUNT,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -577,14 +1367,14 @@ mod tests {
             command: Some(Command::Past {
                 summary: false,
                 list: Some(requested_attributes),
+                json: false,
+                csv: false,
+                stats: false,
                 days: DAY_COUNT,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.request_kind, expected);
     }
 
@@ -594,13 +1384,14 @@ mod tests {
             command: Some(Command::Now {
                 summary: true,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.units.temperature, TemperatureUnit::Celsius);
     }
 
@@ -610,13 +1401,15 @@ mod tests {
             command: Some(Command::Now {
                 summary: true,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
             temp_unit: Some(TemperatureUnitArg::Fahrenheit),
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.units.temperature, TemperatureUnit::Fahrenheit);
     }
 
@@ -626,13 +1419,14 @@ mod tests {
             command: Some(Command::Now {
                 summary: true,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.units.speed, SpeedUnit::MetersPerSecond);
     }
 
@@ -642,13 +1436,15 @@ mod tests {
             command: Some(Command::Now {
                 summary: true,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
             speed_unit: Some(SpeedUnitArg::Kmh),
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.units.speed, SpeedUnit::KilometersPerHour);
     }
 
@@ -658,13 +1454,15 @@ mod tests {
             command: Some(Command::Now {
                 summary: true,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
             speed_unit: Some(SpeedUnitArg::Mph),
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.units.speed, SpeedUnit::MilesPerHour);
     }
 
@@ -674,13 +1472,15 @@ mod tests {
             command: Some(Command::Now {
                 summary: true,
                 list: None,
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
             }),
-            coords: None,
-            here: false,
-            temp_unit: None,
             speed_unit: Some(SpeedUnitArg::Kn),
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
+        let params = convert_args_to_parameters(args, Config::default());
         assert_eq!(params.units.speed, SpeedUnit::Knots);
     }
 
@@ -694,22 +1494,965 @@ mod tests {
 
     #[test]
     fn raises_error_on_invalid_coordinates() {
-        matches!(Coordinates::from_str("foo,45.67"), Err(_));
-        matches!(Coordinates::from_str("1.23,bar"), Err(_));
-        matches!(Coordinates::from_str("1.2,3.4,5.6"), Err(_));
+        assert!(Coordinates::from_str("foo,45.67").is_err());
+        assert!(Coordinates::from_str("1.23,bar").is_err());
+        assert!(Coordinates::from_str("1.2,3.4,5.6").is_err());
     }
 
     #[test]
     fn parses_coordinates() {
         let coordinates = Coordinates::new(1.23, 45.67);
         let args = Args {
-            command: None,
-            coords: Some(coordinates.clone()),
-            here: false,
-            temp_unit: None,
-            speed_unit: None,
+            coords: vec![coordinates],
+            ..make_args()
         };
-        let params = convert_args_to_parameters(args);
-        assert_eq!(params.coordinates, Some(coordinates));
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(params.coordinates, vec![coordinates]);
+    }
+
+    #[test]
+    fn parses_now_command_with_summary_and_list_into_partial_summary() {
+        let args = Args {
+            command: Some(Command::Now {
+                summary: true,
+                list: Some(vec![WeatherAttribute::Temperature]),
+                gear: false,
+                watch: None,
+                json: false,
+                csv: false,
+            }),
+            ..make_args()
+        };
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected =
+            RequestKind::CurrentPartial(WeatherAttributeSet::from([WeatherAttribute::Temperature]));
+        assert_eq!(params.request_kind, expected);
+        assert_eq!(params.output_format, OutputFormat::Summary);
+    }
+
+    #[test]
+    fn parses_forecast_command_with_summary_and_list_into_partial_summary() {
+        const DAY_COUNT: DayCount = 4;
+        let args = Args {
+            command: Some(Command::Forecast {
+                summary: true,
+                list: Some(vec![WeatherAttribute::Temperature]),
+                json: false,
+                csv: false,
+                today: false,
+                days: Some(DAY_COUNT),
+                activity: None,
+                gear: false,
+            }),
+            ..make_args()
+        };
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected = RequestKind::ForecastPartial(
+            DAY_COUNT,
+            WeatherAttributeSet::from([WeatherAttribute::Temperature]),
+        );
+        assert_eq!(params.request_kind, expected);
+        assert_eq!(params.output_format, OutputFormat::Summary);
+    }
+
+    #[test]
+    fn parses_past_command_with_summary_and_list_into_partial_summary() {
+        const DAY_COUNT: DayCount = 4;
+        let args = Args {
+            command: Some(Command::Past {
+                summary: true,
+                list: Some(vec![WeatherAttribute::Temperature]),
+                json: false,
+                csv: false,
+                stats: false,
+                days: DAY_COUNT,
+            }),
+            ..make_args()
+        };
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected = RequestKind::PastPartial(
+            DAY_COUNT,
+            WeatherAttributeSet::from([WeatherAttribute::Temperature]),
+        );
+        assert_eq!(params.request_kind, expected);
+        assert_eq!(params.output_format, OutputFormat::Summary);
+    }
+
+    #[test]
+    fn parses_forecast_command_with_today_and_summary_and_list_into_partial_summary() {
+        let args = Args {
+            command: Some(Command::Forecast {
+                summary: true,
+                list: Some(vec![WeatherAttribute::Temperature]),
+                json: false,
+                csv: false,
+                today: true,
+                days: None,
+                activity: None,
+                gear: false,
+            }),
+            ..make_args()
+        };
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected = RequestKind::ForecastPartial(
+            DayCount::from(1),
+            WeatherAttributeSet::from([WeatherAttribute::Temperature]),
+        );
+        assert_eq!(params.request_kind, expected);
+        assert_eq!(params.output_format, OutputFormat::Summary);
+    }
+
+    #[test]
+    fn parses_no_location_into_coordinates_from_config() {
+        let configured = Coordinates::new(52.23, 21.01);
+        let args = make_args();
+        let config = Config {
+            coords: Some(configured),
+            ..Config::default()
+        };
+        let params = convert_args_to_parameters(args, config);
+        assert_eq!(params.coordinates, vec![configured]);
+    }
+
+    #[test]
+    fn parses_no_location_without_config_into_current_location() {
+        let args = make_args();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert!(params.coordinates.is_empty());
+    }
+
+    #[test]
+    fn parses_coordinates_over_coordinates_from_config() {
+        let coordinates = Coordinates::new(1.23, 45.67);
+        let args = Args {
+            coords: vec![coordinates],
+            ..make_args()
+        };
+        let config = Config {
+            coords: Some(Coordinates::new(52.23, 21.01)),
+            ..Config::default()
+        };
+        let params = convert_args_to_parameters(args, config);
+        assert_eq!(params.coordinates, vec![coordinates]);
+    }
+
+    #[test]
+    fn parses_here_into_current_location() {
+        let args = Args {
+            here: true,
+            ..make_args()
+        };
+        let params = convert_args_to_parameters(args, Config::default());
+        assert!(params.coordinates.is_empty());
+    }
+
+    #[test]
+    fn parses_here_into_current_location_over_coordinates_from_config() {
+        let args = Args {
+            here: true,
+            ..make_args()
+        };
+        let config = Config {
+            coords: Some(Coordinates::new(52.23, 21.01)),
+            ..Config::default()
+        };
+        let params = convert_args_to_parameters(args, config);
+        assert!(params.coordinates.is_empty());
+    }
+
+    #[test]
+    fn accepts_summary_together_with_list() {
+        let result = Args::try_parse_from(["what-weather", "now", "--summary", "--list=wind"]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn rejects_here_together_with_coordinates() {
+        let result = Args::try_parse_from(["what-weather", "--here", "--coords=1.2,3.4"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_past_command_without_days() {
+        let result = Args::try_parse_from(["what-weather", "past", "--days=0"]);
+        assert!(result.is_err());
+        let result = Args::try_parse_from(["what-weather", "past", "--days=200"]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn rejects_today_together_with_days() {
+        let result = Args::try_parse_from(["what-weather", "forecast", "--today", "--days=3"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn selects_output_format() {
+        let select = |arguments: &[&str]| {
+            let args = Args::try_parse_from(arguments).unwrap();
+            convert_args_to_parameters(args, Config::default()).output_format
+        };
+        assert_eq!(select(&["what-weather", "now"]), OutputFormat::Summary);
+        assert_eq!(
+            select(&["what-weather", "now", "--list"]),
+            OutputFormat::List
+        );
+        assert_eq!(
+            select(&["what-weather", "now", "--json"]),
+            OutputFormat::Json
+        );
+        assert_eq!(
+            select(&["what-weather", "past", "--days=2", "--csv", "--list=wind"]),
+            OutputFormat::Csv
+        );
+        assert_eq!(
+            select(&["what-weather", "forecast", "--summary", "--list=wind"]),
+            OutputFormat::Summary
+        );
+    }
+
+    #[test]
+    fn selects_statistics_in_any_format() {
+        let select = |arguments: &[&str]| {
+            let args = Args::try_parse_from(arguments).unwrap();
+            let params = convert_args_to_parameters(args, Config::default());
+            (params.output_format, params.content, params.request_kind)
+        };
+        let all_attributes = RequestKind::PastFull(30);
+        assert_eq!(
+            select(&["what-weather", "past", "--days=30", "--stats"]),
+            (
+                OutputFormat::Summary,
+                ReportContent::Statistics,
+                all_attributes.clone()
+            )
+        );
+        assert_eq!(
+            select(&["what-weather", "past", "--days=30", "--stats", "--json"]),
+            (
+                OutputFormat::Json,
+                ReportContent::Statistics,
+                all_attributes.clone()
+            )
+        );
+        assert_eq!(
+            select(&["what-weather", "past", "--days=30", "--stats", "--csv"]),
+            (OutputFormat::Csv, ReportContent::Statistics, all_attributes)
+        );
+        assert_eq!(
+            select(&[
+                "what-weather",
+                "past",
+                "--days=30",
+                "--stats",
+                "--list=wind"
+            ]),
+            (
+                OutputFormat::List,
+                ReportContent::Statistics,
+                RequestKind::PastPartial(30, WeatherAttributeSet::from([WeatherAttribute::Wind]))
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_multiple_output_formats() {
+        let result = Args::try_parse_from(["what-weather", "now", "--json", "--csv"]);
+        assert!(result.is_err());
+        let result = Args::try_parse_from(["what-weather", "forecast", "--summary", "--json"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn selects_destination() {
+        let select = |arguments: &[&str]| {
+            let args = Args::try_parse_from(arguments).unwrap();
+            convert_args_to_parameters(args, Config::default()).destination
+        };
+        assert_eq!(select(&["what-weather"]), Destination::Console);
+        assert_eq!(
+            select(&["what-weather", "--output=report.txt"]),
+            Destination::File {
+                path: "report.txt".into(),
+                append: false
+            }
+        );
+        assert_eq!(
+            select(&["what-weather", "--output=report.log", "--append"]),
+            Destination::File {
+                path: "report.log".into(),
+                append: true
+            }
+        );
+        assert_eq!(
+            select(&["what-weather", "--pipe=mail -s weather me"]),
+            Destination::Command("mail -s weather me".into())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_destination_combinations() {
+        let result = Args::try_parse_from(["what-weather", "--output=a.txt", "--pipe=cat"]);
+        assert!(result.is_err());
+        let result = Args::try_parse_from(["what-weather", "--append"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn selects_verbosity() {
+        let select = |arguments: &[&str]| {
+            let args = Args::try_parse_from(arguments).unwrap();
+            convert_args_to_parameters(args, Config::default()).verbosity
+        };
+        assert_eq!(select(&["what-weather"]), Verbosity::Normal);
+        assert_eq!(select(&["what-weather", "--quiet"]), Verbosity::Quiet);
+        assert_eq!(select(&["what-weather", "-v"]), Verbosity::Verbose);
+        let result = Args::try_parse_from(["what-weather", "--quiet", "--verbose"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn accepts_repeated_coordinates() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "--coords=52.23,21.01",
+            "--coords=50.06,19.94",
+            "now",
+        ])
+        .unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(
+            params.coordinates,
+            vec![
+                Coordinates::new(52.23, 21.01),
+                Coordinates::new(50.06, 19.94)
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_compare_command() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "compare",
+            "--coords=52.23,21.01",
+            "--coords=50.06,19.94",
+        ])
+        .unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected_attribute_set: WeatherAttributeSet = WeatherAttribute::iter().collect();
+        assert_eq!(
+            params.request_kind,
+            RequestKind::CurrentPartial(expected_attribute_set)
+        );
+        assert_eq!(params.output_format, OutputFormat::Comparison);
+        assert_eq!(params.coordinates.len(), 2);
+    }
+
+    #[test]
+    fn parses_compare_command_with_days_and_attributes() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "compare",
+            "--days=3",
+            "--attributes=temperature,wind",
+        ])
+        .unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected_attribute_set =
+            WeatherAttributeSet::from([WeatherAttribute::Temperature, WeatherAttribute::Wind]);
+        assert_eq!(
+            params.request_kind,
+            RequestKind::ForecastPartial(3, expected_attribute_set)
+        );
+    }
+
+    #[test]
+    fn parses_degree_days_command() {
+        let args =
+            Args::try_parse_from(["what-weather", "degree-days", "--past=30", "--base=15.5"])
+                .unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected_attribute_set = WeatherAttributeSet::from([WeatherAttribute::Temperature]);
+        assert_eq!(
+            params.request_kind,
+            RequestKind::PastPartial(30, expected_attribute_set)
+        );
+        assert_eq!(params.output_format, OutputFormat::Summary);
+        assert_eq!(
+            params.content,
+            ReportContent::DegreeDays(Temperature::new_celsius(15.5))
+        );
+    }
+
+    #[test]
+    fn parses_degree_days_command_with_forecast_and_default_base() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "--temp-unit=fahrenheit",
+            "degree-days",
+            "--days=7",
+            "--csv",
+        ])
+        .unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        let expected_attribute_set = WeatherAttributeSet::from([WeatherAttribute::Temperature]);
+        assert_eq!(
+            params.request_kind,
+            RequestKind::ForecastPartial(7, expected_attribute_set)
+        );
+        assert_eq!(params.output_format, OutputFormat::Csv);
+        assert_eq!(
+            params.content,
+            ReportContent::DegreeDays(Temperature::new_fahrenheit(65.0))
+        );
+    }
+
+    #[test]
+    fn requires_period_of_degree_days() {
+        let result = Args::try_parse_from(["what-weather", "degree-days"]);
+        assert!(result.is_err());
+        let result = Args::try_parse_from(["what-weather", "degree-days", "--past=3", "--days=3"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_base_temperature_in_either_unit() {
+        let parse = |string| {
+            parse_base_temperature(string)
+                .unwrap()
+                .to_temperature(TemperatureUnit::Celsius)
+        };
+        assert_eq!(parse("18"), Temperature::new_celsius(18.0));
+        assert_eq!(parse("-2.5c"), Temperature::new_celsius(-2.5));
+        assert_eq!(parse("65F"), Temperature::new_fahrenheit(65.0));
+        assert_eq!(parse("65 °F"), Temperature::new_fahrenheit(65.0));
+        assert!(parse_base_temperature("warm").is_err());
+        assert!(parse_base_temperature("18K").is_err());
+    }
+
+    #[test]
+    fn parses_alert_command() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "alert",
+            "--if",
+            "temperature<0",
+            "--if=wind>15m/s",
+            "--days=2",
+        ])
+        .unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::Alert(parameters, conditions) = action else {
+            panic!("Expected alert action");
+        };
+        let expected_attribute_set =
+            WeatherAttributeSet::from([WeatherAttribute::Temperature, WeatherAttribute::Wind]);
+        assert_eq!(
+            parameters.request_kind,
+            RequestKind::ForecastPartial(2, expected_attribute_set)
+        );
+        let conditions: Vec<String> = conditions.iter().map(Condition::to_string).collect();
+        assert_eq!(conditions, ["temperature<0", "wind>15m/s"]);
+    }
+
+    #[test]
+    fn parses_verify_command() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "--config=/etc/what-weather/config.toml",
+            "verify",
+        ])
+        .unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::Verify(parameters) = action else {
+            panic!("Expected verify action");
+        };
+        assert_eq!(parameters.request_kind, RequestKind::PastFull(14));
+        assert_eq!(
+            parameters.forecast_store,
+            Some(PathBuf::from("/etc/what-weather/forecasts.toml"))
+        );
+
+        let args = Args::try_parse_from(["what-weather", "verify", "--days=30"]).unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(params.request_kind, RequestKind::PastFull(30));
+        assert!(Args::try_parse_from(["what-weather", "verify", "--days=93"]).is_err());
+    }
+
+    #[test]
+    fn parses_now_command_with_watch_interval() {
+        let args = Args::try_parse_from(["what-weather", "now", "--watch"]).unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::Watch(parameters, interval) = action else {
+            panic!("Expected watch action");
+        };
+        assert_eq!(interval, watch::DEFAULT_INTERVAL);
+        assert_eq!(parameters.request_kind, RequestKind::CurrentFull);
+
+        let args = Args::try_parse_from(["what-weather", "now", "--watch=90s", "--list"]).unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::Watch(parameters, interval) = action else {
+            panic!("Expected watch action");
+        };
+        assert_eq!(interval, Duration::from_secs(90));
+        assert_eq!(parameters.output_format, OutputFormat::List);
+    }
+
+    #[test]
+    fn parses_watch_intervals() {
+        assert_eq!(parse_watch_interval("5m"), Ok(Duration::from_secs(300)));
+        assert_eq!(parse_watch_interval("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(parse_watch_interval("20"), Ok(Duration::from_secs(1200)));
+        assert!(parse_watch_interval("30s").is_err());
+        assert!(parse_watch_interval("soon").is_err());
+    }
+
+    #[test]
+    fn rejects_watch_with_other_destination() {
+        let args = Args::try_parse_from(["what-weather", "--output=weather.txt", "now", "--watch"])
+            .unwrap();
+        let result = convert_args_to_action(args, Config::default(), load_test_store);
+        assert!(matches!(result, Err(Error::InvalidArguments(_))));
+    }
+
+    #[test]
+    fn parses_history_command() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "--config=/etc/what-weather/config.toml",
+            "--location=office",
+            "history",
+            "--coldest",
+            "--morning",
+            "--json",
+        ])
+        .unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::History(parameters) = action else {
+            panic!("Expected history action");
+        };
+        assert_eq!(parameters.output_format, OutputFormat::Json);
+        assert_eq!(
+            parameters.store,
+            Some(PathBuf::from("/etc/what-weather/history.toml"))
+        );
+        assert_eq!(
+            parameters.filter,
+            HistoryFilter {
+                query: HistoryQuery::Coldest,
+                day_count: 30,
+                mornings_only: true,
+                coordinates: vec![Coordinates::new(52.2297, 21.0122)],
+            }
+        );
+
+        let args =
+            Args::try_parse_from(["what-weather", "history", "--last=Snow", "--days=7"]).unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::History(parameters) = action else {
+            panic!("Expected history action");
+        };
+        assert_eq!(
+            parameters.filter.query,
+            HistoryQuery::Last(KindCategory::Snow)
+        );
+        assert_eq!(parameters.filter.day_count, 7);
+    }
+
+    #[test]
+    fn rejects_invalid_history_queries() {
+        let result = Args::try_parse_from(["what-weather", "history", "--coldest", "--warmest"]);
+        assert!(result.is_err());
+        let result = Args::try_parse_from(["what-weather", "history", "--last=hail"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn records_history_only_when_enabled_in_config() {
+        let args = Args::try_parse_from(["what-weather", "--config=/tmp/config.toml"]).unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(params.history_store, None);
+
+        let args = Args::try_parse_from(["what-weather", "--config=/tmp/config.toml"]).unwrap();
+        let config = Config {
+            record_history: true,
+            ..Config::default()
+        };
+        let params = convert_args_to_parameters(args, config);
+        assert_eq!(
+            params.history_store,
+            Some(PathBuf::from("/tmp/history.toml"))
+        );
+    }
+
+    #[test]
+    fn records_forecasts_only_when_enabled_in_config() {
+        let args = Args::try_parse_from(["what-weather", "--config=/tmp/config.toml"]).unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(params.forecast_store, None);
+
+        let args = Args::try_parse_from(["what-weather", "--config=/tmp/config.toml"]).unwrap();
+        let config = Config {
+            record_forecasts: true,
+            ..Config::default()
+        };
+        let params = convert_args_to_parameters(args, config);
+        assert_eq!(
+            params.forecast_store,
+            Some(PathBuf::from("/tmp/forecasts.toml"))
+        );
+    }
+
+    #[test]
+    fn selects_place_name_source_from_config() {
+        let args = Args::try_parse_from(["what-weather", "--config=/tmp/config.toml"]).unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(params.place_names, PlaceNameSource::Offline);
+
+        let args = Args::try_parse_from(["what-weather", "--config=/tmp/config.toml"]).unwrap();
+        let config = Config {
+            place_names: PlaceNames::Online,
+            ..Config::default()
+        };
+        let params = convert_args_to_parameters(args, config);
+        assert_eq!(
+            params.place_names,
+            PlaceNameSource::Online {
+                cache: Some(PathBuf::from("/tmp/places.toml"))
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_alert_without_conditions() {
+        let result = Args::try_parse_from(["what-weather", "alert", "--days=2"]);
+        assert!(result.is_err());
+        let result = Args::try_parse_from(["what-weather", "alert", "--if=temperature>15kn"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_forecast_with_builtin_activity_for_a_week() {
+        let args =
+            Args::try_parse_from(["what-weather", "forecast", "--activity=running"]).unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::Report(parameters) = action else {
+            panic!("Expected report action");
+        };
+        assert_eq!(parameters.request_kind, RequestKind::ForecastFull(7));
+        let ReportContent::Activity(profile) = parameters.content else {
+            panic!("Expected activity content");
+        };
+        assert_eq!(profile.name, "running");
+
+        let args =
+            Args::try_parse_from(["what-weather", "forecast", "--activity=bbq", "--csv"]).unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store).unwrap();
+        let Action::Report(parameters) = action else {
+            panic!("Expected report action");
+        };
+        assert_eq!(parameters.output_format, OutputFormat::Csv);
+        assert!(matches!(parameters.content, ReportContent::Activity(_)));
+    }
+
+    #[test]
+    fn applies_configured_activity_profile() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "forecast",
+            "--activity=gardening",
+            "--days=3",
+        ])
+        .unwrap();
+        let config = Config {
+            activities: HashMap::from([(
+                "gardening".into(),
+                config::ActivityConfig {
+                    wind: Some(0.0),
+                    ideal_temperature: Some((10.0, 20.0)),
+                    ..config::ActivityConfig::default()
+                },
+            )]),
+            ..Config::default()
+        };
+        let action = convert_args_to_action(args, config, load_test_store).unwrap();
+        let Action::Report(parameters) = action else {
+            panic!("Expected report action");
+        };
+        assert_eq!(parameters.request_kind, RequestKind::ForecastFull(3));
+        let ReportContent::Activity(profile) = parameters.content else {
+            panic!("Expected activity content");
+        };
+        assert_eq!(profile.weights.wind, 0.0);
+        assert_eq!(profile.weights.temperature, 1.0);
+        assert_eq!(profile.ideal_temperature_celsius, (10.0, 20.0));
+    }
+
+    #[test]
+    fn parses_gear_with_default_or_configured_rules() {
+        let args = Args::try_parse_from(["what-weather", "now", "--gear"]).unwrap();
+        let params = convert_args_to_parameters(args, Config::default());
+        assert_eq!(
+            params.content,
+            ReportContent::WeatherWithGear(gear::default_rules())
+        );
+
+        let args =
+            Args::try_parse_from(["what-weather", "forecast", "--days=3", "--gear"]).unwrap();
+        let config = Config {
+            gear: vec![GearRuleConfig {
+                item: "a scarf".into(),
+                below_temperature: Some(5.0),
+                above_temperature: None,
+                precipitation: None,
+                min_intensity: None,
+                freezing: false,
+                min_wind_speed: None,
+                max_cloud_coverage: None,
+                min_uv_index: None,
+            }],
+            ..Config::default()
+        };
+        let params = convert_args_to_parameters(args, config);
+        assert_eq!(params.request_kind, RequestKind::ForecastFull(3));
+        let expected_rules = vec![GearRule {
+            below_temperature_celsius: Some(5.0),
+            ..GearRule::new("a scarf")
+        }];
+        assert_eq!(
+            params.content,
+            ReportContent::WeatherWithGear(expected_rules)
+        );
+
+        let result = Args::try_parse_from(["what-weather", "now", "--gear", "--json"]);
+        assert!(result.is_err());
+        let result = Args::try_parse_from(["what-weather", "now", "--gear", "--list"]);
+        assert!(result.is_err());
+        let result =
+            Args::try_parse_from(["what-weather", "forecast", "--gear", "--list=temperature"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_activity() {
+        let args = Args::try_parse_from(["what-weather", "forecast", "--activity=skiing"]).unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store);
+        assert!(matches!(action, Err(Error::UnknownActivity(name)) if name == "skiing"));
+        let result = Args::try_parse_from(["what-weather", "forecast", "--activity=bbq", "--list"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_here_together_with_coordinates_after_command() {
+        let result =
+            Args::try_parse_from(["what-weather", "compare", "--here", "--coords=1.2,3.4"]);
+        assert!(result.is_err());
+    }
+
+    fn load_test_store() -> Result<LocationStore, Error> {
+        let path = crate::test_support::make_test_path(LOCATIONS_FILE_NAME);
+        let mut store = LocationStore::load(&path).unwrap();
+        store.add("office", Coordinates::new(52.2297, 21.0122));
+        Ok(store)
+    }
+
+    fn fail_to_load_store() -> Result<LocationStore, Error> {
+        Err(Error::Locations(locations::Error::File(
+            file::Error::Parsing(LOCATIONS_FILE_NAME.into(), "invalid".into()),
+        )))
+    }
+
+    #[test]
+    fn loads_locations_only_when_needed() {
+        let args =
+            Args::try_parse_from(["what-weather", "--coords=52.2297,21.0122", "now"]).unwrap();
+        let result = convert_args_to_action(args, Config::default(), fail_to_load_store);
+        assert!(matches!(result, Ok(Action::Report(_))));
+
+        let args = Args::try_parse_from(["what-weather", "--location=office", "now"]).unwrap();
+        let result = convert_args_to_action(args, Config::default(), fail_to_load_store);
+        assert!(matches!(result, Err(Error::Locations(_))));
+
+        let args = Args::try_parse_from(["what-weather", "locations", "list"]).unwrap();
+        let result = convert_args_to_action(args, Config::default(), fail_to_load_store);
+        assert!(matches!(result, Err(Error::Locations(_))));
+    }
+
+    #[test]
+    fn resolves_named_locations_after_coordinates() {
+        let args = Args::try_parse_from([
+            "what-weather",
+            "--location=office",
+            "now",
+            "--coords=50.06,19.94",
+        ])
+        .unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store);
+        let Ok(Action::Report(params)) = action else {
+            panic!("Expected report action");
+        };
+        assert_eq!(
+            params.coordinates,
+            vec![
+                Coordinates::new(50.06, 19.94),
+                Coordinates::new(52.2297, 21.0122)
+            ]
+        );
+    }
+
+    #[test]
+    fn prefers_named_location_over_configured_coordinates() {
+        let args = Args::try_parse_from(["what-weather", "--location=office"]).unwrap();
+        let config = Config {
+            coords: Some(Coordinates::new(1.0, 2.0)),
+            ..Config::default()
+        };
+        let action = convert_args_to_action(args, config, load_test_store);
+        let Ok(Action::Report(params)) = action else {
+            panic!("Expected report action");
+        };
+        assert_eq!(params.coordinates, vec![Coordinates::new(52.2297, 21.0122)]);
+    }
+
+    #[test]
+    fn raises_error_on_unknown_location() {
+        let args = Args::try_parse_from(["what-weather", "--location=home"]).unwrap();
+        let action = convert_args_to_action(args, Config::default(), load_test_store);
+        assert!(matches!(
+            action,
+            Err(Error::Locations(locations::Error::UnknownLocation(_)))
+        ));
+    }
+
+    #[test]
+    fn parses_locations_commands() {
+        let convert = |arguments: &[&str]| {
+            let args = Args::try_parse_from(arguments).unwrap();
+            match convert_args_to_action(args, Config::default(), load_test_store) {
+                Ok(Action::ManageLocations(parameters)) => parameters.action,
+                _ => panic!("Expected locations action"),
+            }
+        };
+        assert_eq!(
+            convert(&[
+                "what-weather",
+                "locations",
+                "add",
+                "eiffel",
+                "48.8584,2.2944"
+            ]),
+            LocationAction::Add {
+                name: "eiffel".into(),
+                place: Place::Coordinates(Coordinates::new(48.8584, 2.2944)),
+            }
+        );
+        assert_eq!(
+            convert(&["what-weather", "locations", "add", "paris", "Paris, France"]),
+            LocationAction::Add {
+                name: "paris".into(),
+                place: Place::Address("Paris, France".into()),
+            }
+        );
+        assert_eq!(
+            convert(&["what-weather", "locations", "list"]),
+            LocationAction::List
+        );
+        assert_eq!(
+            convert(&["what-weather", "locations", "remove", "eiffel"]),
+            LocationAction::Remove {
+                name: "eiffel".into()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_here_together_with_location() {
+        let result = Args::try_parse_from(["what-weather", "--here", "--location=office"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn selects_locations_file_next_to_config_file() {
+        let path = select_store_path(
+            Some(Path::new("/etc/what-weather/config.toml")),
+            LOCATIONS_FILE_NAME,
+        );
+        assert_eq!(
+            path,
+            Some(PathBuf::from("/etc/what-weather/locations.toml"))
+        );
+    }
+
+    #[test]
+    fn selects_gps_location_source() {
+        let convert = |arguments: &[&str]| {
+            let args = Args::try_parse_from(arguments).unwrap();
+            convert_args_to_parameters(args, Config::default())
+        };
+        let params = convert(&["what-weather", "now"]);
+        assert_eq!(params.location_source, LocationSource::Ip);
+
+        let config = Config {
+            coords: Some(Coordinates::new(1.0, 2.0)),
+            ..Config::default()
+        };
+        let args = Args::try_parse_from(["what-weather", "--location-source=gps", "now"]).unwrap();
+        let params = convert_args_to_parameters(args, config);
+        assert!(params.coordinates.is_empty());
+        assert_eq!(
+            params.location_source,
+            LocationSource::Gpsd {
+                address: "localhost:2947".into(),
+                timeout: Duration::from_secs(10),
+            }
+        );
+
+        let params = convert(&[
+            "what-weather",
+            "--location-source=gps",
+            "--gps-device=/dev/ttyUSB0",
+            "--gps-timeout=30",
+            "now",
+        ]);
+        assert_eq!(
+            params.location_source,
+            LocationSource::Nmea {
+                path: PathBuf::from("/dev/ttyUSB0"),
+                timeout: Duration::from_secs(30),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_location_source_together_with_coordinates() {
+        let result =
+            Args::try_parse_from(["what-weather", "--location-source=gps", "--coords=1,2"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_timezone_option() {
+        let convert = |arguments: &[&str]| {
+            let args = Args::try_parse_from(arguments).unwrap();
+            convert_args_to_parameters(args, Config::default())
+        };
+        let params = convert(&["what-weather", "now"]);
+        assert_eq!(params.timezone, Timezone::Auto);
+        let params = convert(&["what-weather", "forecast", "--timezone=Asia/Tokyo"]);
+        assert_eq!(params.timezone, Timezone::Named("Asia/Tokyo".into()));
+        let params = convert(&["what-weather", "--timezone=GMT+2", "now"]);
+        let offset = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+        assert_eq!(params.timezone, Timezone::Fixed(offset));
+        let result = Args::try_parse_from(["what-weather", "--timezone=GMT+5:30", "now"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parses_anomaly_flag() {
+        let args = Args::try_parse_from(["what-weather", "forecast", "--days=3"]).unwrap();
+        assert!(!convert_args_to_parameters(args, Config::default()).include_normals);
+        let args = Args::try_parse_from(["what-weather", "past", "--days=3", "--anomaly"]).unwrap();
+        assert!(convert_args_to_parameters(args, Config::default()).include_normals);
     }
 }
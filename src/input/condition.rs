@@ -0,0 +1,138 @@
+use crate::types::attributes::WeatherAttribute;
+use crate::types::condition::*;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+const COMPARISONS: [(&str, Comparison); 4] = [
+    ("<=", Comparison::LessOrEqual),
+    (">=", Comparison::GreaterOrEqual),
+    ("<", Comparison::Less),
+    (">", Comparison::Greater),
+];
+
+impl FromStr for Condition {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (attribute, comparison, threshold) = split_condition(string).ok_or_else(|| {
+            format!("Invalid condition '{string}', expected e.g. temperature<0 or wind>15m/s")
+        })?;
+        let attribute = parse_attribute(attribute.trim())?;
+        let threshold = threshold.trim();
+        let number_end = threshold
+            .find(|character: char| !(character.is_ascii_digit() || "+-.".contains(character)))
+            .unwrap_or(threshold.len());
+        let (number, unit) = threshold.split_at(number_end);
+        let value: f32 = number
+            .parse()
+            .map_err(|_| format!("Invalid threshold '{threshold}' in condition '{string}'"))?;
+        let unit = parse_unit(unit.trim())?;
+        if let Some(unit) = unit
+            && !unit.applies_to(attribute)
+        {
+            return Err(format!(
+                "Unit {} does not apply to {}",
+                unit.to_symbol(),
+                attribute_name(attribute)
+            ));
+        }
+        Ok(Self {
+            attribute,
+            comparison,
+            threshold: value,
+            unit,
+        })
+    }
+}
+
+fn split_condition(string: &str) -> Option<(&str, Comparison, &str)> {
+    let position = string.find(['<', '>'])?;
+    let (attribute, rest) = string.split_at(position);
+    COMPARISONS.iter().find_map(|(symbol, comparison)| {
+        rest.strip_prefix(symbol)
+            .map(|threshold| (attribute, *comparison, threshold))
+    })
+}
+
+fn parse_attribute(name: &str) -> Result<WeatherAttribute, String> {
+    let attribute = WeatherAttribute::iter()
+        .find(|attribute| attribute_name(*attribute).eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("Unknown attribute '{name}' in condition"))?;
+    if attribute == WeatherAttribute::WeatherKind {
+        return Err(format!(
+            "Attribute {name} cannot be compared with a threshold"
+        ));
+    }
+    Ok(attribute)
+}
+
+fn parse_unit(unit: &str) -> Result<Option<ThresholdUnit>, String> {
+    let unit = match unit.to_ascii_lowercase().trim_start_matches('°') {
+        "" => return Ok(None),
+        "c" => ThresholdUnit::Celsius,
+        "f" => ThresholdUnit::Fahrenheit,
+        "m/s" | "ms" => ThresholdUnit::MetersPerSecond,
+        "km/h" | "kmh" => ThresholdUnit::KilometersPerHour,
+        "mph" => ThresholdUnit::MilesPerHour,
+        "kn" | "kt" => ThresholdUnit::Knots,
+        "%" => ThresholdUnit::Percent,
+        "hpa" => ThresholdUnit::Hectopascal,
+        _ => return Err(format!("Unknown unit '{unit}' in condition")),
+    };
+    Ok(Some(unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_condition_without_unit() {
+        assert_eq!(
+            Condition::from_str("temperature<0"),
+            Ok(Condition {
+                attribute: WeatherAttribute::Temperature,
+                comparison: Comparison::Less,
+                threshold: 0.0,
+                unit: None,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_condition_with_unit() {
+        assert_eq!(
+            Condition::from_str("wind >= 15 m/s"),
+            Ok(Condition {
+                attribute: WeatherAttribute::Wind,
+                comparison: Comparison::GreaterOrEqual,
+                threshold: 15.0,
+                unit: Some(ThresholdUnit::MetersPerSecond),
+            })
+        );
+        assert_eq!(
+            Condition::from_str("temperature<=-5.5°F").map(|condition| condition.unit),
+            Ok(Some(ThresholdUnit::Fahrenheit))
+        );
+        assert_eq!(
+            Condition::from_str("humidity>90%").map(|condition| condition.to_string()),
+            Ok("humidity>90%".into())
+        );
+    }
+
+    #[test]
+    fn rejects_unit_of_other_attribute() {
+        let result = Condition::from_str("temperature>15m/s");
+        assert_eq!(result, Err("Unit m/s does not apply to temperature".into()));
+    }
+
+    #[test]
+    fn rejects_invalid_conditions() {
+        assert!(Condition::from_str("temperature").is_err());
+        assert!(Condition::from_str("temperature=0").is_err());
+        assert!(Condition::from_str("weather_kind>0").is_err());
+        assert!(Condition::from_str("snow>10").is_err());
+        assert!(Condition::from_str("wind>fast").is_err());
+        assert!(Condition::from_str("wind>15furlongs").is_err());
+    }
+}
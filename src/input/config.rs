@@ -0,0 +1,306 @@
+use crate::types::activity::ActivityProfile;
+use crate::types::gear::GearRule;
+use crate::types::units::Coordinates;
+use crate::types::weather::{PrecipitationIntensity, PrecipitationKind};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const APPLICATION_DIRECTORY: &str = "what-weather";
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default, deserialize_with = "deserialize_coordinates")]
+    pub coords: Option<Coordinates>,
+    #[serde(default)]
+    pub activities: HashMap<String, ActivityConfig>,
+    #[serde(default)]
+    pub gear: Vec<GearRuleConfig>,
+    #[serde(default)]
+    pub record_history: bool,
+    #[serde(default)]
+    pub record_forecasts: bool,
+    #[serde(default)]
+    pub place_names: PlaceNames,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaceNames {
+    Off,
+    #[default]
+    Offline,
+    Online,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActivityConfig {
+    pub temperature: Option<f32>,
+    pub precipitation: Option<f32>,
+    pub wind: Option<f32>,
+    pub humidity: Option<f32>,
+    pub cloud_coverage: Option<f32>,
+    pub uv: Option<f32>,
+    pub ideal_temperature: Option<(f32, f32)>,
+    pub max_wind_speed: Option<f32>,
+}
+
+impl ActivityConfig {
+    pub fn apply(&self, profile: &mut ActivityProfile) {
+        let weights = &mut profile.weights;
+        let overrides = [
+            (&mut weights.temperature, self.temperature),
+            (&mut weights.precipitation, self.precipitation),
+            (&mut weights.wind, self.wind),
+            (&mut weights.humidity, self.humidity),
+            (&mut weights.cloud_coverage, self.cloud_coverage),
+            (&mut weights.uv, self.uv),
+        ];
+        for (weight, value) in overrides {
+            if let Some(value) = value {
+                *weight = value;
+            }
+        }
+        if let Some(range) = self.ideal_temperature {
+            profile.ideal_temperature_celsius = range;
+        }
+        if let Some(speed) = self.max_wind_speed {
+            profile.max_wind_speed_meters_per_second = speed;
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GearRuleConfig {
+    pub item: String,
+    pub below_temperature: Option<f32>,
+    pub above_temperature: Option<f32>,
+    pub precipitation: Option<PrecipitationConfig>,
+    pub min_intensity: Option<IntensityConfig>,
+    #[serde(default)]
+    pub freezing: bool,
+    pub min_wind_speed: Option<f32>,
+    pub max_cloud_coverage: Option<i8>,
+    pub min_uv_index: Option<f32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrecipitationConfig {
+    Rain,
+    Snow,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IntensityConfig {
+    Light,
+    Moderate,
+    Heavy,
+}
+
+impl GearRuleConfig {
+    pub fn to_rule(&self) -> GearRule {
+        GearRule {
+            item: self.item.clone(),
+            below_temperature_celsius: self.below_temperature,
+            above_temperature_celsius: self.above_temperature,
+            precipitation: self.precipitation.map(|kind| match kind {
+                PrecipitationConfig::Rain => PrecipitationKind::Rain,
+                PrecipitationConfig::Snow => PrecipitationKind::Snow,
+            }),
+            min_intensity: self.min_intensity.map(|intensity| match intensity {
+                IntensityConfig::Light => PrecipitationIntensity::Light,
+                IntensityConfig::Moderate => PrecipitationIntensity::Moderate,
+                IntensityConfig::Heavy => PrecipitationIntensity::Heavy,
+            }),
+            freezing: self.freezing,
+            min_wind_speed_meters_per_second: self.min_wind_speed,
+            max_cloud_coverage: self.max_cloud_coverage,
+            min_uv_index: self.min_uv_index,
+        }
+    }
+}
+
+impl FromStr for Config {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        toml::from_str(string).map_err(|error| Error::Parsing(error.message().into()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Reading(PathBuf),
+    Parsing(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reading(path) => write!(f, "Failed to read config file '{}'", path.display()),
+            Self::Parsing(message) => write!(f, "Failed to parse config file: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub fn default_directory() -> Option<PathBuf> {
+    dirs::config_dir().map(|directory| directory.join(APPLICATION_DIRECTORY))
+}
+
+pub fn load(path: Option<&Path>) -> Result<Config, Error> {
+    match path {
+        Some(path) => read(path),
+        None => match default_directory().map(|directory| directory.join(CONFIG_FILE_NAME)) {
+            Some(path) if path.exists() => read(&path),
+            _ => Ok(Config::default()),
+        },
+    }
+}
+
+fn read(path: &Path) -> Result<Config, Error> {
+    let content = std::fs::read_to_string(path).map_err(|_| Error::Reading(path.into()))?;
+    Config::from_str(&content)
+}
+
+fn deserialize_coordinates<'de, D>(deserializer: D) -> Result<Option<Coordinates>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    Coordinates::from_str(&string)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_empty_config() {
+        assert_eq!(Config::from_str(""), Ok(Config::default()));
+    }
+
+    #[test]
+    fn parses_default_coordinates() {
+        let config = Config::from_str("coords = \"52.2297,21.0122\"").unwrap();
+        assert_eq!(config.coords, Some(Coordinates::new(52.2297, 21.0122)));
+    }
+
+    #[test]
+    fn raises_error_on_invalid_coordinates() {
+        let result = Config::from_str("coords = \"foo,21.0122\"");
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+
+    #[test]
+    fn parses_gear_rules() {
+        let content = "[[gear]]\n\
+                       item = \"a raincoat\"\n\
+                       precipitation = \"rain\"\n\
+                       min_intensity = \"moderate\"\n\
+                       [[gear]]\n\
+                       item = \"a scarf\"\n\
+                       below_temperature = 5\n\
+                       [[gear]]\n\
+                       item = \"sunscreen\"\n\
+                       min_uv_index = 6\n";
+        let config = Config::from_str(content).unwrap();
+        let rules: Vec<GearRule> = config.gear.iter().map(GearRuleConfig::to_rule).collect();
+        assert_eq!(
+            rules,
+            [
+                GearRule {
+                    precipitation: Some(PrecipitationKind::Rain),
+                    min_intensity: Some(PrecipitationIntensity::Moderate),
+                    ..GearRule::new("a raincoat")
+                },
+                GearRule {
+                    below_temperature_celsius: Some(5.0),
+                    ..GearRule::new("a scarf")
+                },
+                GearRule {
+                    min_uv_index: Some(6.0),
+                    ..GearRule::new("sunscreen")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn raises_error_on_invalid_gear_rule() {
+        let result = Config::from_str("[[gear]]\nitem = \"boots\"\nprecipitation = \"hail\"\n");
+        assert!(matches!(result, Err(Error::Parsing(_))));
+        let result = Config::from_str("[[gear]]\nbelow_temperature = 5\n");
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+
+    #[test]
+    fn parses_activity_profiles() {
+        let content = "[activities.running]\n\
+                       wind = 4\n\
+                       uv = 2\n\
+                       ideal_temperature = [5, 15]\n\
+                       [activities.kayaking]\n\
+                       max_wind_speed = 6.5\n";
+        let config = Config::from_str(content).unwrap();
+        let mut profile = ActivityProfile::new("running");
+        config.activities["running"].apply(&mut profile);
+        assert_eq!(profile.weights.wind, 4.0);
+        assert_eq!(profile.weights.uv, 2.0);
+        assert_eq!(profile.weights.temperature, 1.0);
+        assert_eq!(profile.ideal_temperature_celsius, (5.0, 15.0));
+        assert_eq!(config.activities["kayaking"].max_wind_speed, Some(6.5));
+    }
+
+    #[test]
+    fn raises_error_on_unknown_activity_fields() {
+        let result = Config::from_str("[activities.running]\npollen = 2");
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+
+    #[test]
+    fn parses_history_recording_switch() {
+        assert!(!Config::default().record_history);
+        let config = Config::from_str("record_history = true").unwrap();
+        assert!(config.record_history);
+    }
+
+    #[test]
+    fn parses_forecast_recording_switch() {
+        assert!(!Config::default().record_forecasts);
+        let config = Config::from_str("record_forecasts = true").unwrap();
+        assert!(config.record_forecasts);
+    }
+
+    #[test]
+    fn parses_place_name_source() {
+        assert_eq!(Config::default().place_names, PlaceNames::Offline);
+        let config = Config::from_str("place_names = \"online\"").unwrap();
+        assert_eq!(config.place_names, PlaceNames::Online);
+        let config = Config::from_str("place_names = \"off\"").unwrap();
+        assert_eq!(config.place_names, PlaceNames::Off);
+        assert!(Config::from_str("place_names = \"nominatim\"").is_err());
+    }
+
+    #[test]
+    fn raises_error_on_unknown_fields() {
+        let result = Config::from_str("foo = \"bar\"");
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+
+    #[test]
+    fn raises_error_when_specified_file_does_not_exist() {
+        let path = Path::new("/nonexistent/what-weather/config.toml");
+        assert_eq!(load(Some(path)), Err(Error::Reading(path.into())));
+    }
+}
@@ -0,0 +1,367 @@
+use crate::types::units::Coordinates;
+use std::str::FromStr;
+
+const GEOHASH_ALPHABET: &str = "0123456789bcdefghjkmnpqrstuvwxyz";
+const PLUS_CODE_ALPHABET: &str = "23456789CFGHJMPQRVWX";
+const PLUS_CODE_SEPARATOR: char = '+';
+const PLUS_CODE_PADDING: char = '0';
+const PLUS_CODE_PAIR_LENGTH: usize = 10;
+const PLUS_CODE_PAIR_RESOLUTIONS: [f64; 5] = [20.0, 1.0, 0.05, 0.0025, 0.000125];
+const PLUS_CODE_GRID_ROWS: f64 = 5.0;
+const PLUS_CODE_GRID_COLUMNS: f64 = 4.0;
+
+impl FromStr for Coordinates {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        if string.contains(PLUS_CODE_SEPARATOR) {
+            parse_plus_code(string)
+        } else if is_geohash(string) {
+            parse_geohash(string)
+        } else {
+            parse_angles(string)
+        }
+    }
+}
+
+pub fn parse_without_geohash(string: &str) -> Result<Coordinates, String> {
+    if is_geohash(string.trim()) {
+        return Err("Geohash is not accepted here".into());
+    }
+    Coordinates::from_str(string)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Hemisphere {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Hemisphere {
+    fn from_letter(letter: char) -> Option<Self> {
+        match letter.to_ascii_uppercase() {
+            'N' => Some(Self::North),
+            'S' => Some(Self::South),
+            'E' => Some(Self::East),
+            'W' => Some(Self::West),
+            _ => None,
+        }
+    }
+
+    fn is_latitude(&self) -> bool {
+        matches!(self, Self::North | Self::South)
+    }
+
+    fn sign(&self) -> f64 {
+        match self {
+            Self::North | Self::East => 1.0,
+            Self::South | Self::West => -1.0,
+        }
+    }
+}
+
+struct Angle {
+    value: f64,
+    hemisphere: Option<Hemisphere>,
+}
+
+fn parse_angles(string: &str) -> Result<Coordinates, String> {
+    let (first, second) = split_angles(string).ok_or(
+        "Coordinates must be in format 'latitude,longitude', \
+         e.g. '52.2297,21.0122' or '48°51'30\"N 2°17'40\"E'",
+    )?;
+    let first = parse_angle(first)?;
+    let second = parse_angle(second)?;
+    let (latitude, longitude) = match (first.hemisphere, second.hemisphere) {
+        (Some(first_hemisphere), Some(second_hemisphere))
+            if first_hemisphere.is_latitude() == second_hemisphere.is_latitude() =>
+        {
+            return Err("Coordinates must have one latitude (N/S) and one longitude (E/W)".into());
+        }
+        (Some(hemisphere), _) if !hemisphere.is_latitude() => (second, first),
+        (_, Some(hemisphere)) if hemisphere.is_latitude() => (second, first),
+        _ => (first, second),
+    };
+    make_coordinates(latitude.value, longitude.value)
+}
+
+fn split_angles(string: &str) -> Option<(&str, &str)> {
+    if let Some((first, second)) = string.split_once(',') {
+        return (!second.contains(',')).then_some((first, second));
+    }
+    if let Some(index) = string.find(|c: char| Hemisphere::from_letter(c).is_some()) {
+        let (first, second) = string.split_at(index + 1);
+        if !second.trim().is_empty() {
+            return Some((first, second));
+        }
+    }
+    let parts: Vec<&str> = string.split_whitespace().collect();
+    match parts[..] {
+        [first, second] => Some((first, second)),
+        _ => None,
+    }
+}
+
+fn parse_angle(string: &str) -> Result<Angle, String> {
+    let string = string.trim();
+    let invalid = || format!("Invalid coordinate '{string}'");
+    let (number_part, hemisphere) = match string.chars().last().and_then(Hemisphere::from_letter) {
+        Some(hemisphere) => (&string[..string.len() - 1], Some(hemisphere)),
+        None => (string, None),
+    };
+    let components: Vec<f64> = number_part
+        .split(|c: char| c.is_whitespace() || "°º'′\"″".contains(c))
+        .filter(|component| !component.is_empty())
+        .map(|component| component.parse::<f64>().map_err(|_| invalid()))
+        .collect::<Result<_, _>>()?;
+    let (degrees, minutes, seconds) = match components[..] {
+        [degrees] => (degrees, 0.0, 0.0),
+        [degrees, minutes] => (degrees, minutes, 0.0),
+        [degrees, minutes, seconds] => (degrees, minutes, seconds),
+        _ => return Err(invalid()),
+    };
+    if !(0.0..60.0).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return Err(format!(
+            "Invalid coordinate '{string}', minutes and seconds must be between 0 and 60"
+        ));
+    }
+    let negative = number_part.trim_start().starts_with('-');
+    if negative && hemisphere.is_some() {
+        return Err(format!(
+            "Invalid coordinate '{string}', use either a minus sign or a hemisphere letter"
+        ));
+    }
+    let magnitude = degrees.abs() + minutes / 60.0 + seconds / 3600.0;
+    let sign = match hemisphere {
+        Some(hemisphere) => hemisphere.sign(),
+        None if negative => -1.0,
+        None => 1.0,
+    };
+    Ok(Angle {
+        value: sign * magnitude,
+        hemisphere,
+    })
+}
+
+fn make_coordinates(latitude: f64, longitude: f64) -> Result<Coordinates, String> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(format!(
+            "Latitude {latitude} is out of range, must be between -90 and 90"
+        ));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(format!(
+            "Longitude {longitude} is out of range, must be between -180 and 180"
+        ));
+    }
+    Ok(Coordinates::new(latitude as f32, longitude as f32))
+}
+
+fn is_geohash(string: &str) -> bool {
+    (1..=12).contains(&string.len())
+        && string.chars().all(|c| GEOHASH_ALPHABET.contains(c))
+        && string.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+fn parse_geohash(string: &str) -> Result<Coordinates, String> {
+    let mut latitude_range = (-90.0, 90.0);
+    let mut longitude_range = (-180.0, 180.0);
+    let mut is_longitude_bit = true;
+    for character in string.chars() {
+        let index = GEOHASH_ALPHABET
+            .find(character)
+            .ok_or_else(|| format!("Invalid geohash '{string}'"))?;
+        for bit in (0..5).rev() {
+            let range: &mut (f64, f64) = if is_longitude_bit {
+                &mut longitude_range
+            } else {
+                &mut latitude_range
+            };
+            let middle = (range.0 + range.1) / 2.0;
+            if index & (1 << bit) != 0 {
+                range.0 = middle;
+            } else {
+                range.1 = middle;
+            }
+            is_longitude_bit = !is_longitude_bit;
+        }
+    }
+    make_coordinates(
+        (latitude_range.0 + latitude_range.1) / 2.0,
+        (longitude_range.0 + longitude_range.1) / 2.0,
+    )
+}
+
+fn parse_plus_code(string: &str) -> Result<Coordinates, String> {
+    let invalid = || format!("Invalid plus code '{string}'");
+    let code = string.to_ascii_uppercase();
+    let (prefix, suffix) = code.split_once(PLUS_CODE_SEPARATOR).ok_or_else(invalid)?;
+    if prefix.len() < 8 {
+        return Err(format!(
+            "Short plus code '{string}' is not supported, use the full code with 8 characters before '+'"
+        ));
+    }
+    if prefix.len() != 8 || suffix.len() == 1 || suffix.contains(PLUS_CODE_SEPARATOR) {
+        return Err(invalid());
+    }
+    let digits: String = prefix.trim_end_matches(PLUS_CODE_PADDING).to_owned() + suffix;
+    if digits.len() % 2 == 1 && digits.len() < PLUS_CODE_PAIR_LENGTH {
+        return Err(invalid());
+    }
+    let values: Vec<f64> = digits
+        .chars()
+        .map(|c| PLUS_CODE_ALPHABET.find(c).map(|index| index as f64))
+        .collect::<Option<_>>()
+        .ok_or_else(invalid)?;
+
+    let mut latitude = -90.0;
+    let mut longitude = -180.0;
+    let mut latitude_step = PLUS_CODE_PAIR_RESOLUTIONS[0] * 20.0;
+    let mut longitude_step = latitude_step;
+    let (pairs, grid) = values.split_at(values.len().min(PLUS_CODE_PAIR_LENGTH));
+    for (pair, resolution) in pairs.chunks(2).zip(PLUS_CODE_PAIR_RESOLUTIONS) {
+        latitude += pair[0] * resolution;
+        longitude += pair[1] * resolution;
+        latitude_step = resolution;
+        longitude_step = resolution;
+    }
+    for value in grid {
+        latitude_step /= PLUS_CODE_GRID_ROWS;
+        longitude_step /= PLUS_CODE_GRID_COLUMNS;
+        latitude += (value / PLUS_CODE_GRID_COLUMNS).floor() * latitude_step;
+        longitude += (value % PLUS_CODE_GRID_COLUMNS) * longitude_step;
+    }
+    make_coordinates(
+        latitude + latitude_step / 2.0,
+        longitude + longitude_step / 2.0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(result: Result<Coordinates, String>, latitude: f32, longitude: f32) {
+        let coordinates = result.unwrap();
+        let tolerance = 0.0005;
+        assert!(
+            (coordinates.latitude.raw() - latitude).abs() < tolerance
+                && (coordinates.longitude.raw() - longitude).abs() < tolerance,
+            "{coordinates:.5} is not near {latitude}, {longitude}"
+        );
+    }
+
+    #[test]
+    fn parses_decimal_coordinates() {
+        assert_eq!(
+            Coordinates::from_str("1.23,45.67"),
+            Ok(Coordinates::new(1.23, 45.67))
+        );
+        assert_eq!(
+            Coordinates::from_str(" -33.9249 , 18.4241 "),
+            Ok(Coordinates::new(-33.9249, 18.4241))
+        );
+        assert_eq!(
+            Coordinates::from_str("52.2297 21.0122"),
+            Ok(Coordinates::new(52.2297, 21.0122))
+        );
+    }
+
+    #[test]
+    fn parses_coordinates_with_hemisphere_letters() {
+        assert_near(
+            Coordinates::from_str("33.9249S,18.4241E"),
+            -33.9249,
+            18.4241,
+        );
+        assert_near(Coordinates::from_str("40.7128N 74.0060W"), 40.7128, -74.006);
+        assert_near(
+            Coordinates::from_str("40.7128°n, 74.0060°w"),
+            40.7128,
+            -74.006,
+        );
+    }
+
+    #[test]
+    fn parses_coordinates_in_swapped_order_when_hemispheres_are_given() {
+        assert_near(Coordinates::from_str("74.0060W 40.7128N"), 40.7128, -74.006);
+    }
+
+    #[test]
+    fn parses_degrees_minutes_seconds() {
+        assert_near(
+            Coordinates::from_str("48°51'30\"N 2°17'40\"E"),
+            48.858_334,
+            2.294_444,
+        );
+        assert_near(
+            Coordinates::from_str("48° 51′ 30″ N, 2° 17′ 40″ E"),
+            48.858_334,
+            2.294_444,
+        );
+        assert_near(Coordinates::from_str("34°36'S 58°22'W"), -34.6, -58.366_665);
+        assert_near(
+            Coordinates::from_str("-34°36', -58°22'"),
+            -34.6,
+            -58.366_665,
+        );
+    }
+
+    #[test]
+    fn parses_geohash() {
+        assert_near(Coordinates::from_str("u3qcnhhs5"), 52.2297, 21.0122);
+        assert_near(Coordinates::from_str("dr5regw3p"), 40.7128, -74.006);
+    }
+
+    #[test]
+    fn parses_plus_codes() {
+        assert_near(Coordinates::from_str("8FW4V75V+8Q"), 48.858_3, 2.294_5);
+        assert_near(Coordinates::from_str("9G4362J3+GR"), 52.231_3, 21.004_5);
+        assert_near(Coordinates::from_str("9g4362j3+gr"), 52.231_3, 21.004_5);
+        assert_near(Coordinates::from_str("9G430000+"), 52.5, 21.5);
+    }
+
+    #[test]
+    fn rejects_short_plus_codes() {
+        let result = Coordinates::from_str("62J3+GR");
+        assert!(result.unwrap_err().contains("Short plus code"));
+    }
+
+    #[test]
+    fn rejects_coordinates_out_of_range() {
+        let error = Coordinates::from_str("200,500").unwrap_err();
+        assert_eq!(
+            error,
+            "Latitude 200 is out of range, must be between -90 and 90"
+        );
+        let error = Coordinates::from_str("20,-500").unwrap_err();
+        assert_eq!(
+            error,
+            "Longitude -500 is out of range, must be between -180 and 180"
+        );
+        assert!(Coordinates::from_str("91N,0E").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_coordinates() {
+        assert!(Coordinates::from_str("foo,45.67").is_err());
+        assert!(Coordinates::from_str("1.23,bar").is_err());
+        assert!(Coordinates::from_str("1.2,3.4,5.6").is_err());
+        assert!(Coordinates::from_str("48°61'N 2°17'E").is_err());
+        assert!(Coordinates::from_str("-48N 2E").is_err());
+        assert!(Coordinates::from_str("48N 2S").is_err());
+        assert!(Coordinates::from_str("Paris").is_err());
+    }
+
+    #[test]
+    fn rejects_geohash_when_not_accepted() {
+        assert!(parse_without_geohash("bern").is_err());
+        assert_eq!(
+            parse_without_geohash("1.23,45.67"),
+            Ok(Coordinates::new(1.23, 45.67))
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::types::timezone::Timezone;
+use chrono::FixedOffset;
+use std::str::FromStr;
+
+const AUTO: &str = "auto";
+const UTC_PREFIXES: [&str; 2] = ["UTC", "GMT"];
+const MAX_HOURS_EAST: i32 = 14;
+const MAX_HOURS_WEST: i32 = 12;
+
+impl FromStr for Timezone {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        if string.eq_ignore_ascii_case(AUTO) {
+            return Ok(Self::Auto);
+        }
+        let upper = string.to_ascii_uppercase();
+        if let Some(offset) = UTC_PREFIXES
+            .iter()
+            .find_map(|prefix| upper.strip_prefix(prefix))
+        {
+            return if offset.is_empty() {
+                Ok(Self::Fixed(FixedOffset::east_opt(0).unwrap()))
+            } else {
+                parse_offset(offset).map(Self::Fixed)
+            };
+        }
+        if string.starts_with(['+', '-']) {
+            return parse_offset(string).map(Self::Fixed);
+        }
+        if is_iana_name(string) {
+            return Ok(Self::Named(string.into()));
+        }
+        Err(format!(
+            "Invalid timezone '{string}', expected a name like Europe/Warsaw or an offset like GMT+2"
+        ))
+    }
+}
+
+fn parse_offset(string: &str) -> Result<FixedOffset, String> {
+    let invalid = || format!("Invalid UTC offset '{string}'");
+    let (sign, rest) = match string.split_at_checked(1) {
+        Some(("+", rest)) => (1, rest),
+        Some(("-", rest)) => (-1, rest),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if minutes != 0 {
+        return Err(format!(
+            "UTC offset '{string}' is not a whole hour, use a timezone name like Asia/Kolkata instead"
+        ));
+    }
+    let hours = sign * hours;
+    if !(-MAX_HOURS_WEST..=MAX_HOURS_EAST).contains(&hours) {
+        return Err(format!(
+            "UTC offset {hours} is out of range, must be between -{MAX_HOURS_WEST} and +{MAX_HOURS_EAST} hours"
+        ));
+    }
+    FixedOffset::east_opt(hours * 3600).ok_or_else(invalid)
+}
+
+fn is_iana_name(string: &str) -> bool {
+    let (area, location) = match string.split_once('/') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let is_part = |part: &str| {
+        part.starts_with(|character: char| character.is_ascii_alphabetic())
+            && part
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "/_-+".contains(character))
+    };
+    is_part(area) && is_part(location)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hours_east(hours: i32) -> Timezone {
+        Timezone::Fixed(FixedOffset::east_opt(hours * 3600).unwrap())
+    }
+
+    #[test]
+    fn parses_auto() {
+        assert_eq!(Timezone::from_str("auto"), Ok(Timezone::Auto));
+    }
+
+    #[test]
+    fn parses_iana_names() {
+        assert_eq!(
+            Timezone::from_str("Europe/Warsaw"),
+            Ok(Timezone::Named("Europe/Warsaw".into()))
+        );
+        assert_eq!(
+            Timezone::from_str("America/Argentina/Buenos_Aires"),
+            Ok(Timezone::Named("America/Argentina/Buenos_Aires".into()))
+        );
+    }
+
+    #[test]
+    fn parses_fixed_offsets() {
+        assert_eq!(Timezone::from_str("GMT+2"), Ok(hours_east(2)));
+        assert_eq!(Timezone::from_str("utc-05:00"), Ok(hours_east(-5)));
+        assert_eq!(Timezone::from_str("+0300"), Ok(hours_east(3)));
+        assert_eq!(Timezone::from_str("UTC"), Ok(hours_east(0)));
+    }
+
+    #[test]
+    fn rejects_fractional_offsets() {
+        let result = Timezone::from_str("GMT+5:30");
+        assert!(result.unwrap_err().contains("not a whole hour"));
+    }
+
+    #[test]
+    fn rejects_offsets_out_of_range() {
+        let result = Timezone::from_str("GMT+15");
+        assert!(result.unwrap_err().contains("out of range"));
+    }
+
+    #[test]
+    fn rejects_unknown_formats() {
+        assert!(Timezone::from_str("Warsaw").is_err());
+        assert!(Timezone::from_str("GMT+two").is_err());
+        assert!(Timezone::from_str("Europe/").is_err());
+    }
+}
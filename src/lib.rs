@@ -1,7 +1,13 @@
+pub mod exit_status;
 pub mod external;
 pub mod format;
 pub mod input;
+pub mod location_manager;
 pub mod output;
 pub mod port;
+pub mod store;
+#[cfg(test)]
+mod test_support;
 pub mod types;
+pub mod watcher;
 pub mod weather_reporter;
@@ -0,0 +1,219 @@
+use crate::output::{Destination, Diagnostics, Verbosity};
+use crate::port::geocoding::GeocodingProvider;
+use crate::store::locations::{self, LocationStore};
+use crate::types::error::FetchError;
+use crate::types::units::Coordinates;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Place {
+    Coordinates(Coordinates),
+    Address(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationAction {
+    Add { name: String, place: Place },
+    List,
+    Remove { name: String },
+}
+
+pub struct LocationParameters {
+    pub store: LocationStore,
+    pub action: LocationAction,
+    pub destination: Destination,
+    pub verbosity: Verbosity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Store(locations::Error),
+    Geocoding(FetchError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Store(error) => error.fmt(f),
+            Self::Geocoding(error) => write!(f, "Failed to find address: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub struct LocationManager<GC: GeocodingProvider> {
+    geocoding_provider: GC,
+}
+
+impl<GC: GeocodingProvider> LocationManager<GC> {
+    pub fn new(geocoding_provider: GC) -> Self {
+        Self { geocoding_provider }
+    }
+
+    pub fn run(&self, parameters: LocationParameters) -> Result<String, Error> {
+        let diagnostics = Diagnostics::new(parameters.verbosity);
+        let mut store = parameters.store;
+        match parameters.action {
+            LocationAction::Add { name, place } => {
+                let coordinates = self.resolve_place(place, &diagnostics)?;
+                store.add(&name, coordinates);
+                store.save().map_err(Error::Store)?;
+                Ok(format!("Saved location '{name}' at {coordinates:.4}\n"))
+            }
+            LocationAction::List => {
+                let mut result = String::new();
+                for (name, coordinates) in store.iter() {
+                    writeln!(result, "{name}: {coordinates:.4}").expect("Failed to write location");
+                }
+                Ok(result)
+            }
+            LocationAction::Remove { name } => {
+                store.remove(&name).map_err(Error::Store)?;
+                store.save().map_err(Error::Store)?;
+                Ok(format!("Removed location '{name}'\n"))
+            }
+        }
+    }
+
+    fn resolve_place(&self, place: Place, diagnostics: &Diagnostics) -> Result<Coordinates, Error> {
+        match place {
+            Place::Coordinates(coordinates) => Ok(coordinates),
+            Place::Address(address) => {
+                diagnostics.info(format!("Searching for address '{address}'"));
+                self.geocoding_provider
+                    .search(&address)
+                    .map_err(Error::Geocoding)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::mocks::MockGeocodingProvider;
+    use crate::store::locations::LOCATIONS_FILE_NAME;
+    use mockall::predicate::eq;
+    use std::path::PathBuf;
+
+    fn make_store_path() -> PathBuf {
+        crate::test_support::make_test_path(LOCATIONS_FILE_NAME)
+    }
+
+    fn make_store() -> LocationStore {
+        LocationStore::load(&make_store_path()).unwrap()
+    }
+
+    fn make_parameters(store: LocationStore, action: LocationAction) -> LocationParameters {
+        LocationParameters {
+            store,
+            action,
+            destination: Destination::Console,
+            verbosity: Verbosity::Normal,
+        }
+    }
+
+    #[test]
+    fn adds_location_with_coordinates() {
+        let mut geocoding_provider = MockGeocodingProvider::new();
+        geocoding_provider.expect_search().never();
+
+        let sut = LocationManager::new(geocoding_provider);
+        let action = LocationAction::Add {
+            name: "eiffel".into(),
+            place: Place::Coordinates(Coordinates::new(48.8584, 2.2944)),
+        };
+        let path = make_store_path();
+        let store = LocationStore::load(&path).unwrap();
+        let result = sut.run(make_parameters(store, action));
+        assert_eq!(
+            result,
+            Ok("Saved location 'eiffel' at 48.8584°, 2.2944°\n".into())
+        );
+        let store = LocationStore::load(&path).unwrap();
+        assert_eq!(store.get("eiffel"), Ok(Coordinates::new(48.8584, 2.2944)));
+    }
+
+    #[test]
+    fn adds_location_with_address() {
+        let mut geocoding_provider = MockGeocodingProvider::new();
+        geocoding_provider
+            .expect_search()
+            .with(eq("Paris"))
+            .times(1)
+            .return_const(Ok(Coordinates::new(48.8534, 2.3488)));
+
+        let sut = LocationManager::new(geocoding_provider);
+        let action = LocationAction::Add {
+            name: "paris".into(),
+            place: Place::Address("Paris".into()),
+        };
+        let path = make_store_path();
+        let store = LocationStore::load(&path).unwrap();
+        let result = sut.run(make_parameters(store, action));
+        assert!(result.is_ok());
+        let store = LocationStore::load(&path).unwrap();
+        assert_eq!(store.get("paris"), Ok(Coordinates::new(48.8534, 2.3488)));
+    }
+
+    #[test]
+    fn fails_to_add_location_with_unknown_address() {
+        let mut geocoding_provider = MockGeocodingProvider::new();
+        geocoding_provider
+            .expect_search()
+            .return_const(Err(FetchError::NotFound));
+
+        let sut = LocationManager::new(geocoding_provider);
+        let action = LocationAction::Add {
+            name: "nowhere".into(),
+            place: Place::Address("Nowhere".into()),
+        };
+        let result = sut.run(make_parameters(make_store(), action));
+        assert_eq!(result, Err(Error::Geocoding(FetchError::NotFound)));
+    }
+
+    #[test]
+    fn lists_locations() {
+        let mut store = make_store();
+        store.add("office", Coordinates::new(52.2297, 21.0122));
+        store.add("eiffel", Coordinates::new(48.8584, 2.2944));
+
+        let sut = LocationManager::new(MockGeocodingProvider::new());
+        let result = sut.run(make_parameters(store, LocationAction::List));
+        let expected = "eiffel: 48.8584°, 2.2944°\noffice: 52.2297°, 21.0122°\n";
+        assert_eq!(result, Ok(expected.into()));
+    }
+
+    #[test]
+    fn removes_location() {
+        let path = make_store_path();
+        let mut store = LocationStore::load(&path).unwrap();
+        store.add("office", Coordinates::new(52.2297, 21.0122));
+        store.save().unwrap();
+
+        let sut = LocationManager::new(MockGeocodingProvider::new());
+        let action = LocationAction::Remove {
+            name: "office".into(),
+        };
+        let result = sut.run(make_parameters(store, action));
+        assert_eq!(result, Ok("Removed location 'office'\n".into()));
+        let store = LocationStore::load(&path).unwrap();
+        assert_eq!(store.iter().count(), 0);
+    }
+
+    #[test]
+    fn fails_to_remove_unknown_location() {
+        let sut = LocationManager::new(MockGeocodingProvider::new());
+        let action = LocationAction::Remove {
+            name: "office".into(),
+        };
+        let result = sut.run(make_parameters(make_store(), action));
+        assert_eq!(
+            result,
+            Err(Error::Store(locations::Error::UnknownLocation(
+                "office".into()
+            )))
+        );
+    }
+}
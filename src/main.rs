@@ -1,22 +1,213 @@
-use what_weather::external::{ConcreteGeolocationProvider, ConcreteWeatherProvider};
-use what_weather::format;
-use what_weather::input::cli;
-use what_weather::output::{ConsoleView, View};
-use what_weather::weather_reporter::WeatherReporter;
-
-fn main() {
-    let parameters = cli::parse();
+use std::io::IsTerminal;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+use what_weather::exit_status::ExitStatus;
+use what_weather::external::{
+    self, ConcreteGeocodingProvider, ConcreteWeatherProvider, SystemClock,
+};
+use what_weather::format::{self, AlertFormatter, Formatter, ReportContent, VerificationFormatter};
+use what_weather::input::cli::{self, Action, HistoryParameters};
+use what_weather::location_manager::{LocationManager, LocationParameters};
+use what_weather::output::{self, Diagnostics};
+use what_weather::port::clock::Clock;
+use what_weather::store::forecasts::ForecastStore;
+use what_weather::store::history::HistoryStore;
+use what_weather::types::condition::{self, Condition};
+use what_weather::types::history;
+use what_weather::types::report::Report;
+use what_weather::watcher::Watcher;
+use what_weather::weather_reporter::{Parameters, WeatherReporter};
+
+fn main() -> ExitCode {
+    match cli::parse() {
+        Ok(Action::Report(parameters)) => report_weather(parameters),
+        Ok(Action::Alert(parameters, conditions)) => check_alerts(parameters, conditions),
+        Ok(Action::Verify(parameters)) => verify_forecasts(parameters),
+        Ok(Action::History(parameters)) => show_history(parameters),
+        Ok(Action::Watch(parameters, interval)) => watch_weather(parameters, interval),
+        Ok(Action::ManageLocations(parameters)) => manage_locations(parameters),
+        Err(cli::Error::InvalidArguments(error)) => {
+            let _ = error.print();
+            if error.use_stderr() {
+                ExitStatus::InvalidArguments.into()
+            } else {
+                ExitStatus::Success.into()
+            }
+        }
+        Err(error) => {
+            Diagnostics::default().error(&error);
+            ExitStatus::from(&error).into()
+        }
+    }
+}
+
+fn report_weather(parameters: Parameters) -> ExitCode {
+    let formatter =
+        format::make_formatter(parameters.output_format.clone(), parameters.content.clone());
+    match fetch_and_display(parameters, formatter.as_ref()) {
+        Ok(_) => ExitStatus::Success.into(),
+        Err(status) => status.into(),
+    }
+}
+
+fn check_alerts(parameters: Parameters, conditions: Vec<Condition>) -> ExitCode {
+    let formatter = AlertFormatter::new(conditions.clone());
+    match fetch_and_display(parameters, &formatter) {
+        Ok(reports)
+            if reports
+                .iter()
+                .any(|report| !condition::evaluate(&conditions, report).is_empty()) =>
+        {
+            ExitStatus::AlertTriggered.into()
+        }
+        Ok(_) => ExitStatus::Success.into(),
+        Err(status) => status.into(),
+    }
+}
+
+fn verify_forecasts(parameters: Parameters) -> ExitCode {
+    let diagnostics = Diagnostics::new(parameters.verbosity);
+    let store = match parameters
+        .forecast_store
+        .as_deref()
+        .map(ForecastStore::load)
+    {
+        Some(Ok(store)) => store,
+        Some(Err(error)) => {
+            diagnostics.error(&error);
+            return ExitStatus::from(&error).into();
+        }
+        None => {
+            diagnostics.error("Forecasts file location is unknown");
+            return ExitStatus::Config.into();
+        }
+    };
+    let formatter = VerificationFormatter::new(store.records().to_vec(), SystemClock);
+    match fetch_and_display(parameters, &formatter) {
+        Ok(_) => ExitStatus::Success.into(),
+        Err(status) => status.into(),
+    }
+}
+
+fn show_history(parameters: HistoryParameters) -> ExitCode {
+    let diagnostics = Diagnostics::new(parameters.verbosity);
+    let Some(path) = parameters.store.as_deref() else {
+        diagnostics.error("History file location is unknown");
+        return ExitStatus::Config.into();
+    };
+    let reports = match HistoryStore::load(path).and_then(|store| store.reports(&parameters.units))
+    {
+        Ok(reports) => history::select(&reports, &parameters.filter, SystemClock.now()),
+        Err(error) => {
+            diagnostics.error(&error);
+            return ExitStatus::from(&error).into();
+        }
+    };
+    if reports.is_empty() {
+        diagnostics.warning("No recorded weather matches the query");
+        return ExitStatus::Success.into();
+    }
+    let formatter = format::make_formatter(parameters.output_format, ReportContent::Weather);
+    let view = output::make_view(parameters.destination);
+    match view.display(&format::format_reports(formatter.as_ref(), &reports)) {
+        Ok(()) => ExitStatus::Success.into(),
+        Err(error) => {
+            diagnostics.error(error);
+            ExitStatus::Output.into()
+        }
+    }
+}
+
+fn watch_weather(parameters: Parameters, interval: Duration) -> ExitCode {
+    let diagnostics = Diagnostics::new(parameters.verbosity);
+    let formatter =
+        format::make_formatter(parameters.output_format.clone(), parameters.content.clone());
     let weather_reporter = WeatherReporter::new(
-        ConcreteGeolocationProvider,
+        external::make_geolocation_provider(&parameters.location_source),
         ConcreteWeatherProvider::default(),
+        external::make_reverse_geocoding_provider(&parameters.place_names),
     );
-    match weather_reporter.run(parameters) {
-        Ok(report) => {
-            let formatted_report = format::describe(&report);
-            ConsoleView.display(&formatted_report);
+    let view = output::make_view(parameters.destination.clone());
+    let fetch = || {
+        let reports = weather_reporter.run(parameters.clone())?;
+        if let Some(path) = &parameters.history_store {
+            record_history(path, &reports, &diagnostics);
         }
+        Ok(reports)
+    };
+    let watcher = Watcher::new(SystemClock, interval, std::io::stdout().is_terminal());
+    watcher
+        .run(fetch, formatter.as_ref(), view.as_ref(), &diagnostics)
+        .into()
+}
+
+fn fetch_and_display(
+    parameters: Parameters,
+    formatter: &dyn Formatter,
+) -> Result<Vec<Report>, ExitStatus> {
+    let diagnostics = Diagnostics::new(parameters.verbosity);
+    let forecast_store = parameters.forecast_store.clone();
+    let history_store = parameters.history_store.clone();
+    let weather_reporter = WeatherReporter::new(
+        external::make_geolocation_provider(&parameters.location_source),
+        ConcreteWeatherProvider::default(),
+        external::make_reverse_geocoding_provider(&parameters.place_names),
+    );
+    let view = output::make_view(parameters.destination.clone());
+    let reports = weather_reporter.run(parameters).map_err(|error| {
+        diagnostics.error(&error);
+        ExitStatus::from(&error)
+    })?;
+    if let Some(path) = forecast_store {
+        record_forecasts(&path, &reports, &diagnostics);
+    }
+    if let Some(path) = history_store {
+        record_history(&path, &reports, &diagnostics);
+    }
+    view.display(&format::format_reports(formatter, &reports))
+        .map_err(|error| {
+            diagnostics.error(error);
+            ExitStatus::Output
+        })?;
+    Ok(reports)
+}
+
+fn record_forecasts(path: &Path, reports: &[Report], diagnostics: &Diagnostics) {
+    let result = ForecastStore::load(path).and_then(|mut store| {
+        store.record_reports(reports, SystemClock.now());
+        store.save()
+    });
+    if let Err(error) = result {
+        diagnostics.warning(error);
+    }
+}
+
+fn record_history(path: &Path, reports: &[Report], diagnostics: &Diagnostics) {
+    let result = HistoryStore::load(path).and_then(|mut store| {
+        store.record_reports(reports, SystemClock.now());
+        store.save()
+    });
+    if let Err(error) = result {
+        diagnostics.warning(error);
+    }
+}
+
+fn manage_locations(parameters: LocationParameters) -> ExitCode {
+    let diagnostics = Diagnostics::new(parameters.verbosity);
+    let location_manager = LocationManager::new(ConcreteGeocodingProvider);
+    let view = output::make_view(parameters.destination.clone());
+    match location_manager.run(parameters) {
+        Ok(text) => match view.display(&text) {
+            Ok(()) => ExitStatus::Success.into(),
+            Err(error) => {
+                diagnostics.error(error);
+                ExitStatus::Output.into()
+            }
+        },
         Err(error) => {
-            eprintln!("Error: {error}");
+            diagnostics.error(&error);
+            ExitStatus::from(&error).into()
         }
     }
 }
@@ -1,8 +1,54 @@
+mod command;
 mod console;
+mod diagnostics;
+mod file;
 
+pub use command::CommandView;
 pub use console::ConsoleView;
+pub use diagnostics::{Diagnostics, Verbosity};
+pub use file::FileView;
+
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+    Console,
+    File { path: PathBuf, append: bool },
+    Command(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    WritingToConsole,
+    WritingToFile(PathBuf),
+    RunningCommand(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WritingToConsole => write!(f, "Failed to write report to console"),
+            Self::WritingToFile(path) => {
+                write!(f, "Failed to write report to file '{}'", path.display())
+            }
+            Self::RunningCommand(command) => {
+                write!(f, "Failed to pass report to command '{command}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 #[mockall::automock]
 pub trait View {
-    fn display(&self, data: &str);
+    fn display(&self, data: &str) -> Result<(), Error>;
+}
+
+pub fn make_view(destination: Destination) -> Box<dyn View> {
+    match destination {
+        Destination::Console => Box::new(ConsoleView),
+        Destination::File { path, append } => Box::new(FileView::new(path, append)),
+        Destination::Command(command) => Box::new(CommandView::new(command)),
+    }
 }
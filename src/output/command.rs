@@ -0,0 +1,72 @@
+use crate::output::{Error, View};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+pub struct CommandView {
+    command: String,
+}
+
+impl CommandView {
+    pub fn new(command: String) -> Self {
+        Self { command }
+    }
+
+    fn run(&self, data: &str) -> std::io::Result<bool> {
+        let mut child = make_shell_command(&self.command)
+            .stdin(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(data.as_bytes())?;
+        }
+        Ok(child.wait()?.success())
+    }
+}
+
+impl View for CommandView {
+    fn display(&self, data: &str) -> Result<(), Error> {
+        match self.run(data) {
+            Ok(true) => Ok(()),
+            _ => Err(Error::RunningCommand(self.command.clone())),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn make_shell_command(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(windows)]
+fn make_shell_command(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passes_report_to_command_input() {
+        let path = std::env::temp_dir().join(format!(
+            "what-weather-command-view-{}.txt",
+            std::process::id()
+        ));
+        let view = CommandView::new(format!("cat > '{}'", path.display()));
+        assert_eq!(view.display("report\n"), Ok(()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "report\n");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn raises_error_when_command_fails() {
+        let view = CommandView::new("cat > /dev/null; exit 3".into());
+        assert_eq!(
+            view.display("report\n"),
+            Err(Error::RunningCommand("cat > /dev/null; exit 3".into()))
+        );
+    }
+}
@@ -1,9 +1,14 @@
-use crate::output::View;
+use crate::output::{Error, View};
+use std::io::Write;
 
 pub struct ConsoleView;
 
 impl View for ConsoleView {
-    fn display(&self, data: &str) {
-        print!("{data}")
+    fn display(&self, data: &str) -> Result<(), Error> {
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(data.as_bytes())
+            .and_then(|_| stdout.flush())
+            .map_err(|_| Error::WritingToConsole)
     }
 }
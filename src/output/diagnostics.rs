@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Diagnostics {
+    verbosity: Verbosity,
+}
+
+impl Diagnostics {
+    pub fn new(verbosity: Verbosity) -> Self {
+        Self { verbosity }
+    }
+
+    pub fn error(&self, message: impl Display) {
+        if self.verbosity >= Verbosity::Normal {
+            eprintln!("Error: {message}");
+        }
+    }
+
+    pub fn warning(&self, message: impl Display) {
+        if self.verbosity >= Verbosity::Normal {
+            eprintln!("Warning: {message}");
+        }
+    }
+
+    pub fn info(&self, message: impl Display) {
+        if self.verbosity >= Verbosity::Verbose {
+            eprintln!("{message}");
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::output::{Error, View};
+use crate::store::file::write_atomically;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+pub struct FileView {
+    path: PathBuf,
+    append: bool,
+}
+
+impl FileView {
+    pub fn new(path: PathBuf, append: bool) -> Self {
+        Self { path, append }
+    }
+
+    fn append(&self, data: &str) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(data.as_bytes())
+    }
+}
+
+impl View for FileView {
+    fn display(&self, data: &str) -> Result<(), Error> {
+        let result = if self.append {
+            self.append(data)
+        } else {
+            write_atomically(&self.path, data)
+        };
+        result.map_err(|_| Error::WritingToFile(self.path.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_test_path;
+    use std::fs;
+
+    #[test]
+    fn replaces_file_content() {
+        let path = make_test_path("report.txt");
+        fs::write(&path, "old report\n").unwrap();
+        let view = FileView::new(path.clone(), false);
+        assert_eq!(view.display("new report\n"), Ok(()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new report\n");
+        let entries = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn appends_to_file() {
+        let path = make_test_path("report.txt");
+        let view = FileView::new(path.clone(), true);
+        assert_eq!(view.display("first report\n"), Ok(()));
+        assert_eq!(view.display("second report\n"), Ok(()));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "first report\nsecond report\n"
+        );
+    }
+
+    #[test]
+    fn raises_error_when_directory_does_not_exist() {
+        let path = PathBuf::from("/nonexistent/what-weather/report.txt");
+        let view = FileView::new(path.clone(), false);
+        assert_eq!(view.display("report\n"), Err(Error::WritingToFile(path)));
+    }
+}
@@ -1,8 +1,13 @@
+pub mod clock;
+pub mod geocoding;
 pub mod geolocation;
 pub mod weather;
 
 #[cfg(test)]
 pub mod mocks {
+    pub use super::clock::MockClock;
+    pub use super::geocoding::MockGeocodingProvider;
+    pub use super::geocoding::MockReverseGeocodingProvider;
     pub use super::geolocation::MockGeolocationProvider;
     pub use super::weather::MockWeatherProvider;
 }
@@ -0,0 +1,8 @@
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+#[mockall::automock]
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep(&self, duration: Duration);
+}
@@ -0,0 +1,19 @@
+use crate::types::error::FetchError;
+use crate::types::location::PlaceName;
+use crate::types::units::Coordinates;
+
+#[mockall::automock]
+pub trait GeocodingProvider {
+    fn search(&self, address: &str) -> Result<Coordinates, FetchError>;
+}
+
+#[mockall::automock]
+pub trait ReverseGeocodingProvider {
+    fn lookup(&self, coordinates: &Coordinates) -> Result<PlaceName, FetchError>;
+}
+
+impl<T: ReverseGeocodingProvider + ?Sized> ReverseGeocodingProvider for Box<T> {
+    fn lookup(&self, coordinates: &Coordinates) -> Result<PlaceName, FetchError> {
+        (**self).lookup(coordinates)
+    }
+}
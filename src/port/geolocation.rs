@@ -5,3 +5,9 @@ use crate::types::units::Coordinates;
 pub trait GeolocationProvider {
     fn fetch(&self) -> Result<Coordinates, FetchError>;
 }
+
+impl<T: GeolocationProvider + ?Sized> GeolocationProvider for Box<T> {
+    fn fetch(&self) -> Result<Coordinates, FetchError> {
+        (**self).fetch()
+    }
+}
@@ -1,9 +1,10 @@
 use crate::types::attributes::WeatherAttributeSet;
 use crate::types::error::FetchError;
 use crate::types::report::*;
+use crate::types::timezone::Timezone;
 use crate::types::units::*;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RequestKind {
     PastFull(DayCount),
     PastPartial(DayCount, WeatherAttributeSet),
@@ -13,13 +14,13 @@ pub enum RequestKind {
     ForecastPartial(DayCount, WeatherAttributeSet),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TemperatureUnit {
     Celsius,
     Fahrenheit,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SpeedUnit {
     MetersPerSecond,
     KilometersPerHour,
@@ -27,7 +28,7 @@ pub enum SpeedUnit {
     Knots,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Units {
     pub temperature: TemperatureUnit,
     pub speed: SpeedUnit,
@@ -36,6 +37,19 @@ pub struct Units {
 #[derive(Debug, PartialEq)]
 pub struct ReportRequest {
     pub coordinates: Coordinates,
+    pub elevation: Option<Meters>,
+    pub timezone: Timezone,
+    pub include_normals: bool,
+    pub kind: RequestKind,
+    pub units: Units,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BatchReportRequest {
+    pub coordinates: Vec<Coordinates>,
+    pub elevation: Option<Meters>,
+    pub timezone: Timezone,
+    pub include_normals: bool,
     pub kind: RequestKind,
     pub units: Units,
 }
@@ -43,4 +57,5 @@ pub struct ReportRequest {
 #[mockall::automock]
 pub trait WeatherProvider {
     fn fetch(&self, request: &ReportRequest) -> Result<Report, FetchError>;
+    fn fetch_batch(&self, request: &BatchReportRequest) -> Result<Vec<Report>, FetchError>;
 }
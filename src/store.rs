@@ -0,0 +1,5 @@
+pub mod file;
+pub mod forecasts;
+pub mod history;
+pub mod locations;
+pub mod places;
@@ -0,0 +1,106 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Reading(PathBuf),
+    Parsing(PathBuf, String),
+    Writing(PathBuf),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reading(path) => write!(f, "Failed to read file '{}'", path.display()),
+            Self::Parsing(path, message) => {
+                write!(f, "Failed to parse file '{}': {message}", path.display())
+            }
+            Self::Writing(path) => write!(f, "Failed to write file '{}'", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T, Error> {
+    if !path.exists() {
+        return Ok(T::default());
+    }
+    let content = fs::read_to_string(path).map_err(|_| Error::Reading(path.into()))?;
+    toml::from_str(&content).map_err(|error| Error::Parsing(path.into(), error.message().into()))
+}
+
+pub fn save<T: Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+    let content = toml::to_string(value).map_err(|_| Error::Writing(path.into()))?;
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(|_| Error::Writing(path.into()))?;
+    }
+    write_atomically(path, &content).map_err(|_| Error::Writing(path.into()))
+}
+
+pub fn write_atomically(path: &Path, data: &str) -> std::io::Result<()> {
+    let temporary_path = make_temporary_path(path);
+    let result = fs::write(&temporary_path, data).and_then(|_| fs::rename(&temporary_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary_path);
+    }
+    result
+}
+
+fn make_temporary_path(path: &Path) -> PathBuf {
+    let mut file_name = std::ffi::OsString::from(".");
+    file_name.push(path.file_name().unwrap_or_default());
+    file_name.push(format!(".{}.tmp", std::process::id()));
+    path.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_test_path;
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
+    #[serde(deny_unknown_fields)]
+    struct TestFile {
+        #[serde(default)]
+        names: Vec<String>,
+    }
+
+    #[test]
+    fn loads_default_when_file_does_not_exist() {
+        let path = make_test_path("missing.toml");
+        assert_eq!(load::<TestFile>(&path), Ok(TestFile::default()));
+    }
+
+    #[test]
+    fn saves_and_loads_file_without_leaving_temporary_files() {
+        let path = make_test_path("nested").join("names.toml");
+        let file = TestFile {
+            names: vec!["office".into()],
+        };
+        save(&path, &file).unwrap();
+        assert_eq!(load::<TestFile>(&path), Ok(file));
+        let entries = fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(entries, 1);
+    }
+
+    #[test]
+    fn raises_error_with_path_on_invalid_content() {
+        let path = make_test_path("invalid.toml");
+        fs::write(&path, "unknown = 1").unwrap();
+        assert!(matches!(
+            load::<TestFile>(&path),
+            Err(Error::Parsing(error_path, _)) if error_path == path
+        ));
+    }
+
+    #[test]
+    fn makes_temporary_path_next_to_target() {
+        let path = Path::new("/tmp/reports/today.txt");
+        let expected = format!("/tmp/reports/.today.txt.{}.tmp", std::process::id());
+        assert_eq!(make_temporary_path(path), PathBuf::from(expected));
+    }
+}
@@ -0,0 +1,294 @@
+use crate::store::file::{self, Error};
+use crate::types::report::Report;
+use crate::types::units::{Coordinates, Date};
+use crate::types::verification::{DailyValues, ForecastRecord, KindCategory};
+use chrono::{DateTime, Days, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const FORECASTS_FILE_NAME: &str = "forecasts.toml";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const RETENTION_DAYS: u64 = 92;
+
+type RecordKey = (u32, u32, Date, Date);
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct ForecastsFile {
+    #[serde(default)]
+    forecasts: Vec<RecordEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct RecordEntry {
+    coordinates: String,
+    issued: String,
+    date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lowest_temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    highest_temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cloud_coverage: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    humidity: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind_speed: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pressure: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForecastStore {
+    path: PathBuf,
+    records: Vec<ForecastRecord>,
+}
+
+impl ForecastStore {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file: ForecastsFile = file::load(path)?;
+        let records = file
+            .forecasts
+            .into_iter()
+            .map(parse_entry)
+            .collect::<Result<_, _>>()
+            .map_err(|message| Error::Parsing(path.into(), message))?;
+        Ok(Self {
+            path: path.into(),
+            records,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let file = ForecastsFile {
+            forecasts: self.records.iter().map(make_entry).collect(),
+        };
+        file::save(&self.path, &file)
+    }
+
+    pub fn record(&mut self, records: Vec<ForecastRecord>) {
+        let mut positions: HashMap<RecordKey, usize> = self
+            .records
+            .iter()
+            .enumerate()
+            .map(|(position, record)| (make_key(record), position))
+            .collect();
+        for record in records {
+            match positions.entry(make_key(&record)) {
+                Entry::Occupied(entry) => {
+                    let existing = &mut self.records[*entry.get()];
+                    existing.values = record.values.or(std::mem::take(&mut existing.values));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(self.records.len());
+                    self.records.push(record);
+                }
+            }
+        }
+    }
+
+    pub fn prune(&mut self, today: Date) {
+        if let Some(oldest) = today.checked_sub_days(Days::new(RETENTION_DAYS)) {
+            self.records.retain(|record| record.date >= oldest);
+        }
+    }
+
+    pub fn record_reports(&mut self, reports: &[Report], now: DateTime<Utc>) {
+        for report in reports {
+            if let Report::ForecastFull(_) | Report::ForecastPartial(_) = report
+                && let Some(inner) = report.to_daily_partial()
+            {
+                let issued = report.location().today(now);
+                self.record(ForecastRecord::from_report(&inner, issued));
+            }
+        }
+        self.prune(now.date_naive());
+    }
+
+    pub fn records(&self) -> &[ForecastRecord] {
+        &self.records
+    }
+}
+
+fn make_key(record: &ForecastRecord) -> RecordKey {
+    (
+        record.coordinates.latitude.raw().to_bits(),
+        record.coordinates.longitude.raw().to_bits(),
+        record.issued,
+        record.date,
+    )
+}
+
+fn parse_entry(entry: RecordEntry) -> Result<ForecastRecord, String> {
+    let parse_date = |string: &str| {
+        Date::parse_from_str(string, DATE_FORMAT).map_err(|_| format!("Invalid date '{string}'"))
+    };
+    let kind = entry
+        .kind
+        .map(|name| {
+            KindCategory::ALL
+                .into_iter()
+                .find(|category| category.name() == name)
+                .ok_or_else(|| format!("Invalid weather kind '{name}'"))
+        })
+        .transpose()?;
+    Ok(ForecastRecord {
+        coordinates: Coordinates::from_str(&entry.coordinates)?,
+        issued: parse_date(&entry.issued)?,
+        date: parse_date(&entry.date)?,
+        values: DailyValues {
+            kind,
+            lowest_temperature_celsius: entry.lowest_temperature,
+            highest_temperature_celsius: entry.highest_temperature,
+            cloud_coverage_percent: entry.cloud_coverage,
+            humidity_percent: entry.humidity,
+            wind_speed_meters_per_second: entry.wind_speed,
+            pressure_hpa: entry.pressure,
+        },
+    })
+}
+
+fn make_entry(record: &ForecastRecord) -> RecordEntry {
+    let values = &record.values;
+    RecordEntry {
+        coordinates: format!(
+            "{},{}",
+            record.coordinates.latitude.raw(),
+            record.coordinates.longitude.raw()
+        ),
+        issued: record.issued.format(DATE_FORMAT).to_string(),
+        date: record.date.format(DATE_FORMAT).to_string(),
+        kind: values.kind.map(|kind| kind.name().into()),
+        lowest_temperature: values.lowest_temperature_celsius,
+        highest_temperature: values.highest_temperature_celsius,
+        cloud_coverage: values.cloud_coverage_percent,
+        humidity: values.humidity_percent,
+        wind_speed: values.wind_speed_meters_per_second,
+        pressure: values.pressure_hpa,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_test_path;
+    use crate::types::report::{DailyPartialData, DailyPartialReport};
+    use crate::types::units::TemperatureRange;
+
+    fn make_record(issued: u32, date: u32, temperature: f32) -> ForecastRecord {
+        ForecastRecord {
+            coordinates: Coordinates::new(52.25, 21.0),
+            issued: Date::from_ymd_opt(2025, 3, issued).unwrap(),
+            date: Date::from_ymd_opt(2025, 3, date).unwrap(),
+            values: DailyValues {
+                kind: Some(KindCategory::Snow),
+                lowest_temperature_celsius: Some(temperature),
+                ..DailyValues::default()
+            },
+        }
+    }
+
+    #[test]
+    fn saves_and_loads_records() {
+        let path = make_test_path(FORECASTS_FILE_NAME);
+        let mut store = ForecastStore::load(&path).unwrap();
+        assert!(store.records().is_empty());
+        store.record(vec![make_record(1, 2, -1.5)]);
+        store.save().unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "[[forecasts]]\n\
+             coordinates = \"52.25,21\"\n\
+             issued = \"2025-03-01\"\n\
+             date = \"2025-03-02\"\n\
+             kind = \"snow\"\n\
+             lowest_temperature = -1.5\n"
+        );
+        assert_eq!(ForecastStore::load(&path), Ok(store));
+    }
+
+    #[test]
+    fn replaces_record_of_same_forecast() {
+        let mut store = ForecastStore::load(&make_test_path(FORECASTS_FILE_NAME)).unwrap();
+        store.record(vec![make_record(1, 2, -1.5), make_record(1, 3, 0.5)]);
+        store.record(vec![make_record(1, 2, -2.5), make_record(2, 3, 1.0)]);
+        let temperatures: Vec<Option<f32>> = store
+            .records()
+            .iter()
+            .map(|record| record.values.lowest_temperature_celsius)
+            .collect();
+        assert_eq!(temperatures, [Some(-2.5), Some(0.5), Some(1.0)]);
+
+        let mut partial = make_record(1, 2, 0.0);
+        partial.values = DailyValues {
+            pressure_hpa: Some(1020.0),
+            ..DailyValues::default()
+        };
+        store.record(vec![partial]);
+        let values = &store.records()[0].values;
+        assert_eq!(values.lowest_temperature_celsius, Some(-2.5));
+        assert_eq!(values.pressure_hpa, Some(1020.0));
+    }
+
+    #[test]
+    fn records_only_forecast_reports() {
+        let make_report = || DailyPartialReport {
+            location: Coordinates::new(52.25, 21.0).into(),
+            data: vec![DailyPartialData {
+                date: Date::from_ymd_opt(2025, 3, 5).unwrap(),
+                kind: None,
+                temperature_range: Some(TemperatureRange::new_celsius(-3.0, 2.0)),
+                temperature_normal: None,
+                uv_index: None,
+                cloud_coverage_range: None,
+                humidity_range: None,
+                wind: None,
+                pressure_range: None,
+            }],
+        };
+        let reports = [
+            Report::PastPartial(make_report()),
+            Report::ForecastPartial(make_report()),
+        ];
+        let mut store = ForecastStore::load(&make_test_path(FORECASTS_FILE_NAME)).unwrap();
+        let now = "2025-03-03T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        store.record_reports(&reports, now);
+        let dates: Vec<(Date, i64)> = store
+            .records()
+            .iter()
+            .map(|record| (record.issued, record.lead_days()))
+            .collect();
+        assert_eq!(dates, [(Date::from_ymd_opt(2025, 3, 3).unwrap(), 2)]);
+    }
+
+    #[test]
+    fn prunes_records_of_days_too_old_to_verify() {
+        let mut store = ForecastStore::load(&make_test_path(FORECASTS_FILE_NAME)).unwrap();
+        store.record(vec![make_record(1, 2, -1.5), make_record(1, 3, 0.5)]);
+        store.prune(Date::from_ymd_opt(2025, 6, 3).unwrap());
+        let dates: Vec<Date> = store.records().iter().map(|record| record.date).collect();
+        assert_eq!(dates, [Date::from_ymd_opt(2025, 3, 3).unwrap()]);
+    }
+
+    #[test]
+    fn raises_error_on_invalid_record() {
+        let path = make_test_path(FORECASTS_FILE_NAME);
+        std::fs::write(
+            &path,
+            "[[forecasts]]\ncoordinates = \"52.25,21\"\nissued = \"yesterday\"\ndate = \"2025-03-02\"\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            ForecastStore::load(&path),
+            Err(Error::Parsing(..))
+        ));
+    }
+}
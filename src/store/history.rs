@@ -0,0 +1,697 @@
+use crate::port::weather::{SpeedUnit, TemperatureUnit, Units};
+use crate::store::file::{self, Error};
+use crate::types::location::Location;
+use crate::types::report::*;
+use crate::types::units::*;
+use crate::types::weather::*;
+use chrono::{Days, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const HISTORY_FILE_NAME: &str = "history.toml";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const RETENTION_DAYS: u64 = DayCount::MAX as u64;
+const MAX_CURRENT_ENTRIES: usize = 10_000;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct HistoryFile {
+    #[serde(default)]
+    current: Vec<CurrentEntry>,
+    #[serde(default)]
+    daily: Vec<DailyEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct CurrentEntry {
+    coordinates: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+    time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cloud_coverage: Option<i8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    humidity: Option<i8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind_speed: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind_direction: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pressure: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct DailyEntry {
+    coordinates: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    place: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    utc_offset: Option<i32>,
+    date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<(f32, f32)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cloud_coverage: Option<(i8, i8)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    humidity: Option<(i8, i8)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind_speed: Option<(f32, f32)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wind_direction: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pressure: Option<(f32, f32)>,
+}
+
+impl CurrentEntry {
+    fn or(self, other: Self) -> Self {
+        Self {
+            place: self.place.or(other.place),
+            country: self.country.or(other.country),
+            kind: self.kind.or(other.kind),
+            temperature: self.temperature.or(other.temperature),
+            cloud_coverage: self.cloud_coverage.or(other.cloud_coverage),
+            humidity: self.humidity.or(other.humidity),
+            wind_speed: self.wind_speed.or(other.wind_speed),
+            wind_direction: self.wind_direction.or(other.wind_direction),
+            pressure: self.pressure.or(other.pressure),
+            ..self
+        }
+    }
+}
+
+impl DailyEntry {
+    fn or(self, other: Self) -> Self {
+        Self {
+            place: self.place.or(other.place),
+            country: self.country.or(other.country),
+            utc_offset: self.utc_offset.or(other.utc_offset),
+            kind: self.kind.or(other.kind),
+            temperature: self.temperature.or(other.temperature),
+            cloud_coverage: self.cloud_coverage.or(other.cloud_coverage),
+            humidity: self.humidity.or(other.humidity),
+            wind_speed: self.wind_speed.or(other.wind_speed),
+            wind_direction: self.wind_direction.or(other.wind_direction),
+            pressure: self.pressure.or(other.pressure),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryStore {
+    path: PathBuf,
+    current: Vec<CurrentEntry>,
+    daily: Vec<DailyEntry>,
+}
+
+impl HistoryStore {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file: HistoryFile = file::load(path)?;
+        Ok(Self {
+            path: path.into(),
+            current: file.current,
+            daily: file.daily,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let file = HistoryFile {
+            current: self.current.clone(),
+            daily: self.daily.clone(),
+        };
+        file::save(&self.path, &file)
+    }
+
+    pub fn record_reports(&mut self, reports: &[Report], now: chrono::DateTime<Utc>) {
+        for report in reports {
+            match report {
+                Report::CurrentFull(inner) => self.record_current(&inner.clone().into()),
+                Report::CurrentPartial(inner) => self.record_current(inner),
+                Report::PastFull(_) | Report::PastPartial(_) => {
+                    if let Some(inner) = report.to_daily_partial() {
+                        for data in &inner.data {
+                            self.record_day(&inner.location, data);
+                        }
+                    }
+                }
+                Report::ForecastFull(_) | Report::ForecastPartial(_) => {}
+            }
+        }
+        self.prune(now);
+    }
+
+    pub fn prune(&mut self, now: chrono::DateTime<Utc>) {
+        let Some(oldest) = now.date_naive().checked_sub_days(Days::new(RETENTION_DAYS)) else {
+            return;
+        };
+        self.current.retain(|entry| {
+            DateTime::parse_from_rfc3339(&entry.time)
+                .map_or(true, |time| time.date_naive() >= oldest)
+        });
+        self.daily.retain(|entry| {
+            Date::parse_from_str(&entry.date, DATE_FORMAT).map_or(true, |date| date >= oldest)
+        });
+        let excess = self.current.len().saturating_sub(MAX_CURRENT_ENTRIES);
+        self.current.drain(..excess);
+    }
+
+    pub fn reports(&self, units: &Units) -> Result<Vec<Report>, Error> {
+        let parsing_error = |message| Error::Parsing(self.path.clone(), message);
+        let mut reports: Vec<Report> = Vec::new();
+        for entry in &self.daily {
+            let (location, data) = parse_daily_entry(entry, units).map_err(parsing_error)?;
+            let existing = reports.iter_mut().find_map(|report| match report {
+                Report::PastPartial(inner)
+                    if inner.location.coordinates == location.coordinates =>
+                {
+                    Some(inner)
+                }
+                _ => None,
+            });
+            match existing {
+                Some(inner) => inner.data.push(data),
+                None => reports.push(Report::PastPartial(DailyPartialReport {
+                    location,
+                    data: vec![data],
+                })),
+            }
+        }
+        for entry in &self.current {
+            let report = parse_current_entry(entry, units).map_err(parsing_error)?;
+            reports.push(Report::CurrentPartial(report));
+        }
+        Ok(reports)
+    }
+
+    fn record_current(&mut self, report: &CurrentPartialReport) {
+        let Some(entry) = make_current_entry(report) else {
+            return;
+        };
+        match self.current.iter_mut().find(|existing| {
+            existing.coordinates == entry.coordinates && existing.time == entry.time
+        }) {
+            Some(existing) => *existing = entry.or(existing.clone()),
+            None => self.current.push(entry),
+        }
+    }
+
+    fn record_day(&mut self, location: &Location, data: &DailyPartialData) {
+        let entry = make_daily_entry(location, data);
+        match self.daily.iter_mut().find(|existing| {
+            existing.coordinates == entry.coordinates && existing.date == entry.date
+        }) {
+            Some(existing) => *existing = entry.or(existing.clone()),
+            None => self.daily.push(entry),
+        }
+    }
+}
+
+fn make_current_entry(report: &CurrentPartialReport) -> Option<CurrentEntry> {
+    Some(CurrentEntry {
+        coordinates: format_coordinates(&report.location.coordinates),
+        place: report.location.name.clone(),
+        country: report.location.country.clone(),
+        time: report.time?.to_rfc3339(),
+        kind: report.kind.as_ref().map(format_kind),
+        temperature: report
+            .temperature
+            .map(|temperature| temperature.to_celsius().value()),
+        cloud_coverage: report.cloud_coverage.map(|coverage| coverage.value),
+        humidity: report.humidity.map(|humidity| humidity.value),
+        wind_speed: report
+            .wind
+            .as_ref()
+            .map(|wind| wind.speed.to_meters_per_second().value()),
+        wind_direction: report.wind.as_ref().map(|wind| wind.direction.angle.raw()),
+        pressure: report.pressure.map(|pressure| pressure.value()),
+    })
+}
+
+fn make_daily_entry(location: &Location, data: &DailyPartialData) -> DailyEntry {
+    DailyEntry {
+        coordinates: format_coordinates(&location.coordinates),
+        place: location.name.clone(),
+        country: location.country.clone(),
+        utc_offset: location.utc_offset.map(|offset| offset.local_minus_utc()),
+        date: data.date.format(DATE_FORMAT).to_string(),
+        kind: data.kind.as_ref().map(format_kind),
+        temperature: data.temperature_range.as_ref().map(|range| {
+            (
+                range.min().to_celsius().value(),
+                range.max().to_celsius().value(),
+            )
+        }),
+        cloud_coverage: data
+            .cloud_coverage_range
+            .as_ref()
+            .map(|range| (range.min.value, range.max.value)),
+        humidity: data
+            .humidity_range
+            .as_ref()
+            .map(|range| (range.min.value, range.max.value)),
+        wind_speed: data.wind.as_ref().map(|wind| {
+            (
+                wind.speed_range.min().to_meters_per_second().value(),
+                wind.speed_range.max().to_meters_per_second().value(),
+            )
+        }),
+        wind_direction: data
+            .wind
+            .as_ref()
+            .map(|wind| wind.dominant_direction.angle.raw()),
+        pressure: data
+            .pressure_range
+            .as_ref()
+            .map(|range| (range.min().value(), range.max().value())),
+    }
+}
+
+fn parse_current_entry(
+    entry: &CurrentEntry,
+    units: &Units,
+) -> Result<CurrentPartialReport, String> {
+    let time = DateTime::parse_from_rfc3339(&entry.time)
+        .map_err(|_| format!("Invalid time '{}'", entry.time))?;
+    let mut location = parse_location(&entry.coordinates, &entry.place, &entry.country)?;
+    location.utc_offset = Some(*time.offset());
+    let wind = match (entry.wind_speed, entry.wind_direction) {
+        (Some(speed), Some(direction)) => Some(Wind {
+            speed: make_speed(speed, &units.speed),
+            direction: Azimuth::from(direction),
+        }),
+        _ => None,
+    };
+    Ok(CurrentPartialReport {
+        location,
+        time: Some(time),
+        kind: entry.kind.as_deref().map(parse_kind).transpose()?,
+        temperature: entry
+            .temperature
+            .map(|celsius| make_temperature(celsius, &units.temperature)),
+        cloud_coverage: entry.cloud_coverage.map(Percentage::from),
+        humidity: entry.humidity.map(Percentage::from),
+        wind,
+        pressure: entry.pressure.map(Pressure::new_hpa),
+        uv_index: None,
+    })
+}
+
+fn parse_daily_entry(
+    entry: &DailyEntry,
+    units: &Units,
+) -> Result<(Location, DailyPartialData), String> {
+    let date = Date::parse_from_str(&entry.date, DATE_FORMAT)
+        .map_err(|_| format!("Invalid date '{}'", entry.date))?;
+    let mut location = parse_location(&entry.coordinates, &entry.place, &entry.country)?;
+    location.utc_offset = entry.utc_offset.and_then(FixedOffset::east_opt);
+    let check_range = |min: f32, max: f32| {
+        (min <= max)
+            .then_some(())
+            .ok_or_else(|| format!("Invalid range on {}", entry.date))
+    };
+    let temperature_range = match entry.temperature {
+        Some((min, max)) => {
+            check_range(min, max)?;
+            Some(make_temperature_range(min, max, &units.temperature))
+        }
+        None => None,
+    };
+    let wind = match (entry.wind_speed, entry.wind_direction) {
+        (Some((min, max)), Some(direction)) => {
+            check_range(min, max)?;
+            Some(WindScope {
+                speed_range: make_speed_range(min, max, &units.speed),
+                dominant_direction: Azimuth::from(direction),
+            })
+        }
+        _ => None,
+    };
+    let pressure_range = match entry.pressure {
+        Some((min, max)) => {
+            check_range(min, max)?;
+            Some(PressureRange::new_hpa(min, max))
+        }
+        None => None,
+    };
+    let data = DailyPartialData {
+        date,
+        kind: entry.kind.as_deref().map(parse_kind).transpose()?,
+        temperature_range,
+        temperature_normal: None,
+        uv_index: None,
+        cloud_coverage_range: entry
+            .cloud_coverage
+            .map(|(min, max)| PercentageRange::new(min, max)),
+        humidity_range: entry
+            .humidity
+            .map(|(min, max)| PercentageRange::new(min, max)),
+        wind,
+        pressure_range,
+    };
+    Ok((location, data))
+}
+
+fn parse_location(
+    coordinates: &str,
+    place: &Option<String>,
+    country: &Option<String>,
+) -> Result<Location, String> {
+    let mut location = Location::new(Coordinates::from_str(coordinates)?);
+    location.name = place.clone();
+    location.country = country.clone();
+    Ok(location)
+}
+
+fn format_coordinates(coordinates: &Coordinates) -> String {
+    format!(
+        "{},{}",
+        coordinates.latitude.raw(),
+        coordinates.longitude.raw()
+    )
+}
+
+fn make_temperature(celsius: f32, unit: &TemperatureUnit) -> Temperature {
+    let temperature = Temperature::new_celsius(celsius);
+    match unit {
+        TemperatureUnit::Celsius => temperature,
+        TemperatureUnit::Fahrenheit => temperature.to_fahrenheit(),
+    }
+}
+
+fn make_temperature_range(min: f32, max: f32, unit: &TemperatureUnit) -> TemperatureRange {
+    let min = make_temperature(min, unit);
+    let max = make_temperature(max, unit);
+    match unit {
+        TemperatureUnit::Celsius => TemperatureRange::new_celsius(min.value(), max.value()),
+        TemperatureUnit::Fahrenheit => TemperatureRange::new_fahrenheit(min.value(), max.value()),
+    }
+}
+
+fn make_speed(meters_per_second: f32, unit: &SpeedUnit) -> Speed {
+    let speed = Speed::new_meters_per_second(meters_per_second);
+    match unit {
+        SpeedUnit::MetersPerSecond => speed,
+        SpeedUnit::KilometersPerHour => speed.to_kilometers_per_hour(),
+        SpeedUnit::MilesPerHour => speed.to_miles_per_hour(),
+        SpeedUnit::Knots => speed.to_knots(),
+    }
+}
+
+fn make_speed_range(min: f32, max: f32, unit: &SpeedUnit) -> SpeedRange {
+    let min = make_speed(min, unit).value();
+    let max = make_speed(max, unit).value();
+    match unit {
+        SpeedUnit::MetersPerSecond => SpeedRange::new_meters_per_second(min, max),
+        SpeedUnit::KilometersPerHour => SpeedRange::new_kilometers_per_hour(min, max),
+        SpeedUnit::MilesPerHour => SpeedRange::new_miles_per_hour(min, max),
+        SpeedUnit::Knots => SpeedRange::new_knots(min, max),
+    }
+}
+
+fn format_kind(kind: &Kind) -> String {
+    match kind {
+        Kind::Clouds(clouds) => match clouds {
+            Clouds::Clear => "clear",
+            Clouds::Light => "light_clouds",
+            Clouds::Moderate => "moderate_clouds",
+            Clouds::Dense => "dense_clouds",
+        }
+        .into(),
+        Kind::Fog(Fog::Normal) => "fog".into(),
+        Kind::Fog(Fog::Rime) => "rime_fog".into(),
+        Kind::Precipitation(precipitation) => {
+            let intensity = match precipitation.intensity {
+                PrecipitationIntensity::Light => "light",
+                PrecipitationIntensity::Moderate => "moderate",
+                PrecipitationIntensity::Heavy => "heavy",
+                PrecipitationIntensity::Shower => "shower",
+            };
+            let heat = match precipitation.heat {
+                PrecipitationHeat::Freezing => "_freezing",
+                PrecipitationHeat::Normal => "",
+            };
+            let kind = match precipitation.kind {
+                PrecipitationKind::Rain => "rain",
+                PrecipitationKind::Snow => "snow",
+            };
+            format!("{intensity}{heat}_{kind}")
+        }
+        Kind::Thunderstorm => "thunderstorm".into(),
+    }
+}
+
+fn parse_kind(name: &str) -> Result<Kind, String> {
+    let invalid = || format!("Invalid weather kind '{name}'");
+    let kind = match name {
+        "clear" => Kind::Clouds(Clouds::Clear),
+        "light_clouds" => Kind::Clouds(Clouds::Light),
+        "moderate_clouds" => Kind::Clouds(Clouds::Moderate),
+        "dense_clouds" => Kind::Clouds(Clouds::Dense),
+        "fog" => Kind::Fog(Fog::Normal),
+        "rime_fog" => Kind::Fog(Fog::Rime),
+        "thunderstorm" => Kind::Thunderstorm,
+        _ => {
+            let (intensity, rest) = name.split_once('_').ok_or_else(invalid)?;
+            let (heat, kind) = match rest.split_once('_') {
+                Some(("freezing", kind)) => (PrecipitationHeat::Freezing, kind),
+                Some(_) => return Err(invalid()),
+                None => (PrecipitationHeat::Normal, rest),
+            };
+            Kind::Precipitation(Precipitation {
+                intensity: match intensity {
+                    "light" => PrecipitationIntensity::Light,
+                    "moderate" => PrecipitationIntensity::Moderate,
+                    "heavy" => PrecipitationIntensity::Heavy,
+                    "shower" => PrecipitationIntensity::Shower,
+                    _ => return Err(invalid()),
+                },
+                heat,
+                kind: match kind {
+                    "rain" => PrecipitationKind::Rain,
+                    "snow" => PrecipitationKind::Snow,
+                    _ => return Err(invalid()),
+                },
+            })
+        }
+    };
+    Ok(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_test_path;
+    use crate::types::location::PlaceName;
+
+    fn make_now() -> chrono::DateTime<Utc> {
+        "2025-01-20T12:00:00Z".parse().unwrap()
+    }
+
+    fn make_units(temperature: TemperatureUnit, speed: SpeedUnit) -> Units {
+        Units { temperature, speed }
+    }
+
+    fn make_current() -> CurrentPartialReport {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(52.25, 21.0));
+        report.location.set_place_name(PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        });
+        report.time = Some(DateTime::parse_from_rfc3339("2025-01-13T07:30:00+01:00").unwrap());
+        report.temperature = Some(Temperature::new_celsius(-4.5));
+        report.kind = Some(Kind::Precipitation(Precipitation {
+            kind: PrecipitationKind::Snow,
+            intensity: PrecipitationIntensity::Shower,
+            heat: PrecipitationHeat::Normal,
+        }));
+        report.wind = Some(Wind {
+            speed: Speed::new_kilometers_per_hour(18.0),
+            direction: Azimuth::from(270.0),
+        });
+        report
+    }
+
+    fn make_past(day: u32) -> DailyPartialReport {
+        let mut location = Location::new(Coordinates::new(52.25, 21.0));
+        location.utc_offset = FixedOffset::east_opt(3600);
+        DailyPartialReport {
+            location,
+            data: vec![DailyPartialData {
+                date: Date::from_ymd_opt(2025, 1, day).unwrap(),
+                kind: Some(Kind::Precipitation(Precipitation {
+                    kind: PrecipitationKind::Rain,
+                    intensity: PrecipitationIntensity::Light,
+                    heat: PrecipitationHeat::Freezing,
+                })),
+                temperature_range: Some(TemperatureRange::new_celsius(-2.0, 1.0)),
+                temperature_normal: None,
+                uv_index: None,
+                cloud_coverage_range: Some(PercentageRange::new(60, 100)),
+                humidity_range: None,
+                wind: None,
+                pressure_range: Some(PressureRange::new_hpa(1001.5, 1008.0)),
+            }],
+        }
+    }
+
+    #[test]
+    fn saves_and_loads_observed_reports() {
+        let path = make_test_path(HISTORY_FILE_NAME);
+        let mut store = HistoryStore::load(&path).unwrap();
+        store.record_reports(
+            &[
+                Report::CurrentPartial(make_current()),
+                Report::PastPartial(make_past(12)),
+                Report::ForecastPartial(make_past(20)),
+            ],
+            make_now(),
+        );
+        store.save().unwrap();
+
+        let store = HistoryStore::load(&path).unwrap();
+        let reports = store
+            .reports(&make_units(
+                TemperatureUnit::Celsius,
+                SpeedUnit::MetersPerSecond,
+            ))
+            .unwrap();
+        let mut expected_current = make_current();
+        expected_current.location.utc_offset = FixedOffset::east_opt(3600);
+        expected_current.wind = Some(Wind {
+            speed: Speed::new_meters_per_second(5.0),
+            direction: Azimuth::from(270.0),
+        });
+        assert_eq!(
+            reports,
+            [
+                Report::PastPartial(make_past(12)),
+                Report::CurrentPartial(expected_current),
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_values_to_requested_units() {
+        let mut store = HistoryStore::load(&make_test_path(HISTORY_FILE_NAME)).unwrap();
+        store.record_reports(&[Report::CurrentPartial(make_current())], make_now());
+        let reports = store
+            .reports(&make_units(
+                TemperatureUnit::Fahrenheit,
+                SpeedUnit::KilometersPerHour,
+            ))
+            .unwrap();
+        let Report::CurrentPartial(report) = &reports[0] else {
+            panic!("Expected current report");
+        };
+        assert_eq!(report.temperature.unwrap().unit_symbol(), "°F");
+        assert!((report.temperature.unwrap().value() - 23.9).abs() < 0.01);
+        assert!((report.wind.as_ref().unwrap().speed.value() - 18.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn replaces_observation_of_same_day() {
+        let mut store = HistoryStore::load(&make_test_path(HISTORY_FILE_NAME)).unwrap();
+        let mut past = make_past(12);
+        store.record_reports(&[Report::PastPartial(past.clone())], make_now());
+        past.data[0].kind = Some(Kind::Thunderstorm);
+        store.record_reports(
+            &[
+                Report::PastPartial(past.clone()),
+                Report::PastPartial(make_past(13)),
+            ],
+            make_now(),
+        );
+        let reports = store
+            .reports(&make_units(
+                TemperatureUnit::Celsius,
+                SpeedUnit::MetersPerSecond,
+            ))
+            .unwrap();
+        let Report::PastPartial(report) = &reports[0] else {
+            panic!("Expected past report");
+        };
+        assert_eq!(report.data.len(), 2);
+        assert_eq!(report.data[0].kind, Some(Kind::Thunderstorm));
+    }
+
+    #[test]
+    fn keeps_stored_values_missing_from_partial_observation() {
+        let mut store = HistoryStore::load(&make_test_path(HISTORY_FILE_NAME)).unwrap();
+        store.record_reports(&[Report::PastPartial(make_past(12))], make_now());
+        let mut partial = make_past(12);
+        partial.data[0] = DailyPartialData {
+            date: partial.data[0].date,
+            kind: None,
+            temperature_range: Some(TemperatureRange::new_celsius(-3.0, 0.5)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        };
+        store.record_reports(&[Report::PastPartial(partial)], make_now());
+        let reports = store
+            .reports(&make_units(
+                TemperatureUnit::Celsius,
+                SpeedUnit::MetersPerSecond,
+            ))
+            .unwrap();
+        let mut expected = make_past(12);
+        expected.data[0].temperature_range = Some(TemperatureRange::new_celsius(-3.0, 0.5));
+        assert_eq!(reports, [Report::PastPartial(expected)]);
+    }
+
+    #[test]
+    fn prunes_observations_too_old_to_query() {
+        let mut store = HistoryStore::load(&make_test_path(HISTORY_FILE_NAME)).unwrap();
+        store.record_reports(
+            &[
+                Report::CurrentPartial(make_current()),
+                Report::PastPartial(make_past(12)),
+            ],
+            make_now(),
+        );
+        store.prune("2025-09-25T12:00:00Z".parse().unwrap());
+        assert_eq!(store.current.len(), 1);
+        assert_eq!(store.daily.len(), 0);
+    }
+
+    #[test]
+    fn formats_and_parses_weather_kinds() {
+        let kinds = [
+            Kind::Clouds(Clouds::Light),
+            Kind::Fog(Fog::Rime),
+            Kind::Thunderstorm,
+            Kind::Precipitation(Precipitation {
+                kind: PrecipitationKind::Snow,
+                intensity: PrecipitationIntensity::Heavy,
+                heat: PrecipitationHeat::Normal,
+            }),
+            Kind::Precipitation(Precipitation {
+                kind: PrecipitationKind::Rain,
+                intensity: PrecipitationIntensity::Moderate,
+                heat: PrecipitationHeat::Freezing,
+            }),
+        ];
+        for kind in kinds {
+            assert_eq!(parse_kind(&format_kind(&kind)), Ok(kind));
+        }
+        assert_eq!(format_kind(&kinds[4]), "moderate_freezing_rain");
+        assert!(parse_kind("heavy_hail").is_err());
+        assert!(parse_kind("light_warm_rain").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use crate::store::file;
+use crate::types::units::Coordinates;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub const LOCATIONS_FILE_NAME: &str = "locations.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    File(file::Error),
+    UnknownLocation(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(error) => error.fmt(f),
+            Self::UnknownLocation(name) => write!(f, "Unknown location '{name}'"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct LocationsFile {
+    #[serde(default)]
+    locations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationStore {
+    path: PathBuf,
+    locations: BTreeMap<String, Coordinates>,
+}
+
+impl LocationStore {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file = file::load(path).map_err(Error::File)?;
+        let locations = parse(file)
+            .map_err(|message| Error::File(file::Error::Parsing(path.into(), message)))?;
+        Ok(Self {
+            path: path.into(),
+            locations,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let file = LocationsFile {
+            locations: self
+                .locations
+                .iter()
+                .map(|(name, coordinates)| (name.clone(), format_coordinates(coordinates)))
+                .collect(),
+        };
+        file::save(&self.path, &file).map_err(Error::File)
+    }
+
+    pub fn get(&self, name: &str) -> Result<Coordinates, Error> {
+        self.locations
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::UnknownLocation(name.into()))
+    }
+
+    pub fn add(&mut self, name: &str, coordinates: Coordinates) {
+        self.locations.insert(name.into(), coordinates);
+    }
+
+    pub fn remove(&mut self, name: &str) -> Result<Coordinates, Error> {
+        self.locations
+            .remove(name)
+            .ok_or_else(|| Error::UnknownLocation(name.into()))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Coordinates)> {
+        self.locations.iter()
+    }
+}
+
+fn parse(file: LocationsFile) -> Result<BTreeMap<String, Coordinates>, String> {
+    file.locations
+        .into_iter()
+        .map(|(name, value)| {
+            Coordinates::from_str(&value)
+                .map(|coordinates| (name.clone(), coordinates))
+                .map_err(|message| format!("{message} for location '{name}'"))
+        })
+        .collect()
+}
+
+fn format_coordinates(coordinates: &Coordinates) -> String {
+    format!(
+        "{},{}",
+        coordinates.latitude.raw(),
+        coordinates.longitude.raw()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_test_path;
+
+    #[test]
+    fn loads_empty_store_when_file_does_not_exist() {
+        let store = LocationStore::load(&make_test_path(LOCATIONS_FILE_NAME)).unwrap();
+        assert_eq!(store.iter().count(), 0);
+    }
+
+    #[test]
+    fn saves_and_loads_locations() {
+        let path = make_test_path(LOCATIONS_FILE_NAME);
+        let mut store = LocationStore::load(&path).unwrap();
+        store.add("eiffel", Coordinates::new(48.8584, 2.2944));
+        store.add("office", Coordinates::new(52.2297, 21.0122));
+        store.save().unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "[locations]\neiffel = \"48.8584,2.2944\"\noffice = \"52.2297,21.0122\"\n"
+        );
+        assert_eq!(LocationStore::load(&path), Ok(store));
+    }
+
+    #[test]
+    fn resolves_location_by_name() {
+        let mut store = LocationStore::load(&make_test_path(LOCATIONS_FILE_NAME)).unwrap();
+        store.add("office", Coordinates::new(52.2297, 21.0122));
+        assert_eq!(store.get("office"), Ok(Coordinates::new(52.2297, 21.0122)));
+        assert_eq!(
+            store.get("home"),
+            Err(Error::UnknownLocation("home".into()))
+        );
+    }
+
+    #[test]
+    fn removes_location() {
+        let mut store = LocationStore::load(&make_test_path(LOCATIONS_FILE_NAME)).unwrap();
+        store.add("office", Coordinates::new(52.2297, 21.0122));
+        assert_eq!(
+            store.remove("office"),
+            Ok(Coordinates::new(52.2297, 21.0122))
+        );
+        assert_eq!(
+            store.remove("office"),
+            Err(Error::UnknownLocation("office".into()))
+        );
+    }
+
+    #[test]
+    fn raises_error_on_invalid_coordinates() {
+        let path = make_test_path(LOCATIONS_FILE_NAME);
+        std::fs::write(&path, "[locations]\noffice = \"foo\"").unwrap();
+        assert!(matches!(
+            LocationStore::load(&path),
+            Err(Error::File(file::Error::Parsing(..)))
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::store::file::{self, Error};
+use crate::types::location::PlaceName;
+use crate::types::units::Coordinates;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const PLACES_FILE_NAME: &str = "places.toml";
+const MAX_PLACES: usize = 1000;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct PlacesFile {
+    #[serde(default)]
+    places: Vec<PlaceEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct PlaceEntry {
+    coordinates: String,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaceStore {
+    path: PathBuf,
+    places: Vec<PlaceEntry>,
+}
+
+impl PlaceStore {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file: PlacesFile = file::load(path)?;
+        Ok(Self {
+            path: path.into(),
+            places: file.places,
+        })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let file = PlacesFile {
+            places: self.places.clone(),
+        };
+        file::save(&self.path, &file)
+    }
+
+    pub fn get(&self, coordinates: &Coordinates) -> Option<PlaceName> {
+        let key = make_key(coordinates);
+        self.places
+            .iter()
+            .find(|entry| entry.coordinates == key)
+            .map(|entry| PlaceName {
+                name: entry.name.clone(),
+                country: entry.country.clone(),
+            })
+    }
+
+    pub fn add(&mut self, coordinates: &Coordinates, place_name: PlaceName) {
+        let key = make_key(coordinates);
+        self.places.retain(|entry| entry.coordinates != key);
+        self.places.push(PlaceEntry {
+            coordinates: key,
+            name: place_name.name,
+            country: place_name.country,
+        });
+        let excess = self.places.len().saturating_sub(MAX_PLACES);
+        self.places.drain(..excess);
+    }
+}
+
+fn make_key(coordinates: &Coordinates) -> String {
+    format!(
+        "{:.4},{:.4}",
+        coordinates.latitude.raw(),
+        coordinates.longitude.raw()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::make_test_path;
+
+    fn make_place_name(name: &str) -> PlaceName {
+        PlaceName {
+            name: name.into(),
+            country: Some("Poland".into()),
+        }
+    }
+
+    #[test]
+    fn saves_and_loads_places() {
+        let path = make_test_path(PLACES_FILE_NAME);
+        let mut store = PlaceStore::load(&path).unwrap();
+        store.add(
+            &Coordinates::new(52.2297, 21.0122),
+            make_place_name("Warsaw"),
+        );
+        store.save().unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content,
+            "[[places]]\n\
+             coordinates = \"52.2297,21.0122\"\n\
+             name = \"Warsaw\"\n\
+             country = \"Poland\"\n"
+        );
+        let store = PlaceStore::load(&path).unwrap();
+        assert_eq!(
+            store.get(&Coordinates::new(52.22971, 21.01219)),
+            Some(make_place_name("Warsaw"))
+        );
+        assert_eq!(store.get(&Coordinates::new(50.0647, 19.945)), None);
+    }
+
+    #[test]
+    fn keeps_most_recent_places() {
+        let mut store = PlaceStore::load(&make_test_path(PLACES_FILE_NAME)).unwrap();
+        for index in 0..=MAX_PLACES {
+            let coordinates = Coordinates::new(index as f32 / 100.0, 21.0);
+            store.add(&coordinates, make_place_name("Somewhere"));
+        }
+        assert_eq!(store.places.len(), MAX_PLACES);
+        assert_eq!(store.get(&Coordinates::new(0.0, 21.0)), None);
+        assert!(store.get(&Coordinates::new(10.0, 21.0)).is_some());
+    }
+}
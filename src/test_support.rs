@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_DIRECTORY_ID: AtomicUsize = AtomicUsize::new(0);
+
+pub fn make_test_path(file_name: &str) -> PathBuf {
+    let id = NEXT_DIRECTORY_ID.fetch_add(1, Ordering::Relaxed);
+    let directory =
+        std::env::temp_dir().join(format!("what-weather-test-{}-{id}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+    directory.join(file_name)
+}
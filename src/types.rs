@@ -1,5 +1,16 @@
+pub mod activity;
 pub mod attributes;
+pub mod climate;
+pub mod condition;
+pub mod degree_days;
 pub mod error;
+pub mod gear;
+pub mod history;
+pub mod location;
 pub mod report;
+pub mod statistics;
+pub mod timezone;
 pub mod units;
+pub mod verification;
+pub mod watch;
 pub mod weather;
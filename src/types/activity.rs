@@ -0,0 +1,258 @@
+use crate::types::report::DailyFullData;
+use crate::types::units::*;
+use crate::types::weather::*;
+
+pub const MAX_SCORE: u8 = 10;
+const TEMPERATURE_TOLERANCE_CELSIUS: f32 = 10.0;
+const DRY_HUMIDITY_PERCENT: f32 = 50.0;
+const VERY_HIGH_UV_INDEX: f32 = 8.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ActivityWeights {
+    pub temperature: f32,
+    pub precipitation: f32,
+    pub wind: f32,
+    pub humidity: f32,
+    pub cloud_coverage: f32,
+    pub uv: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActivityProfile {
+    pub name: String,
+    pub weights: ActivityWeights,
+    pub ideal_temperature_celsius: (f32, f32),
+    pub max_wind_speed_meters_per_second: f32,
+}
+
+impl ActivityProfile {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            weights: ActivityWeights {
+                temperature: 1.0,
+                precipitation: 1.0,
+                wind: 1.0,
+                humidity: 1.0,
+                cloud_coverage: 1.0,
+                uv: 1.0,
+            },
+            ideal_temperature_celsius: (15.0, 25.0),
+            max_wind_speed_meters_per_second: 10.0,
+        }
+    }
+
+    pub fn score(&self, data: &DailyFullData) -> u8 {
+        let ActivityWeights {
+            temperature,
+            precipitation,
+            wind,
+            humidity,
+            cloud_coverage,
+            uv,
+        } = self.weights;
+        let weighted = [
+            (
+                temperature,
+                Some(self.score_temperature(&data.temperature_range)),
+            ),
+            (precipitation, Some(score_precipitation(&data.kind))),
+            (wind, Some(self.score_wind(&data.wind))),
+            (humidity, Some(score_humidity(&data.humidity_range))),
+            (
+                cloud_coverage,
+                Some(score_cloud_coverage(&data.cloud_coverage_range)),
+            ),
+            // Days from the weather archive have no UV index, they are scored without it
+            (uv, data.uv_index.map(score_uv_index)),
+        ];
+        let weighted: Vec<(f32, f32)> = weighted
+            .into_iter()
+            .filter_map(|(weight, score)| Some((weight, score?)))
+            .collect();
+        let total_weight: f32 = weighted.iter().map(|(weight, _)| weight.max(0.0)).sum();
+        if total_weight == 0.0 {
+            return 0;
+        }
+        let total: f32 = weighted
+            .iter()
+            .map(|(weight, score)| weight.max(0.0) * score)
+            .sum();
+        (total / total_weight * f32::from(MAX_SCORE)).round() as u8
+    }
+
+    pub fn best_day<'a>(&self, data: &'a [DailyFullData]) -> Option<(&'a DailyFullData, u8)> {
+        data.iter()
+            .map(|day| (day, self.score(day)))
+            // max_by_key keeps the last maximum, reversing makes ties resolve to the earliest day
+            .rev()
+            .max_by_key(|(_, score)| *score)
+    }
+
+    fn score_temperature(&self, range: &TemperatureRange) -> f32 {
+        let min = range.min().to_celsius().value();
+        let max = range.max().to_celsius().value();
+        let mean = (min + max) / 2.0;
+        let (ideal_min, ideal_max) = self.ideal_temperature_celsius;
+        let distance = (ideal_min - mean).max(mean - ideal_max).max(0.0);
+        1.0 - (distance / TEMPERATURE_TOLERANCE_CELSIUS).min(1.0)
+    }
+
+    fn score_wind(&self, wind: &WindScope) -> f32 {
+        if self.max_wind_speed_meters_per_second <= 0.0 {
+            return 0.0;
+        }
+        let speed = wind.speed_range.max().to_meters_per_second().value();
+        1.0 - (speed / self.max_wind_speed_meters_per_second).min(1.0)
+    }
+}
+
+pub fn builtin_profiles() -> Vec<ActivityProfile> {
+    let make = |name: &str, weights: [f32; 6], temperature: (f32, f32), wind: f32| {
+        let [
+            temperature_weight,
+            precipitation,
+            wind_weight,
+            humidity,
+            cloud_coverage,
+            uv,
+        ] = weights;
+        ActivityProfile {
+            name: name.into(),
+            weights: ActivityWeights {
+                temperature: temperature_weight,
+                precipitation,
+                wind: wind_weight,
+                humidity,
+                cloud_coverage,
+                uv,
+            },
+            ideal_temperature_celsius: temperature,
+            max_wind_speed_meters_per_second: wind,
+        }
+    };
+    vec![
+        make("running", [3.0, 3.0, 2.0, 2.0, 0.0, 2.0], (8.0, 18.0), 12.0),
+        make("cycling", [2.0, 3.0, 3.0, 1.0, 0.0, 2.0], (12.0, 24.0), 8.0),
+        make(
+            "laundry",
+            [1.0, 4.0, 0.0, 3.0, 2.0, 0.0],
+            (15.0, 35.0),
+            15.0,
+        ),
+        make("bbq", [3.0, 4.0, 1.0, 0.0, 1.0, 1.0], (18.0, 30.0), 10.0),
+    ]
+}
+
+fn score_precipitation(kind: &Kind) -> f32 {
+    match kind {
+        Kind::Clouds(_) => 1.0,
+        Kind::Fog(_) => 0.8,
+        Kind::Precipitation(precipitation) => match precipitation.intensity {
+            PrecipitationIntensity::Light => 0.4,
+            PrecipitationIntensity::Moderate => 0.2,
+            PrecipitationIntensity::Heavy | PrecipitationIntensity::Shower => 0.0,
+        },
+        Kind::Thunderstorm => 0.0,
+    }
+}
+
+fn score_humidity(range: &PercentageRange) -> f32 {
+    let mean = (f32::from(range.min.value) + f32::from(range.max.value)) / 2.0;
+    1.0 - ((mean - DRY_HUMIDITY_PERCENT) / (100.0 - DRY_HUMIDITY_PERCENT)).clamp(0.0, 1.0)
+}
+
+fn score_cloud_coverage(range: &PercentageRange) -> f32 {
+    let mean = (f32::from(range.min.value) + f32::from(range.max.value)) / 2.0;
+    1.0 - (mean / 100.0).clamp(0.0, 1.0)
+}
+
+fn score_uv_index(uv_index: f32) -> f32 {
+    1.0 - (uv_index / VERY_HIGH_UV_INDEX).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_day(day: u32, kind: Kind, temperature: (f32, f32), wind: f32) -> DailyFullData {
+        DailyFullData {
+            date: Date::from_ymd_opt(2025, 6, day).unwrap(),
+            kind,
+            temperature_range: TemperatureRange::new_celsius(temperature.0, temperature.1),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: PercentageRange::new(0, 20),
+            humidity_range: PercentageRange::new(30, 50),
+            wind: WindScope {
+                speed_range: SpeedRange::new_meters_per_second(0.0, wind),
+                dominant_direction: Azimuth::from(180.0),
+            },
+            pressure_range: PressureRange::new_hpa(1010.0, 1015.0),
+        }
+    }
+
+    fn make_running() -> ActivityProfile {
+        builtin_profiles()
+            .into_iter()
+            .find(|profile| profile.name == "running")
+            .unwrap()
+    }
+
+    #[test]
+    fn scores_perfect_day_with_maximum() {
+        let day = make_day(1, Kind::Clouds(Clouds::Clear), (10.0, 16.0), 0.0);
+        assert_eq!(make_running().score(&day), MAX_SCORE);
+    }
+
+    #[test]
+    fn lowers_score_for_rain_heat_and_wind() {
+        let running = make_running();
+        let rainy = make_day(
+            1,
+            Kind::Precipitation(Precipitation {
+                kind: PrecipitationKind::Rain,
+                intensity: PrecipitationIntensity::Heavy,
+                heat: PrecipitationHeat::Normal,
+            }),
+            (10.0, 16.0),
+            0.0,
+        );
+        assert_eq!(running.score(&rainy), 7);
+        let hot = make_day(1, Kind::Clouds(Clouds::Clear), (28.0, 36.0), 0.0);
+        assert_eq!(running.score(&hot), 7);
+        let windy = make_day(1, Kind::Clouds(Clouds::Clear), (10.0, 16.0), 12.0);
+        assert_eq!(running.score(&windy), 8);
+    }
+
+    #[test]
+    fn lowers_score_for_high_uv_index_when_known() {
+        let running = make_running();
+        let mut day = make_day(1, Kind::Clouds(Clouds::Clear), (10.0, 16.0), 0.0);
+        day.uv_index = Some(1.0);
+        assert_eq!(running.score(&day), 10);
+        day.uv_index = Some(9.0);
+        assert_eq!(running.score(&day), 8);
+    }
+
+    #[test]
+    fn converts_units_before_scoring() {
+        let mut day = make_day(1, Kind::Clouds(Clouds::Clear), (10.0, 16.0), 0.0);
+        day.temperature_range = TemperatureRange::new_fahrenheit(50.0, 60.8);
+        day.wind.speed_range = SpeedRange::new_kilometers_per_hour(0.0, 43.2);
+        assert_eq!(make_running().score(&day), 8);
+    }
+
+    #[test]
+    fn finds_earliest_best_day() {
+        let days = [
+            make_day(1, Kind::Thunderstorm, (10.0, 16.0), 0.0),
+            make_day(2, Kind::Clouds(Clouds::Light), (10.0, 16.0), 0.0),
+            make_day(3, Kind::Clouds(Clouds::Clear), (10.0, 16.0), 0.0),
+        ];
+        let (day, score) = make_running().best_day(&days).unwrap();
+        assert_eq!(day.date, Date::from_ymd_opt(2025, 6, 2).unwrap());
+        assert_eq!(score, MAX_SCORE);
+        assert_eq!(make_running().best_day(&[]), None);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::types::units::*;
+
+pub const NORMAL_PERIOD_FIRST_YEAR: i32 = 1991;
+pub const NORMAL_PERIOD_LAST_YEAR: i32 = 2020;
+pub const NORMAL_PERIOD_YEARS: i32 = NORMAL_PERIOD_LAST_YEAR - NORMAL_PERIOD_FIRST_YEAR + 1;
+
+pub fn temperature_anomaly(range: &TemperatureRange, normal: &Temperature) -> Temperature {
+    let min = range.min();
+    let difference = (min.value() + range.max().value()) / 2.0 - normal.value();
+    match min {
+        Temperature::Celsius(_) => Temperature::new_celsius(difference),
+        Temperature::Fahrenheit(_) => Temperature::new_fahrenheit(difference),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_anomaly_of_mean_temperature() {
+        let range = TemperatureRange::new_celsius(10.0, 20.0);
+        let anomaly = temperature_anomaly(&range, &Temperature::new_celsius(11.8));
+        assert!((anomaly.value() - 3.2).abs() < 0.001);
+        let range = TemperatureRange::new_fahrenheit(30.0, 40.0);
+        let anomaly = temperature_anomaly(&range, &Temperature::new_fahrenheit(41.0));
+        assert_eq!(anomaly, Temperature::new_fahrenheit(-6.0));
+    }
+
+    #[test]
+    fn uses_thirty_year_period() {
+        assert_eq!(NORMAL_PERIOD_YEARS, 30);
+    }
+}
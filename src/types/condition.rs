@@ -0,0 +1,355 @@
+use crate::types::attributes::WeatherAttribute;
+use crate::types::report::*;
+use crate::types::units::*;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    fn holds(&self, value: f32, threshold: f32) -> bool {
+        match self {
+            Self::Less => value < threshold,
+            Self::LessOrEqual => value <= threshold,
+            Self::Greater => value > threshold,
+            Self::GreaterOrEqual => value >= threshold,
+        }
+    }
+
+    fn is_upper_bound(&self) -> bool {
+        matches!(self, Self::Less | Self::LessOrEqual)
+    }
+
+    pub fn to_symbol(&self) -> &'static str {
+        match self {
+            Self::Less => "<",
+            Self::LessOrEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterOrEqual => ">=",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThresholdUnit {
+    Celsius,
+    Fahrenheit,
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+    Percent,
+    Hectopascal,
+}
+
+impl ThresholdUnit {
+    pub fn applies_to(&self, attribute: WeatherAttribute) -> bool {
+        match self {
+            Self::Celsius | Self::Fahrenheit => attribute == WeatherAttribute::Temperature,
+            Self::MetersPerSecond | Self::KilometersPerHour | Self::MilesPerHour | Self::Knots => {
+                attribute == WeatherAttribute::Wind
+            }
+            Self::Percent => matches!(
+                attribute,
+                WeatherAttribute::CloudCoverage | WeatherAttribute::Humidity
+            ),
+            Self::Hectopascal => attribute == WeatherAttribute::Pressure,
+        }
+    }
+
+    pub fn to_symbol(&self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+            Self::MetersPerSecond => "m/s",
+            Self::KilometersPerHour => "km/h",
+            Self::MilesPerHour => "mph",
+            Self::Knots => "kn",
+            Self::Percent => "%",
+            Self::Hectopascal => "hPa",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Condition {
+    pub attribute: WeatherAttribute,
+    pub comparison: Comparison,
+    pub threshold: f32,
+    pub unit: Option<ThresholdUnit>,
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            attribute_name(self.attribute),
+            self.comparison.to_symbol(),
+            self.threshold,
+            self.unit.map(|unit| unit.to_symbol()).unwrap_or_default()
+        )
+    }
+}
+
+pub fn attribute_name(attribute: WeatherAttribute) -> &'static str {
+    match attribute {
+        WeatherAttribute::WeatherKind => "weather_kind",
+        WeatherAttribute::Temperature => "temperature",
+        WeatherAttribute::CloudCoverage => "cloud_coverage",
+        WeatherAttribute::Humidity => "humidity",
+        WeatherAttribute::Wind => "wind",
+        WeatherAttribute::Pressure => "pressure",
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Measurement {
+    Temperature(Temperature),
+    Percentage(Percentage),
+    Speed(Speed),
+    Pressure(Pressure),
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Temperature(inner) => inner.fmt(f),
+            Self::Percentage(inner) => inner.fmt(f),
+            Self::Speed(inner) => inner.fmt(f),
+            Self::Pressure(inner) => inner.fmt(f),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trigger {
+    pub date: Date,
+    pub condition: Condition,
+    pub measurement: Measurement,
+}
+
+impl Condition {
+    pub fn check(&self, data: &DailyPartialData) -> Option<Trigger> {
+        let upper = self.comparison.is_upper_bound();
+        let measurement = match self.attribute {
+            WeatherAttribute::WeatherKind => None,
+            WeatherAttribute::Temperature => data.temperature_range.as_ref().map(|range| {
+                Measurement::Temperature(if upper { range.min() } else { range.max() })
+            }),
+            WeatherAttribute::CloudCoverage => data
+                .cloud_coverage_range
+                .as_ref()
+                .map(|range| Measurement::Percentage(if upper { range.min } else { range.max })),
+            WeatherAttribute::Humidity => data
+                .humidity_range
+                .as_ref()
+                .map(|range| Measurement::Percentage(if upper { range.min } else { range.max })),
+            WeatherAttribute::Wind => data.wind.as_ref().map(|wind| {
+                let range = &wind.speed_range;
+                Measurement::Speed(if upper { range.min() } else { range.max() })
+            }),
+            WeatherAttribute::Pressure => data
+                .pressure_range
+                .as_ref()
+                .map(|range| Measurement::Pressure(if upper { range.min() } else { range.max() })),
+        }?;
+        let (value, threshold) = self.compare_in_unit_of(&measurement);
+        self.comparison.holds(value, threshold).then(|| Trigger {
+            date: data.date,
+            condition: self.clone(),
+            measurement,
+        })
+    }
+
+    fn compare_in_unit_of(&self, measurement: &Measurement) -> (f32, f32) {
+        match measurement {
+            Measurement::Temperature(temperature) => {
+                let threshold = match self.unit {
+                    Some(ThresholdUnit::Celsius) => Temperature::new_celsius(self.threshold),
+                    Some(ThresholdUnit::Fahrenheit) => Temperature::new_fahrenheit(self.threshold),
+                    _ => return (temperature.value(), self.threshold),
+                };
+                let threshold = match temperature {
+                    Temperature::Celsius(_) => threshold.to_celsius(),
+                    Temperature::Fahrenheit(_) => threshold.to_fahrenheit(),
+                };
+                (temperature.value(), threshold.value())
+            }
+            Measurement::Speed(speed) => {
+                let threshold = match self.unit {
+                    Some(ThresholdUnit::KilometersPerHour) => {
+                        Speed::new_kilometers_per_hour(self.threshold)
+                    }
+                    Some(ThresholdUnit::MilesPerHour) => Speed::new_miles_per_hour(self.threshold),
+                    Some(ThresholdUnit::Knots) => Speed::new_knots(self.threshold),
+                    Some(ThresholdUnit::MetersPerSecond) => {
+                        Speed::new_meters_per_second(self.threshold)
+                    }
+                    _ => return (speed.value(), self.threshold),
+                };
+                (
+                    speed.to_meters_per_second().value(),
+                    threshold.to_meters_per_second().value(),
+                )
+            }
+            Measurement::Percentage(percentage) => (percentage.value.into(), self.threshold),
+            Measurement::Pressure(pressure) => (pressure.value(), self.threshold),
+        }
+    }
+}
+
+pub fn evaluate(conditions: &[Condition], report: &Report) -> Vec<Trigger> {
+    let Some(report) = report.to_daily_partial() else {
+        return Vec::new();
+    };
+    report
+        .data
+        .iter()
+        .flat_map(|data| {
+            conditions
+                .iter()
+                .filter_map(|condition| condition.check(data))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::weather::*;
+    use chrono::Datelike;
+
+    fn make_condition(
+        attribute: WeatherAttribute,
+        comparison: Comparison,
+        threshold: f32,
+        unit: Option<ThresholdUnit>,
+    ) -> Condition {
+        Condition {
+            attribute,
+            comparison,
+            threshold,
+            unit,
+        }
+    }
+
+    fn make_day(day: u32, temperature: TemperatureRange, wind: SpeedRange) -> DailyPartialData {
+        DailyPartialData {
+            date: Date::from_ymd_opt(2025, 1, day).unwrap(),
+            kind: None,
+            temperature_range: Some(temperature),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: Some(PercentageRange::new(60, 95)),
+            wind: Some(WindScope {
+                speed_range: wind,
+                dominant_direction: Azimuth::from(270.0),
+            }),
+            pressure_range: None,
+        }
+    }
+
+    #[test]
+    fn compares_lowest_value_for_upper_bound() {
+        let condition = make_condition(WeatherAttribute::Temperature, Comparison::Less, 0.0, None);
+        let frost = make_day(
+            1,
+            TemperatureRange::new_celsius(-3.5, 2.0),
+            SpeedRange::new_meters_per_second(1.0, 4.0),
+        );
+        let trigger = condition.check(&frost).unwrap();
+        assert_eq!(
+            trigger.measurement,
+            Measurement::Temperature(Temperature::new_celsius(-3.5))
+        );
+        let mild = make_day(
+            2,
+            TemperatureRange::new_celsius(1.0, 6.0),
+            SpeedRange::new_meters_per_second(1.0, 4.0),
+        );
+        assert_eq!(condition.check(&mild), None);
+    }
+
+    #[test]
+    fn converts_threshold_to_unit_of_report() {
+        let condition = make_condition(
+            WeatherAttribute::Wind,
+            Comparison::Greater,
+            15.0,
+            Some(ThresholdUnit::MetersPerSecond),
+        );
+        let day = make_day(
+            1,
+            TemperatureRange::new_celsius(1.0, 6.0),
+            SpeedRange::new_kilometers_per_hour(20.0, 50.0),
+        );
+        assert_eq!(condition.check(&day), None);
+        let day = make_day(
+            1,
+            TemperatureRange::new_celsius(1.0, 6.0),
+            SpeedRange::new_kilometers_per_hour(20.0, 58.0),
+        );
+        assert!(condition.check(&day).is_some());
+
+        let condition = make_condition(
+            WeatherAttribute::Temperature,
+            Comparison::LessOrEqual,
+            32.0,
+            Some(ThresholdUnit::Fahrenheit),
+        );
+        let day = make_day(
+            1,
+            TemperatureRange::new_celsius(-0.5, 6.0),
+            SpeedRange::new_meters_per_second(1.0, 4.0),
+        );
+        assert!(condition.check(&day).is_some());
+    }
+
+    #[test]
+    fn evaluates_conditions_for_each_day() {
+        let conditions = [
+            make_condition(WeatherAttribute::Temperature, Comparison::Less, 0.0, None),
+            make_condition(
+                WeatherAttribute::Humidity,
+                Comparison::GreaterOrEqual,
+                95.0,
+                Some(ThresholdUnit::Percent),
+            ),
+        ];
+        let report = Report::ForecastPartial(DailyPartialReport {
+            location: Coordinates::new(52.23, 21.01).into(),
+            data: vec![
+                make_day(
+                    1,
+                    TemperatureRange::new_celsius(-2.0, 3.0),
+                    SpeedRange::new_meters_per_second(1.0, 4.0),
+                ),
+                make_day(
+                    2,
+                    TemperatureRange::new_celsius(2.0, 8.0),
+                    SpeedRange::new_meters_per_second(1.0, 4.0),
+                ),
+            ],
+        });
+        let triggers = evaluate(&conditions, &report);
+        let summary: Vec<(u32, String)> = triggers
+            .iter()
+            .map(|trigger| (trigger.date.day(), trigger.condition.to_string()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (1, "temperature<0".into()),
+                (1, "humidity>=95%".into()),
+                (2, "humidity>=95%".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::types::report::*;
+use crate::types::units::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DegreeDay {
+    pub date: Date,
+    pub mean: Temperature,
+    pub heating: f32,
+    pub cooling: f32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DegreeDays {
+    pub base: Temperature,
+    pub days: Vec<DegreeDay>,
+}
+
+impl DegreeDays {
+    pub fn new(report: &DailyPartialReport, base: Temperature) -> Self {
+        let ranges: Vec<(Date, &TemperatureRange)> = report
+            .data
+            .iter()
+            .filter_map(|data| Some((data.date, data.temperature_range.as_ref()?)))
+            .collect();
+        let base = match ranges.first().map(|(_, range)| range.min()) {
+            Some(Temperature::Celsius(_)) => base.to_celsius(),
+            Some(Temperature::Fahrenheit(_)) => base.to_fahrenheit(),
+            None => base,
+        };
+        let days = ranges
+            .into_iter()
+            .map(|(date, range)| {
+                let mean = (range.min().value() + range.max().value()) / 2.0;
+                DegreeDay {
+                    date,
+                    mean: match range {
+                        TemperatureRange::Celsius { .. } => Temperature::new_celsius(mean),
+                        TemperatureRange::Fahrenheit { .. } => Temperature::new_fahrenheit(mean),
+                    },
+                    heating: (base.value() - mean).max(0.0),
+                    cooling: (mean - base.value()).max(0.0),
+                }
+            })
+            .collect();
+        Self { base, days }
+    }
+
+    pub fn total_heating(&self) -> f32 {
+        self.days.iter().map(|day| day.heating).sum()
+    }
+
+    pub fn total_cooling(&self) -> f32 {
+        self.days.iter().map(|day| day.cooling).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_report(ranges: &[TemperatureRange]) -> DailyPartialReport {
+        let mut report = DailyPartialReport {
+            location: Coordinates::new(52.23, 21.01).into(),
+            data: Vec::new(),
+        };
+        for (index, range) in ranges.iter().enumerate() {
+            report.data.push(DailyPartialData {
+                date: Date::from_ymd_opt(2025, 1, index as u32 + 1).unwrap(),
+                kind: None,
+                temperature_range: Some(range.clone()),
+                temperature_normal: None,
+                uv_index: None,
+                cloud_coverage_range: None,
+                humidity_range: None,
+                wind: None,
+                pressure_range: None,
+            });
+        }
+        report
+    }
+
+    #[test]
+    fn computes_heating_and_cooling_degree_days() {
+        let report = make_report(&[
+            TemperatureRange::new_celsius(-2.0, 6.0),
+            TemperatureRange::new_celsius(16.0, 30.0),
+            TemperatureRange::new_celsius(14.0, 22.0),
+        ]);
+        let degree_days = DegreeDays::new(&report, Temperature::new_celsius(18.0));
+        let heating: Vec<f32> = degree_days.days.iter().map(|day| day.heating).collect();
+        let cooling: Vec<f32> = degree_days.days.iter().map(|day| day.cooling).collect();
+        assert_eq!(heating, [16.0, 0.0, 0.0]);
+        assert_eq!(cooling, [0.0, 5.0, 0.0]);
+        assert_eq!(degree_days.total_heating(), 16.0);
+        assert_eq!(degree_days.total_cooling(), 5.0);
+    }
+
+    #[test]
+    fn converts_base_temperature_to_report_unit() {
+        let report = make_report(&[TemperatureRange::new_fahrenheit(40.0, 50.0)]);
+        let degree_days = DegreeDays::new(&report, Temperature::new_celsius(18.0));
+        assert_eq!(degree_days.base, Temperature::new_fahrenheit(64.4));
+        assert!((degree_days.total_heating() - 19.4).abs() < 0.001);
+    }
+
+    #[test]
+    fn skips_days_without_temperature() {
+        let mut report = make_report(&[TemperatureRange::new_celsius(10.0, 20.0)]);
+        report.data[0].temperature_range = None;
+        let degree_days = DegreeDays::new(&report, Temperature::new_celsius(18.0));
+        assert!(degree_days.days.is_empty());
+        assert_eq!(degree_days.total_heating(), 0.0);
+    }
+}
@@ -2,15 +2,24 @@
 pub enum FetchError {
     ConnectionFailure,
     DecodingFailure,
+    NotFound,
+    Rejected(String),
+    Timeout,
+    Unsupported(String),
 }
 
 impl std::fmt::Display for FetchError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let message = match self {
-            Self::ConnectionFailure => "Failed to connect to external server",
-            Self::DecodingFailure => "Failed to decode response from external server",
-        };
-        write!(f, "{message}")
+        match self {
+            Self::ConnectionFailure => write!(f, "Failed to connect to external server"),
+            Self::DecodingFailure => write!(f, "Failed to decode response from external server"),
+            Self::NotFound => write!(f, "Requested place was not found"),
+            Self::Rejected(message) => write!(f, "External server rejected the request: {message}"),
+            Self::Timeout => write!(f, "Timed out waiting for external source"),
+            Self::Unsupported(message) => {
+                write!(f, "Request exceeds what the source supports: {message}")
+            }
+        }
     }
 }
 
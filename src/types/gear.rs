@@ -0,0 +1,313 @@
+use crate::types::report::{CurrentPartialReport, DailyPartialData};
+use crate::types::units::*;
+use crate::types::weather::*;
+
+const WIND_CHILL_MAX_TEMPERATURE_CELSIUS: f32 = 10.0;
+const WIND_CHILL_MIN_SPEED_KILOMETERS_PER_HOUR: f32 = 4.8;
+const MODERATE_UV_INDEX: f32 = 3.0;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GearRule {
+    pub item: String,
+    pub below_temperature_celsius: Option<f32>,
+    pub above_temperature_celsius: Option<f32>,
+    pub precipitation: Option<PrecipitationKind>,
+    pub min_intensity: Option<PrecipitationIntensity>,
+    pub freezing: bool,
+    pub min_wind_speed_meters_per_second: Option<f32>,
+    pub max_cloud_coverage: Option<i8>,
+    pub min_uv_index: Option<f32>,
+}
+
+impl GearRule {
+    pub fn new(item: &str) -> Self {
+        Self {
+            item: item.into(),
+            ..Self::default()
+        }
+    }
+
+    fn applies_to(&self, conditions: &GearConditions) -> bool {
+        let lowest = conditions.apparent_temperature_celsius.map(|(min, _)| min);
+        let highest = conditions.apparent_temperature_celsius.map(|(_, max)| max);
+        let precipitation = conditions.precipitation;
+        [
+            check(self.below_temperature_celsius, lowest, |below, value| {
+                value < below
+            }),
+            check(self.above_temperature_celsius, highest, |above, value| {
+                value >= above
+            }),
+            check(self.precipitation, precipitation, |kind, value| {
+                value.kind == kind
+            }),
+            check(self.min_intensity, precipitation, |intensity, value| {
+                rank_intensity(value.intensity) >= rank_intensity(intensity)
+            }),
+            check(self.freezing.then_some(()), precipitation, |_, value| {
+                value.heat == PrecipitationHeat::Freezing
+            }),
+            check(
+                self.min_wind_speed_meters_per_second,
+                conditions.wind_speed_meters_per_second,
+                |min, value| value >= min,
+            ),
+            check(
+                self.max_cloud_coverage,
+                conditions.cloud_coverage,
+                |max, value| value <= max,
+            ),
+            check(self.min_uv_index, conditions.uv_index, |min, value| {
+                value >= min
+            }),
+        ]
+        .into_iter()
+        .all(|holds| holds)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GearConditions {
+    pub apparent_temperature_celsius: Option<(f32, f32)>,
+    pub precipitation: Option<Precipitation>,
+    pub wind_speed_meters_per_second: Option<f32>,
+    pub cloud_coverage: Option<i8>,
+    pub uv_index: Option<f32>,
+}
+
+impl From<&CurrentPartialReport> for GearConditions {
+    fn from(report: &CurrentPartialReport) -> Self {
+        let wind_speed = report.wind.as_ref().map(|wind| wind.speed);
+        let apparent = report
+            .temperature
+            .as_ref()
+            .map(|temperature| calculate_apparent_temperature(temperature, wind_speed.as_ref()));
+        Self {
+            apparent_temperature_celsius: apparent.map(|value| (value, value)),
+            precipitation: report.kind.as_ref().and_then(to_precipitation),
+            wind_speed_meters_per_second: wind_speed
+                .map(|speed| speed.to_meters_per_second().value()),
+            cloud_coverage: report.cloud_coverage.map(|coverage| coverage.value),
+            uv_index: report.uv_index,
+        }
+    }
+}
+
+impl From<&DailyPartialData> for GearConditions {
+    fn from(data: &DailyPartialData) -> Self {
+        let wind_speed = data.wind.as_ref().map(|wind| wind.speed_range.max());
+        let apparent = data.temperature_range.as_ref().map(|range| {
+            (
+                calculate_apparent_temperature(&range.min(), wind_speed.as_ref()),
+                range.max().to_celsius().value(),
+            )
+        });
+        Self {
+            apparent_temperature_celsius: apparent,
+            precipitation: data.kind.as_ref().and_then(to_precipitation),
+            wind_speed_meters_per_second: wind_speed
+                .map(|speed| speed.to_meters_per_second().value()),
+            cloud_coverage: data
+                .cloud_coverage_range
+                .as_ref()
+                .map(|range| range.min.value),
+            uv_index: data.uv_index,
+        }
+    }
+}
+
+pub fn default_rules() -> Vec<GearRule> {
+    vec![
+        GearRule {
+            below_temperature_celsius: Some(10.0),
+            ..GearRule::new("warm layers")
+        },
+        GearRule {
+            below_temperature_celsius: Some(0.0),
+            ..GearRule::new("a hat and gloves")
+        },
+        GearRule {
+            above_temperature_celsius: Some(25.0),
+            ..GearRule::new("light clothes")
+        },
+        GearRule {
+            precipitation: Some(PrecipitationKind::Rain),
+            ..GearRule::new("an umbrella")
+        },
+        GearRule {
+            precipitation: Some(PrecipitationKind::Snow),
+            ..GearRule::new("waterproof boots")
+        },
+        GearRule {
+            freezing: true,
+            ..GearRule::new("ice grips")
+        },
+        GearRule {
+            min_wind_speed_meters_per_second: Some(10.0),
+            ..GearRule::new("a windproof jacket")
+        },
+        GearRule {
+            min_uv_index: Some(MODERATE_UV_INDEX),
+            ..GearRule::new("sunglasses")
+        },
+    ]
+}
+
+pub fn recommend<'a>(rules: &'a [GearRule], conditions: &GearConditions) -> Vec<&'a str> {
+    rules
+        .iter()
+        .filter(|rule| rule.applies_to(conditions))
+        .map(|rule| rule.item.as_str())
+        .collect()
+}
+
+fn check<T, V>(requirement: Option<T>, value: Option<V>, holds: impl Fn(T, V) -> bool) -> bool {
+    match (requirement, value) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(requirement), Some(value)) => holds(requirement, value),
+    }
+}
+
+fn rank_intensity(intensity: PrecipitationIntensity) -> u8 {
+    match intensity {
+        PrecipitationIntensity::Light => 0,
+        PrecipitationIntensity::Moderate => 1,
+        PrecipitationIntensity::Heavy | PrecipitationIntensity::Shower => 2,
+    }
+}
+
+fn to_precipitation(kind: &Kind) -> Option<Precipitation> {
+    match kind {
+        Kind::Precipitation(precipitation) => Some(*precipitation),
+        Kind::Thunderstorm => Some(Precipitation {
+            kind: PrecipitationKind::Rain,
+            intensity: PrecipitationIntensity::Heavy,
+            heat: PrecipitationHeat::Normal,
+        }),
+        Kind::Clouds(_) | Kind::Fog(_) => None,
+    }
+}
+
+fn calculate_apparent_temperature(temperature: &Temperature, wind_speed: Option<&Speed>) -> f32 {
+    let celsius = temperature.to_celsius().value();
+    let Some(speed) = wind_speed else {
+        return celsius;
+    };
+    let kilometers_per_hour = speed.to_meters_per_second().value() * 3.6;
+    if celsius > WIND_CHILL_MAX_TEMPERATURE_CELSIUS
+        || kilometers_per_hour <= WIND_CHILL_MIN_SPEED_KILOMETERS_PER_HOUR
+    {
+        return celsius;
+    }
+    let factor = kilometers_per_hour.powf(0.16);
+    13.12 + 0.6215 * celsius - 11.37 * factor + 0.3965 * celsius * factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_day(kind: Kind, temperature: (f32, f32), wind: f32, clouds: i8) -> DailyPartialData {
+        DailyPartialData {
+            date: Date::from_ymd_opt(2025, 1, 10).unwrap(),
+            kind: Some(kind),
+            temperature_range: Some(TemperatureRange::new_celsius(temperature.0, temperature.1)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: Some(PercentageRange::new(clouds, 100)),
+            humidity_range: None,
+            wind: Some(WindScope {
+                speed_range: SpeedRange::new_meters_per_second(0.0, wind),
+                dominant_direction: Azimuth::from(90.0),
+            }),
+            pressure_range: None,
+        }
+    }
+
+    #[test]
+    fn recommends_gear_for_freezing_rain() {
+        let day = make_day(
+            Kind::Precipitation(Precipitation {
+                kind: PrecipitationKind::Rain,
+                intensity: PrecipitationIntensity::Light,
+                heat: PrecipitationHeat::Freezing,
+            }),
+            (-2.0, 1.0),
+            3.0,
+            80,
+        );
+        let rules = default_rules();
+        let items = recommend(&rules, &GearConditions::from(&day));
+        assert_eq!(
+            items,
+            [
+                "warm layers",
+                "a hat and gloves",
+                "an umbrella",
+                "ice grips"
+            ]
+        );
+    }
+
+    #[test]
+    fn considers_wind_chill() {
+        let day = make_day(Kind::Clouds(Clouds::Moderate), (2.0, 6.0), 12.0, 60);
+        let conditions = GearConditions::from(&day);
+        let (lowest, highest) = conditions.apparent_temperature_celsius.unwrap();
+        assert!((lowest - -5.0).abs() < 0.1, "{lowest}");
+        assert_eq!(highest, 6.0);
+        let rules = default_rules();
+        assert_eq!(
+            recommend(&rules, &conditions),
+            ["warm layers", "a hat and gloves", "a windproof jacket"]
+        );
+    }
+
+    #[test]
+    fn recommends_nothing_without_data() {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(52.23, 21.01));
+        let rules = default_rules();
+        assert!(recommend(&rules, &GearConditions::from(&report)).is_empty());
+        report.temperature = Some(Temperature::new_fahrenheit(86.0));
+        report.cloud_coverage = Some(Percentage::from(10));
+        assert_eq!(
+            recommend(&rules, &GearConditions::from(&report)),
+            ["light clothes"]
+        );
+        report.uv_index = Some(6.5);
+        assert_eq!(
+            recommend(&rules, &GearConditions::from(&report)),
+            ["light clothes", "sunglasses"]
+        );
+    }
+
+    #[test]
+    fn recommends_sunglasses_by_uv_index_regardless_of_clouds() {
+        let rules = default_rules();
+        let mut day = make_day(Kind::Clouds(Clouds::Dense), (15.0, 20.0), 2.0, 80);
+        day.uv_index = Some(4.0);
+        assert_eq!(
+            recommend(&rules, &GearConditions::from(&day)),
+            ["sunglasses"]
+        );
+        day.uv_index = Some(2.0);
+        assert!(recommend(&rules, &GearConditions::from(&day)).is_empty());
+    }
+
+    #[test]
+    fn requires_minimum_intensity() {
+        let rule = GearRule {
+            min_intensity: Some(PrecipitationIntensity::Moderate),
+            ..GearRule::new("a raincoat")
+        };
+        let rules = [rule];
+        let day = make_day(Kind::Thunderstorm, (15.0, 20.0), 2.0, 90);
+        assert_eq!(
+            recommend(&rules, &GearConditions::from(&day)),
+            ["a raincoat"]
+        );
+        let day = make_day(Kind::Fog(Fog::Normal), (15.0, 20.0), 2.0, 90);
+        assert!(recommend(&rules, &GearConditions::from(&day)).is_empty());
+    }
+}
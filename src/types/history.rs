@@ -0,0 +1,317 @@
+use crate::types::location::Location;
+use crate::types::report::*;
+use crate::types::units::*;
+use crate::types::verification::KindCategory;
+use chrono::{Days, NaiveDateTime, NaiveTime, Timelike, Utc};
+use std::cmp::Ordering;
+use std::ops::Range;
+
+const MORNING_HOURS: Range<u32> = 5..12;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistoryQuery {
+    All,
+    Coldest,
+    Warmest,
+    Windiest,
+    Last(KindCategory),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryFilter {
+    pub query: HistoryQuery,
+    pub day_count: DayCount,
+    pub mornings_only: bool,
+    pub coordinates: Vec<Coordinates>,
+}
+
+enum Observation<'a> {
+    Current(&'a CurrentPartialReport),
+    Day(&'a Location, &'a DailyPartialData),
+}
+
+impl Observation<'_> {
+    fn location(&self) -> &Location {
+        match self {
+            Self::Current(report) => &report.location,
+            Self::Day(location, _) => location,
+        }
+    }
+
+    fn moment(&self) -> Option<NaiveDateTime> {
+        match self {
+            Self::Current(report) => report.time.map(|time| time.naive_local()),
+            Self::Day(_, data) => Some(data.date.and_time(NaiveTime::MIN)),
+        }
+    }
+
+    fn is_morning(&self) -> bool {
+        match self {
+            Self::Current(report) => report
+                .time
+                .is_some_and(|time| MORNING_HOURS.contains(&time.hour())),
+            Self::Day(..) => false,
+        }
+    }
+
+    fn lowest_temperature(&self) -> Option<f32> {
+        let temperature = match self {
+            Self::Current(report) => report.temperature,
+            Self::Day(_, data) => data.temperature_range.as_ref().map(|range| range.min()),
+        };
+        temperature.map(|temperature| temperature.to_celsius().value())
+    }
+
+    fn highest_temperature(&self) -> Option<f32> {
+        let temperature = match self {
+            Self::Current(report) => report.temperature,
+            Self::Day(_, data) => data.temperature_range.as_ref().map(|range| range.max()),
+        };
+        temperature.map(|temperature| temperature.to_celsius().value())
+    }
+
+    fn wind_speed(&self) -> Option<f32> {
+        let speed = match self {
+            Self::Current(report) => report.wind.as_ref().map(|wind| wind.speed),
+            Self::Day(_, data) => data.wind.as_ref().map(|wind| wind.speed_range.max()),
+        };
+        speed.map(|speed| speed.to_meters_per_second().value())
+    }
+
+    fn kind(&self) -> Option<KindCategory> {
+        let kind = match self {
+            Self::Current(report) => report.kind.as_ref(),
+            Self::Day(_, data) => data.kind.as_ref(),
+        };
+        kind.map(KindCategory::from)
+    }
+
+    fn to_report(&self) -> Report {
+        match self {
+            Self::Current(report) => Report::CurrentPartial((*report).clone()),
+            Self::Day(location, data) => Report::PastPartial(DailyPartialReport {
+                location: (*location).clone(),
+                data: vec![(*data).clone()],
+            }),
+        }
+    }
+}
+
+pub fn select(
+    reports: &[Report],
+    filter: &HistoryFilter,
+    now: chrono::DateTime<Utc>,
+) -> Vec<Report> {
+    let observations: Vec<Observation> = collect_observations(reports)
+        .into_iter()
+        .filter(|observation| {
+            let location = observation.location();
+            let since = location
+                .today(now)
+                .checked_sub_days(Days::new(u64::from(filter.day_count)))
+                .unwrap_or(Date::MIN);
+            observation
+                .moment()
+                .is_some_and(|moment| moment.date() >= since)
+                && (!filter.mornings_only || observation.is_morning())
+                && (filter.coordinates.is_empty()
+                    || filter
+                        .coordinates
+                        .iter()
+                        .any(|coordinates| coordinates.is_near(&location.coordinates)))
+        })
+        .collect();
+    let selected = match filter.query {
+        HistoryQuery::All => return group_by_location(observations),
+        HistoryQuery::Coldest => find_extreme(
+            &observations,
+            Observation::lowest_temperature,
+            Ordering::Less,
+        ),
+        HistoryQuery::Warmest => find_extreme(
+            &observations,
+            Observation::highest_temperature,
+            Ordering::Greater,
+        ),
+        HistoryQuery::Windiest => {
+            find_extreme(&observations, Observation::wind_speed, Ordering::Greater)
+        }
+        HistoryQuery::Last(kind) => observations
+            .iter()
+            .filter(|observation| observation.kind() == Some(kind))
+            .max_by_key(|observation| observation.moment()),
+    };
+    selected.map(Observation::to_report).into_iter().collect()
+}
+
+fn collect_observations(reports: &[Report]) -> Vec<Observation<'_>> {
+    reports
+        .iter()
+        .flat_map(|report| match report {
+            Report::CurrentPartial(inner) => vec![Observation::Current(inner)],
+            Report::PastPartial(inner) => inner
+                .data
+                .iter()
+                .map(|data| Observation::Day(&inner.location, data))
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+fn find_extreme<'a, 'b>(
+    observations: &'b [Observation<'a>],
+    value: impl Fn(&Observation<'a>) -> Option<f32>,
+    wanted: Ordering,
+) -> Option<&'b Observation<'a>> {
+    observations
+        .iter()
+        .filter_map(|observation| value(observation).map(|value| (observation, value)))
+        .reduce(|best, candidate| {
+            if candidate.1.partial_cmp(&best.1) == Some(wanted) {
+                candidate
+            } else {
+                best
+            }
+        })
+        .map(|(observation, _)| observation)
+}
+
+fn group_by_location(observations: Vec<Observation>) -> Vec<Report> {
+    let mut days: Vec<DailyPartialReport> = Vec::new();
+    let mut currents: Vec<&CurrentPartialReport> = Vec::new();
+    for observation in observations {
+        match observation {
+            Observation::Current(report) => currents.push(report),
+            Observation::Day(location, data) => {
+                match days
+                    .iter_mut()
+                    .find(|report| report.location.coordinates == location.coordinates)
+                {
+                    Some(report) => report.data.push(data.clone()),
+                    None => days.push(DailyPartialReport {
+                        location: location.clone(),
+                        data: vec![data.clone()],
+                    }),
+                }
+            }
+        }
+    }
+    for report in &mut days {
+        report.data.sort_by_key(|data| data.date);
+    }
+    currents.sort_by_key(|report| report.time);
+    days.into_iter()
+        .map(Report::PastPartial)
+        .chain(currents.into_iter().cloned().map(Report::CurrentPartial))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::weather::*;
+    use chrono::Datelike;
+
+    fn make_current(time: &str, temperature: f32, kind: Kind) -> Report {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(52.25, 21.0));
+        report.time = Some(DateTime::parse_from_rfc3339(time).unwrap());
+        report.temperature = Some(Temperature::new_celsius(temperature));
+        report.kind = Some(kind);
+        Report::CurrentPartial(report)
+    }
+
+    fn make_day(day: u32, temperature: (f32, f32), kind: Kind) -> DailyPartialData {
+        DailyPartialData {
+            date: Date::from_ymd_opt(2025, 1, day).unwrap(),
+            kind: Some(kind),
+            temperature_range: Some(TemperatureRange::new_celsius(temperature.0, temperature.1)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        }
+    }
+
+    fn make_snow() -> Kind {
+        Kind::Precipitation(Precipitation {
+            kind: PrecipitationKind::Snow,
+            intensity: PrecipitationIntensity::Light,
+            heat: PrecipitationHeat::Normal,
+        })
+    }
+
+    fn make_reports() -> Vec<Report> {
+        vec![
+            Report::PastPartial(DailyPartialReport {
+                location: Coordinates::new(52.25, 21.0).into(),
+                data: vec![
+                    make_day(12, (-6.0, -1.0), make_snow()),
+                    make_day(10, (-2.0, 3.0), Kind::Clouds(Clouds::Dense)),
+                    make_day(2, (-9.0, -4.0), make_snow()),
+                ],
+            }),
+            make_current("2025-01-13T07:30:00+01:00", -4.5, make_snow()),
+            make_current(
+                "2025-01-13T14:00:00+01:00",
+                -7.0,
+                Kind::Clouds(Clouds::Clear),
+            ),
+        ]
+    }
+
+    fn make_filter(query: HistoryQuery) -> HistoryFilter {
+        HistoryFilter {
+            query,
+            day_count: 7,
+            mornings_only: false,
+            coordinates: Vec::new(),
+        }
+    }
+
+    fn now() -> chrono::DateTime<Utc> {
+        "2025-01-14T12:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn selects_observations_of_period_grouped_by_location() {
+        let result = select(&make_reports(), &make_filter(HistoryQuery::All), now());
+        let Report::PastPartial(days) = &result[0] else {
+            panic!("Expected daily report");
+        };
+        let dates: Vec<u32> = days.data.iter().map(|data| data.date.day()).collect();
+        assert_eq!(dates, [10, 12]);
+        assert_eq!(result.len(), 3);
+        assert!(matches!(result[1], Report::CurrentPartial(_)));
+    }
+
+    #[test]
+    fn finds_coldest_observation() {
+        let result = select(&make_reports(), &make_filter(HistoryQuery::Coldest), now());
+        assert_eq!(result, [make_reports()[2].clone()]);
+
+        let mut filter = make_filter(HistoryQuery::Coldest);
+        filter.mornings_only = true;
+        let result = select(&make_reports(), &filter, now());
+        assert_eq!(result, [make_reports()[1].clone()]);
+
+        filter.coordinates = vec![Coordinates::new(50.06, 19.94)];
+        assert!(select(&make_reports(), &filter, now()).is_empty());
+    }
+
+    #[test]
+    fn finds_last_time_of_weather_kind() {
+        let query = HistoryQuery::Last(KindCategory::Snow);
+        let result = select(&make_reports(), &make_filter(query), now());
+        assert_eq!(result, [make_reports()[1].clone()]);
+
+        let query = HistoryQuery::Last(KindCategory::Cloudy);
+        let result = select(&make_reports(), &make_filter(query), now());
+        let Report::PastPartial(days) = &result[0] else {
+            panic!("Expected daily report");
+        };
+        assert_eq!(days.data[0].date, Date::from_ymd_opt(2025, 1, 10).unwrap());
+    }
+}
@@ -0,0 +1,119 @@
+use crate::types::units::*;
+use chrono::{FixedOffset, Utc};
+use std::fmt::Display;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlaceName {
+    pub name: String,
+    pub country: Option<String>,
+}
+
+impl Display for PlaceName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.country {
+            Some(country) => write!(f, "{}, {country}", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    pub coordinates: Coordinates,
+    pub name: Option<String>,
+    pub country: Option<String>,
+    pub elevation: Option<Meters>,
+    pub requested_elevation: Option<Meters>,
+    pub timezone: Option<String>,
+    pub utc_offset: Option<FixedOffset>,
+}
+
+impl Location {
+    pub fn new(coordinates: Coordinates) -> Self {
+        Self {
+            coordinates,
+            name: None,
+            country: None,
+            elevation: None,
+            requested_elevation: None,
+            timezone: None,
+            utc_offset: None,
+        }
+    }
+
+    pub fn set_place_name(&mut self, place_name: PlaceName) {
+        self.name = Some(place_name.name);
+        self.country = place_name.country;
+    }
+
+    pub fn today(&self, now: chrono::DateTime<Utc>) -> Date {
+        match self.utc_offset {
+            Some(offset) => now.with_timezone(&offset).date_naive(),
+            None => now.with_timezone(&chrono::Local).date_naive(),
+        }
+    }
+
+    pub fn place_name(&self) -> Option<PlaceName> {
+        self.name.as_ref().map(|name| PlaceName {
+            name: name.clone(),
+            country: self.country.clone(),
+        })
+    }
+}
+
+impl From<Coordinates> for Location {
+    fn from(coordinates: Coordinates) -> Self {
+        Self::new(coordinates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_place_name_with_country() {
+        let place_name = PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        };
+        assert_eq!(place_name.to_string(), "Warsaw, Poland");
+    }
+
+    #[test]
+    fn displays_place_name_without_country() {
+        let place_name = PlaceName {
+            name: "Warsaw".into(),
+            country: None,
+        };
+        assert_eq!(place_name.to_string(), "Warsaw");
+    }
+
+    #[test]
+    fn sets_place_name_of_location() {
+        let mut location = Location::new(Coordinates::new(52.2297, 21.0122));
+        assert_eq!(location.place_name(), None);
+        let place_name = PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        };
+        location.set_place_name(place_name.clone());
+        assert_eq!(location.place_name(), Some(place_name));
+    }
+
+    #[test]
+    fn computes_today_in_timezone_of_location() {
+        let now = "2025-06-10T22:30:00Z".parse().unwrap();
+        let mut location = Location::new(Coordinates::new(35.6762, 139.6503));
+        location.utc_offset = FixedOffset::east_opt(9 * 3600);
+        assert_eq!(
+            location.today(now),
+            Date::from_ymd_opt(2025, 6, 11).unwrap()
+        );
+        location.utc_offset = FixedOffset::west_opt(7 * 3600);
+        assert_eq!(
+            location.today(now),
+            Date::from_ymd_opt(2025, 6, 10).unwrap()
+        );
+    }
+}
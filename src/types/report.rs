@@ -1,46 +1,75 @@
+use crate::types::location::*;
 use crate::types::units::*;
 use crate::types::weather::*;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CurrentFullReport {
+    pub location: Location,
+    pub time: DateTime,
     pub kind: Kind,
     pub temperature: Temperature,
     pub cloud_coverage: Percentage,
     pub humidity: Percentage,
     pub wind: Wind,
     pub pressure: Pressure,
+    pub uv_index: Option<f32>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CurrentPartialReport {
-    pub coordinates: Coordinates,
+    pub location: Location,
+    pub time: Option<DateTime>,
     pub kind: Option<Kind>,
     pub temperature: Option<Temperature>,
     pub cloud_coverage: Option<Percentage>,
     pub humidity: Option<Percentage>,
     pub wind: Option<Wind>,
     pub pressure: Option<Pressure>,
+    pub uv_index: Option<f32>,
 }
 
 impl CurrentPartialReport {
-    pub fn new_empty(coordinates: Coordinates) -> Self {
+    pub fn new_empty(location: impl Into<Location>) -> Self {
         Self {
-            coordinates,
+            location: location.into(),
+            time: None,
             kind: None,
             temperature: None,
             cloud_coverage: None,
             humidity: None,
             wind: None,
             pressure: None,
+            uv_index: None,
+        }
+    }
+}
+
+impl From<CurrentFullReport> for CurrentPartialReport {
+    fn from(report: CurrentFullReport) -> Self {
+        Self {
+            location: report.location,
+            time: Some(report.time),
+            kind: Some(report.kind),
+            temperature: Some(report.temperature),
+            cloud_coverage: Some(report.cloud_coverage),
+            humidity: Some(report.humidity),
+            wind: Some(report.wind),
+            pressure: Some(report.pressure),
+            uv_index: report.uv_index,
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct DailyFullData {
+    /// Calendar date in the requested timezone, which `Location::today` resolves with the
+    /// location offset
     pub date: Date,
     pub kind: Kind,
     pub temperature_range: TemperatureRange,
+    pub temperature_normal: Option<Temperature>,
+    /// Daily maximum of the UV index, missing from the weather archive
+    pub uv_index: Option<f32>,
     pub cloud_coverage_range: PercentageRange,
     pub humidity_range: PercentageRange,
     pub wind: WindScope,
@@ -49,14 +78,19 @@ pub struct DailyFullData {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct DailyFullReport {
+    pub location: Location,
     pub data: Vec<DailyFullData>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct DailyPartialData {
+    /// Calendar date in the requested timezone, which `Location::today` resolves with the
+    /// location offset
     pub date: Date,
     pub kind: Option<Kind>,
     pub temperature_range: Option<TemperatureRange>,
+    pub temperature_normal: Option<Temperature>,
+    pub uv_index: Option<f32>,
     pub cloud_coverage_range: Option<PercentageRange>,
     pub humidity_range: Option<PercentageRange>,
     pub wind: Option<WindScope>,
@@ -65,10 +99,39 @@ pub struct DailyPartialData {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct DailyPartialReport {
-    pub coordinates: Coordinates,
+    pub location: Location,
     pub data: Vec<DailyPartialData>,
 }
 
+impl From<DailyFullData> for DailyPartialData {
+    fn from(data: DailyFullData) -> Self {
+        Self {
+            date: data.date,
+            kind: Some(data.kind),
+            temperature_range: Some(data.temperature_range),
+            temperature_normal: data.temperature_normal,
+            uv_index: data.uv_index,
+            cloud_coverage_range: Some(data.cloud_coverage_range),
+            humidity_range: Some(data.humidity_range),
+            wind: Some(data.wind),
+            pressure_range: Some(data.pressure_range),
+        }
+    }
+}
+
+impl From<DailyFullReport> for DailyPartialReport {
+    fn from(report: DailyFullReport) -> Self {
+        Self {
+            location: report.location,
+            data: report
+                .data
+                .into_iter()
+                .map(DailyPartialData::from)
+                .collect(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Report {
     PastFull(DailyFullReport),
@@ -78,3 +141,35 @@ pub enum Report {
     ForecastFull(DailyFullReport),
     ForecastPartial(DailyPartialReport),
 }
+
+impl Report {
+    pub fn location(&self) -> &Location {
+        match self {
+            Self::PastFull(inner) | Self::ForecastFull(inner) => &inner.location,
+            Self::PastPartial(inner) | Self::ForecastPartial(inner) => &inner.location,
+            Self::CurrentFull(inner) => &inner.location,
+            Self::CurrentPartial(inner) => &inner.location,
+        }
+    }
+
+    pub fn location_mut(&mut self) -> &mut Location {
+        match self {
+            Self::PastFull(inner) | Self::ForecastFull(inner) => &mut inner.location,
+            Self::PastPartial(inner) | Self::ForecastPartial(inner) => &mut inner.location,
+            Self::CurrentFull(inner) => &mut inner.location,
+            Self::CurrentPartial(inner) => &mut inner.location,
+        }
+    }
+
+    pub fn coordinates(&self) -> &Coordinates {
+        &self.location().coordinates
+    }
+
+    pub fn to_daily_partial(&self) -> Option<DailyPartialReport> {
+        match self {
+            Self::PastFull(inner) | Self::ForecastFull(inner) => Some(inner.clone().into()),
+            Self::PastPartial(inner) | Self::ForecastPartial(inner) => Some(inner.clone()),
+            Self::CurrentFull(_) | Self::CurrentPartial(_) => None,
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use crate::types::report::*;
+use crate::types::units::*;
+use crate::types::weather::*;
+
+const PRESSURE_TREND_THRESHOLD_HPA: f32 = 3.0;
+const MIN_WIND_DIRECTION_CONSISTENCY: f32 = 0.01;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Extreme<T> {
+    pub value: T,
+    pub date: Date,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AttributeStatistics<T> {
+    pub mean: T,
+    pub min: Extreme<T>,
+    pub max: Extreme<T>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PressureTrend {
+    Rising,
+    Falling,
+    Steady,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DailyStatistics {
+    pub first_date: Date,
+    pub last_date: Date,
+    pub day_count: usize,
+    pub temperature: Option<AttributeStatistics<Temperature>>,
+    pub cloud_coverage: Option<AttributeStatistics<Percentage>>,
+    pub humidity: Option<AttributeStatistics<Percentage>>,
+    pub wind_speed: Option<AttributeStatistics<Speed>>,
+    pub prevailing_wind_direction: Option<Azimuth>,
+    pub pressure: Option<AttributeStatistics<Pressure>>,
+    pub pressure_trend: Option<PressureTrend>,
+    pub rainy_days: Option<usize>,
+}
+
+struct DailyValues {
+    date: Date,
+    mean: f32,
+    min: f32,
+    max: f32,
+}
+
+impl DailyStatistics {
+    pub fn new(report: &DailyPartialReport) -> Option<Self> {
+        let data = &report.data;
+        let first_date = data.iter().map(|day| day.date).min()?;
+        let last_date = data.iter().map(|day| day.date).max()?;
+        Some(Self {
+            first_date,
+            last_date,
+            day_count: data.len(),
+            temperature: summarize_temperature(data),
+            cloud_coverage: summarize_percentage(data, |day| day.cloud_coverage_range.as_ref()),
+            humidity: summarize_percentage(data, |day| day.humidity_range.as_ref()),
+            wind_speed: summarize_wind_speed(data),
+            prevailing_wind_direction: compute_prevailing_wind_direction(data),
+            pressure: summarize_pressure(data),
+            pressure_trend: compute_pressure_trend(data),
+            rainy_days: count_rainy_days(data),
+        })
+    }
+}
+
+fn summarize<T>(
+    values: Vec<DailyValues>,
+    make: impl Fn(f32) -> T,
+) -> Option<AttributeStatistics<T>> {
+    let mean = values.iter().map(|day| day.mean).sum::<f32>() / values.len() as f32;
+    let lowest = values.iter().min_by(|a, b| a.min.total_cmp(&b.min))?;
+    // Ties resolve to the earliest date, as min_by already does
+    let highest = values
+        .iter()
+        .max_by(|a, b| a.max.total_cmp(&b.max).then(b.date.cmp(&a.date)))?;
+    Some(AttributeStatistics {
+        mean: make(mean),
+        min: Extreme {
+            value: make(lowest.min),
+            date: lowest.date,
+        },
+        max: Extreme {
+            value: make(highest.max),
+            date: highest.date,
+        },
+    })
+}
+
+fn make_daily_values(date: Date, min: f32, max: f32) -> DailyValues {
+    DailyValues {
+        date,
+        mean: (min + max) / 2.0,
+        min,
+        max,
+    }
+}
+
+fn summarize_temperature(data: &[DailyPartialData]) -> Option<AttributeStatistics<Temperature>> {
+    let template = data
+        .iter()
+        .find_map(|day| day.temperature_range.as_ref())?
+        .min();
+    let values = data
+        .iter()
+        .filter_map(|day| {
+            let range = day.temperature_range.as_ref()?;
+            Some(make_daily_values(
+                day.date,
+                range.min().value(),
+                range.max().value(),
+            ))
+        })
+        .collect();
+    summarize(values, |value| match template {
+        Temperature::Celsius(_) => Temperature::new_celsius(value),
+        Temperature::Fahrenheit(_) => Temperature::new_fahrenheit(value),
+    })
+}
+
+fn summarize_percentage(
+    data: &[DailyPartialData],
+    select: impl Fn(&DailyPartialData) -> Option<&PercentageRange>,
+) -> Option<AttributeStatistics<Percentage>> {
+    let values = data
+        .iter()
+        .filter_map(|day| {
+            let range = select(day)?;
+            Some(make_daily_values(
+                day.date,
+                range.min.value.into(),
+                range.max.value.into(),
+            ))
+        })
+        .collect();
+    summarize(values, |value| Percentage::from(value.round() as i8))
+}
+
+fn summarize_wind_speed(data: &[DailyPartialData]) -> Option<AttributeStatistics<Speed>> {
+    let template = data
+        .iter()
+        .find_map(|day| day.wind.as_ref())?
+        .speed_range
+        .min();
+    let values = data
+        .iter()
+        .filter_map(|day| {
+            let range = &day.wind.as_ref()?.speed_range;
+            Some(make_daily_values(
+                day.date,
+                range.min().value(),
+                range.max().value(),
+            ))
+        })
+        .collect();
+    summarize(values, |value| match template {
+        Speed::MetersPerSecond(_) => Speed::new_meters_per_second(value),
+        Speed::KilometersPerHour(_) => Speed::new_kilometers_per_hour(value),
+        Speed::MilesPerHour(_) => Speed::new_miles_per_hour(value),
+        Speed::Knots(_) => Speed::new_knots(value),
+    })
+}
+
+fn summarize_pressure(data: &[DailyPartialData]) -> Option<AttributeStatistics<Pressure>> {
+    let values = data
+        .iter()
+        .filter_map(|day| {
+            let range = day.pressure_range.as_ref()?;
+            Some(make_daily_values(
+                day.date,
+                range.min().value(),
+                range.max().value(),
+            ))
+        })
+        .collect();
+    summarize(values, Pressure::new_hpa)
+}
+
+fn compute_prevailing_wind_direction(data: &[DailyPartialData]) -> Option<Azimuth> {
+    let directions: Vec<f32> = data
+        .iter()
+        .filter_map(|day| day.wind.as_ref())
+        .map(|wind| f32::from(wind.dominant_direction).to_radians())
+        .collect();
+    if directions.is_empty() {
+        return None;
+    }
+    let count = directions.len() as f32;
+    let sin = directions.iter().map(|angle| angle.sin()).sum::<f32>() / count;
+    let cos = directions.iter().map(|angle| angle.cos()).sum::<f32>() / count;
+    if sin.hypot(cos) < MIN_WIND_DIRECTION_CONSISTENCY {
+        return None;
+    }
+    Some(Azimuth::from(sin.atan2(cos).to_degrees().rem_euclid(360.0)))
+}
+
+fn compute_pressure_trend(data: &[DailyPartialData]) -> Option<PressureTrend> {
+    let mut days: Vec<(Date, &PressureRange)> = data
+        .iter()
+        .filter_map(|day| Some((day.date, day.pressure_range.as_ref()?)))
+        .collect();
+    if days.len() < 2 {
+        return None;
+    }
+    days.sort_by_key(|(date, _)| *date);
+    let mean = |range: &PressureRange| (range.min().value() + range.max().value()) / 2.0;
+    let change = mean(days.last()?.1) - mean(days.first()?.1);
+    Some(if change >= PRESSURE_TREND_THRESHOLD_HPA {
+        PressureTrend::Rising
+    } else if change <= -PRESSURE_TREND_THRESHOLD_HPA {
+        PressureTrend::Falling
+    } else {
+        PressureTrend::Steady
+    })
+}
+
+fn count_rainy_days(data: &[DailyPartialData]) -> Option<usize> {
+    let kinds: Vec<&Kind> = data.iter().filter_map(|day| day.kind.as_ref()).collect();
+    if kinds.is_empty() {
+        return None;
+    }
+    Some(kinds.into_iter().filter(|kind| is_rainy(kind)).count())
+}
+
+fn is_rainy(kind: &Kind) -> bool {
+    match kind {
+        Kind::Precipitation(precipitation) => precipitation.kind == PrecipitationKind::Rain,
+        Kind::Thunderstorm => true,
+        Kind::Clouds(_) | Kind::Fog(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_day(day: u32, kind: Kind, temperature: (f32, f32), direction: f32) -> DailyPartialData {
+        DailyPartialData {
+            date: Date::from_ymd_opt(2025, 8, day).unwrap(),
+            kind: Some(kind),
+            temperature_range: Some(TemperatureRange::new_celsius(temperature.0, temperature.1)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: Some(PercentageRange::new(40, 60)),
+            wind: Some(WindScope {
+                speed_range: SpeedRange::new_meters_per_second(1.0, 3.0),
+                dominant_direction: Azimuth::from(direction),
+            }),
+            pressure_range: Some(PressureRange::new_hpa(
+                1000.0 + day as f32,
+                1010.0 + day as f32,
+            )),
+        }
+    }
+
+    fn make_report(data: Vec<DailyPartialData>) -> DailyPartialReport {
+        DailyPartialReport {
+            location: Coordinates::new(52.23, 21.01).into(),
+            data,
+        }
+    }
+
+    fn make_rain() -> Kind {
+        Kind::Precipitation(Precipitation {
+            kind: PrecipitationKind::Rain,
+            intensity: PrecipitationIntensity::Light,
+            heat: PrecipitationHeat::Normal,
+        })
+    }
+
+    #[test]
+    fn computes_means_and_extremes_with_dates() {
+        let report = make_report(vec![
+            make_day(1, make_rain(), (10.0, 20.0), 350.0),
+            make_day(2, Kind::Clouds(Clouds::Clear), (5.0, 15.0), 10.0),
+            make_day(3, Kind::Thunderstorm, (12.0, 24.0), 20.0),
+        ]);
+        let statistics = DailyStatistics::new(&report).unwrap();
+        assert_eq!(statistics.day_count, 3);
+        assert_eq!(
+            statistics.first_date,
+            Date::from_ymd_opt(2025, 8, 1).unwrap()
+        );
+        assert_eq!(
+            statistics.last_date,
+            Date::from_ymd_opt(2025, 8, 3).unwrap()
+        );
+        let temperature = statistics.temperature.unwrap();
+        assert_eq!(temperature.mean, Temperature::new_celsius(14.333_333));
+        assert_eq!(
+            temperature.min,
+            Extreme {
+                value: Temperature::new_celsius(5.0),
+                date: Date::from_ymd_opt(2025, 8, 2).unwrap(),
+            }
+        );
+        assert_eq!(
+            temperature.max,
+            Extreme {
+                value: Temperature::new_celsius(24.0),
+                date: Date::from_ymd_opt(2025, 8, 3).unwrap(),
+            }
+        );
+        assert_eq!(statistics.humidity.unwrap().mean, Percentage::from(50));
+        assert_eq!(statistics.cloud_coverage, None);
+        assert_eq!(statistics.rainy_days, Some(2));
+    }
+
+    #[test]
+    fn computes_circular_mean_of_wind_direction() {
+        let report = make_report(vec![
+            make_day(1, make_rain(), (10.0, 20.0), 350.0),
+            make_day(2, make_rain(), (10.0, 20.0), 10.0),
+            make_day(3, make_rain(), (10.0, 20.0), 30.0),
+        ]);
+        let statistics = DailyStatistics::new(&report).unwrap();
+        let direction = f32::from(statistics.prevailing_wind_direction.unwrap());
+        assert!((direction - 10.0).abs() < 0.01, "{direction}");
+    }
+
+    #[test]
+    fn has_no_prevailing_direction_for_opposite_winds() {
+        let report = make_report(vec![
+            make_day(1, make_rain(), (10.0, 20.0), 90.0),
+            make_day(2, make_rain(), (10.0, 20.0), 270.0),
+        ]);
+        let statistics = DailyStatistics::new(&report).unwrap();
+        assert_eq!(statistics.prevailing_wind_direction, None);
+    }
+
+    #[test]
+    fn computes_pressure_trend() {
+        let report = make_report(vec![
+            make_day(1, make_rain(), (10.0, 20.0), 90.0),
+            make_day(5, make_rain(), (10.0, 20.0), 90.0),
+        ]);
+        let statistics = DailyStatistics::new(&report).unwrap();
+        assert_eq!(statistics.pressure_trend, Some(PressureTrend::Rising));
+
+        let report = make_report(vec![
+            make_day(5, make_rain(), (10.0, 20.0), 90.0),
+            make_day(6, make_rain(), (10.0, 20.0), 90.0),
+        ]);
+        let statistics = DailyStatistics::new(&report).unwrap();
+        assert_eq!(statistics.pressure_trend, Some(PressureTrend::Steady));
+    }
+
+    #[test]
+    fn has_no_statistics_for_empty_report() {
+        assert_eq!(DailyStatistics::new(&make_report(Vec::new())), None);
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::FixedOffset;
+use std::fmt::Display;
+
+const SECONDS_PER_HOUR: i32 = 3600;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Timezone {
+    #[default]
+    Auto,
+    Named(String),
+    Fixed(FixedOffset),
+}
+
+impl Timezone {
+    /// Names the timezone for weather services, automatic timezones have no name and offsets
+    /// that are not whole hours have no IANA zone
+    pub fn to_iana_name(&self) -> Result<Option<String>, String> {
+        match self {
+            Self::Auto => Ok(None),
+            Self::Named(name) => Ok(Some(name.clone())),
+            Self::Fixed(offset) => {
+                let seconds = offset.local_minus_utc();
+                if seconds % SECONDS_PER_HOUR != 0 {
+                    return Err(format!(
+                        "UTC offset {offset} is not a whole hour and has no IANA timezone"
+                    ));
+                }
+                let hours = seconds / SECONDS_PER_HOUR;
+                // Signs of Etc/GMT zones are inverted, Etc/GMT-2 is two hours ahead of UTC
+                Ok(Some(match hours {
+                    0 => "GMT".into(),
+                    hours if hours > 0 => format!("Etc/GMT-{hours}"),
+                    hours => format!("Etc/GMT+{}", -hours),
+                }))
+            }
+        }
+    }
+}
+
+impl Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Named(name) => write!(f, "{name}"),
+            Self::Fixed(offset) => write!(f, "UTC{offset}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_whole_hour_offsets_to_etc_zones() {
+        let east = Timezone::Fixed(FixedOffset::east_opt(2 * 3600).unwrap());
+        assert_eq!(east.to_iana_name(), Ok(Some("Etc/GMT-2".into())));
+        let west = Timezone::Fixed(FixedOffset::west_opt(5 * 3600).unwrap());
+        assert_eq!(west.to_iana_name(), Ok(Some("Etc/GMT+5".into())));
+        let zero = Timezone::Fixed(FixedOffset::east_opt(0).unwrap());
+        assert_eq!(zero.to_iana_name(), Ok(Some("GMT".into())));
+    }
+
+    #[test]
+    fn rejects_fractional_offsets_without_iana_name() {
+        let india = Timezone::Fixed(FixedOffset::east_opt(5 * 3600 + 1800).unwrap());
+        assert_eq!(
+            india.to_iana_name(),
+            Err("UTC offset +05:30 is not a whole hour and has no IANA timezone".into())
+        );
+        assert_eq!(Timezone::Auto.to_iana_name(), Ok(None));
+    }
+
+    #[test]
+    fn displays_timezone() {
+        assert_eq!(Timezone::Auto.to_string(), "auto");
+        assert_eq!(
+            Timezone::Named("Europe/Warsaw".into()).to_string(),
+            "Europe/Warsaw"
+        );
+        let fixed = Timezone::Fixed(FixedOffset::east_opt(2 * 3600).unwrap());
+        assert_eq!(fixed.to_string(), "UTC+02:00");
+    }
+}
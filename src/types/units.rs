@@ -97,6 +97,30 @@ impl Temperature {
     pub fn new_fahrenheit(value: f32) -> Self {
         Self::Fahrenheit(Fahrenheit::from(value))
     }
+    pub fn value(&self) -> f32 {
+        match self {
+            Self::Celsius(inner) => inner.degrees.raw(),
+            Self::Fahrenheit(inner) => inner.degrees.raw(),
+        }
+    }
+    pub fn unit_symbol(&self) -> &'static str {
+        match self {
+            Self::Celsius(_) => "°C",
+            Self::Fahrenheit(_) => "°F",
+        }
+    }
+    pub fn to_celsius(&self) -> Self {
+        match self {
+            Self::Celsius(_) => *self,
+            Self::Fahrenheit(inner) => Self::Celsius(Celsius::from(*inner)),
+        }
+    }
+    pub fn to_fahrenheit(&self) -> Self {
+        match self {
+            Self::Celsius(inner) => Self::Fahrenheit(Fahrenheit::from(*inner)),
+            Self::Fahrenheit(_) => *self,
+        }
+    }
 }
 
 impl Display for Temperature {
@@ -129,6 +153,18 @@ impl TemperatureRange {
             max: Fahrenheit::from(max),
         }
     }
+    pub fn min(&self) -> Temperature {
+        match self {
+            Self::Celsius { min, .. } => Temperature::Celsius(*min),
+            Self::Fahrenheit { min, .. } => Temperature::Fahrenheit(*min),
+        }
+    }
+    pub fn max(&self) -> Temperature {
+        match self {
+            Self::Celsius { max, .. } => Temperature::Celsius(*max),
+            Self::Fahrenheit { max, .. } => Temperature::Fahrenheit(*max),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -266,6 +302,10 @@ impl From<Knots> for f32 {
     }
 }
 
+const METERS_PER_SECOND_IN_KILOMETER_PER_HOUR: f32 = 1.0 / 3.6;
+const METERS_PER_SECOND_IN_MILE_PER_HOUR: f32 = 0.44704;
+const METERS_PER_SECOND_IN_KNOT: f32 = 0.514_444;
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Speed {
     MetersPerSecond(MetersPerSecond),
@@ -287,6 +327,42 @@ impl Speed {
     pub fn new_knots(value: f32) -> Self {
         Self::Knots(Knots::from(value))
     }
+    pub fn value(&self) -> f32 {
+        match self {
+            Self::MetersPerSecond(inner) => inner.value,
+            Self::KilometersPerHour(inner) => inner.value,
+            Self::MilesPerHour(inner) => inner.value,
+            Self::Knots(inner) => inner.value,
+        }
+    }
+    pub fn unit_symbol(&self) -> &'static str {
+        match self {
+            Self::MetersPerSecond(_) => "m/s",
+            Self::KilometersPerHour(_) => "km/h",
+            Self::MilesPerHour(_) => "mph",
+            Self::Knots(_) => "kn",
+        }
+    }
+    pub fn to_meters_per_second(&self) -> Self {
+        let factor = match self {
+            Self::MetersPerSecond(_) => 1.0,
+            Self::KilometersPerHour(_) => METERS_PER_SECOND_IN_KILOMETER_PER_HOUR,
+            Self::MilesPerHour(_) => METERS_PER_SECOND_IN_MILE_PER_HOUR,
+            Self::Knots(_) => METERS_PER_SECOND_IN_KNOT,
+        };
+        Self::new_meters_per_second(self.value() * factor)
+    }
+    pub fn to_kilometers_per_hour(&self) -> Self {
+        let value = self.to_meters_per_second().value() / METERS_PER_SECOND_IN_KILOMETER_PER_HOUR;
+        Self::new_kilometers_per_hour(value)
+    }
+    pub fn to_miles_per_hour(&self) -> Self {
+        let value = self.to_meters_per_second().value() / METERS_PER_SECOND_IN_MILE_PER_HOUR;
+        Self::new_miles_per_hour(value)
+    }
+    pub fn to_knots(&self) -> Self {
+        Self::new_knots(self.to_meters_per_second().value() / METERS_PER_SECOND_IN_KNOT)
+    }
 }
 
 impl Display for Speed {
@@ -349,6 +425,22 @@ impl SpeedRange {
             max: Knots::from(max),
         }
     }
+    pub fn min(&self) -> Speed {
+        match self {
+            Self::MetersPerSecond { min, .. } => Speed::MetersPerSecond(*min),
+            Self::KilometersPerHour { min, .. } => Speed::KilometersPerHour(*min),
+            Self::MilesPerHour { min, .. } => Speed::MilesPerHour(*min),
+            Self::Knots { min, .. } => Speed::Knots(*min),
+        }
+    }
+    pub fn max(&self) -> Speed {
+        match self {
+            Self::MetersPerSecond { max, .. } => Speed::MetersPerSecond(*max),
+            Self::KilometersPerHour { max, .. } => Speed::KilometersPerHour(*max),
+            Self::MilesPerHour { max, .. } => Speed::MilesPerHour(*max),
+            Self::Knots { max, .. } => Speed::Knots(*max),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -476,6 +568,16 @@ impl Pressure {
     pub fn new_hpa(value: f32) -> Self {
         Self::Hpa(Hectopascal::from(value))
     }
+    pub fn value(&self) -> f32 {
+        match self {
+            Self::Hpa(inner) => inner.value,
+        }
+    }
+    pub fn unit_symbol(&self) -> &'static str {
+        match self {
+            Self::Hpa(_) => "hPa",
+        }
+    }
 }
 
 impl Display for Pressure {
@@ -499,8 +601,20 @@ impl PressureRange {
             max: Hectopascal::from(max),
         }
     }
+    pub fn min(&self) -> Pressure {
+        match self {
+            Self::Hpa { min, .. } => Pressure::Hpa(*min),
+        }
+    }
+    pub fn max(&self) -> Pressure {
+        match self {
+            Self::Hpa { max, .. } => Pressure::Hpa(*max),
+        }
+    }
 }
 
+const MAX_NEARBY_DEGREES: f32 = 0.05;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Coordinates {
     pub latitude: Degrees,
@@ -514,6 +628,11 @@ impl Coordinates {
             longitude: longitude.into(),
         }
     }
+
+    pub fn is_near(&self, other: &Coordinates) -> bool {
+        (self.latitude.raw() - other.latitude.raw()).abs() <= MAX_NEARBY_DEGREES
+            && (self.longitude.raw() - other.longitude.raw()).abs() <= MAX_NEARBY_DEGREES
+    }
 }
 
 impl Display for Coordinates {
@@ -527,8 +646,34 @@ impl Display for Coordinates {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Meters {
+    pub value: f32,
+}
+
+impl From<f32> for Meters {
+    fn from(value: f32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<Meters> for f32 {
+    fn from(meters: Meters) -> Self {
+        meters.value
+    }
+}
+
+impl Display for Meters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        write!(f, "{:.precision$} m", self.value)
+    }
+}
+
 pub type Date = chrono::NaiveDate;
 
+pub type DateTime = chrono::DateTime<chrono::FixedOffset>;
+
 pub type DayCount = u8;
 
 #[cfg(test)]
@@ -576,6 +721,32 @@ mod tests {
         assert_eq!(format!("{temperature:.3}"), "34.560°F");
     }
 
+    #[test]
+    fn converts_temperature_between_units() {
+        let celsius = Temperature::new_celsius(20.0);
+        assert_eq!(celsius.to_fahrenheit(), Temperature::new_fahrenheit(68.0));
+        assert_eq!(celsius.to_celsius(), celsius);
+        let fahrenheit = Temperature::new_fahrenheit(50.0);
+        assert_eq!(fahrenheit.to_celsius(), Temperature::new_celsius(10.0));
+    }
+
+    #[test]
+    fn converts_speed_to_meters_per_second() {
+        let speed = Speed::new_kilometers_per_hour(36.0).to_meters_per_second();
+        assert_eq!(speed, Speed::new_meters_per_second(10.0));
+        let speed = Speed::new_knots(10.0).to_meters_per_second();
+        assert!((speed.value() - 5.144_44).abs() < 0.001);
+    }
+
+    #[test]
+    fn converts_speed_from_meters_per_second() {
+        let speed = Speed::new_meters_per_second(10.0);
+        assert!((speed.to_kilometers_per_hour().value() - 36.0).abs() < 0.001);
+        assert!((speed.to_miles_per_hour().value() - 22.369).abs() < 0.001);
+        assert!((speed.to_knots().value() - 19.438).abs() < 0.001);
+        assert_eq!(speed.to_knots().unit_symbol(), "kn");
+    }
+
     #[test]
     fn validates_temperature_range() {
         assert_panics(|| TemperatureRange::new_celsius(32.0, 31.0));
@@ -583,6 +754,26 @@ mod tests {
         assert_no_panic(|| TemperatureRange::new_celsius(32.0, 33.0));
     }
 
+    #[test]
+    fn splits_temperature_into_value_and_unit() {
+        let temperature = Temperature::new_celsius(-4.5);
+        assert_eq!(temperature.value(), -4.5);
+        assert_eq!(temperature.unit_symbol(), "°C");
+        let temperature = Temperature::new_fahrenheit(70.1);
+        assert_eq!(temperature.value(), 70.1);
+        assert_eq!(temperature.unit_symbol(), "°F");
+    }
+
+    #[test]
+    fn extracts_temperature_range_bounds() {
+        let range = TemperatureRange::new_celsius(1.2, 3.4);
+        assert_eq!(range.min(), Temperature::new_celsius(1.2));
+        assert_eq!(range.max(), Temperature::new_celsius(3.4));
+        let range = TemperatureRange::new_fahrenheit(1.2, 3.4);
+        assert_eq!(range.min(), Temperature::new_fahrenheit(1.2));
+        assert_eq!(range.max(), Temperature::new_fahrenheit(3.4));
+    }
+
     #[test]
     fn displays_percentage() {
         let percentage = Percentage::from(27);
@@ -644,6 +835,34 @@ mod tests {
         assert_no_panic(|| SpeedRange::new_knots(32.0, 33.0));
     }
 
+    #[test]
+    fn splits_speed_into_value_and_unit() {
+        let speed = Speed::new_meters_per_second(1.5);
+        assert_eq!((speed.value(), speed.unit_symbol()), (1.5, "m/s"));
+        let speed = Speed::new_kilometers_per_hour(2.5);
+        assert_eq!((speed.value(), speed.unit_symbol()), (2.5, "km/h"));
+        let speed = Speed::new_miles_per_hour(3.5);
+        assert_eq!((speed.value(), speed.unit_symbol()), (3.5, "mph"));
+        let speed = Speed::new_knots(4.5);
+        assert_eq!((speed.value(), speed.unit_symbol()), (4.5, "kn"));
+    }
+
+    #[test]
+    fn extracts_speed_range_bounds() {
+        let range = SpeedRange::new_meters_per_second(1.2, 3.4);
+        assert_eq!(range.min(), Speed::new_meters_per_second(1.2));
+        assert_eq!(range.max(), Speed::new_meters_per_second(3.4));
+        let range = SpeedRange::new_kilometers_per_hour(1.2, 3.4);
+        assert_eq!(range.min(), Speed::new_kilometers_per_hour(1.2));
+        assert_eq!(range.max(), Speed::new_kilometers_per_hour(3.4));
+        let range = SpeedRange::new_miles_per_hour(1.2, 3.4);
+        assert_eq!(range.min(), Speed::new_miles_per_hour(1.2));
+        assert_eq!(range.max(), Speed::new_miles_per_hour(3.4));
+        let range = SpeedRange::new_knots(1.2, 3.4);
+        assert_eq!(range.min(), Speed::new_knots(1.2));
+        assert_eq!(range.max(), Speed::new_knots(3.4));
+    }
+
     #[test]
     fn displays_degrees() {
         let degrees = Degrees::from(-30.5);
@@ -745,6 +964,19 @@ mod tests {
         assert_no_panic(|| PressureRange::new_hpa(32.0, 33.0));
     }
 
+    #[test]
+    fn splits_pressure_into_value_and_unit() {
+        let pressure = Pressure::new_hpa(1012.5);
+        assert_eq!((pressure.value(), pressure.unit_symbol()), (1012.5, "hPa"));
+    }
+
+    #[test]
+    fn extracts_pressure_range_bounds() {
+        let range = PressureRange::new_hpa(1001.2, 1013.4);
+        assert_eq!(range.min(), Pressure::new_hpa(1001.2));
+        assert_eq!(range.max(), Pressure::new_hpa(1013.4));
+    }
+
     #[test]
     fn displays_coordinates() {
         let coordinates = Coordinates::new(1.234, -56.78);
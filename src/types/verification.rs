@@ -0,0 +1,346 @@
+use crate::types::report::{DailyPartialData, DailyPartialReport};
+use crate::types::units::*;
+use crate::types::weather::*;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KindCategory {
+    Clear,
+    Cloudy,
+    Fog,
+    Rain,
+    Snow,
+    Thunderstorm,
+}
+
+impl KindCategory {
+    pub const ALL: [Self; 6] = [
+        Self::Clear,
+        Self::Cloudy,
+        Self::Fog,
+        Self::Rain,
+        Self::Snow,
+        Self::Thunderstorm,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Clear => "clear",
+            Self::Cloudy => "cloudy",
+            Self::Fog => "fog",
+            Self::Rain => "rain",
+            Self::Snow => "snow",
+            Self::Thunderstorm => "thunderstorm",
+        }
+    }
+}
+
+impl From<&Kind> for KindCategory {
+    fn from(kind: &Kind) -> Self {
+        match kind {
+            Kind::Clouds(Clouds::Clear | Clouds::Light) => Self::Clear,
+            Kind::Clouds(Clouds::Moderate | Clouds::Dense) => Self::Cloudy,
+            Kind::Fog(_) => Self::Fog,
+            Kind::Precipitation(precipitation) => match precipitation.kind {
+                PrecipitationKind::Rain => Self::Rain,
+                PrecipitationKind::Snow => Self::Snow,
+            },
+            Kind::Thunderstorm => Self::Thunderstorm,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerifiedAttribute {
+    LowestTemperature,
+    HighestTemperature,
+    CloudCoverage,
+    Humidity,
+    WindSpeed,
+    Pressure,
+}
+
+impl VerifiedAttribute {
+    pub const ALL: [Self; 6] = [
+        Self::LowestTemperature,
+        Self::HighestTemperature,
+        Self::CloudCoverage,
+        Self::Humidity,
+        Self::WindSpeed,
+        Self::Pressure,
+    ];
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DailyValues {
+    pub kind: Option<KindCategory>,
+    pub lowest_temperature_celsius: Option<f32>,
+    pub highest_temperature_celsius: Option<f32>,
+    pub cloud_coverage_percent: Option<f32>,
+    pub humidity_percent: Option<f32>,
+    pub wind_speed_meters_per_second: Option<f32>,
+    pub pressure_hpa: Option<f32>,
+}
+
+impl DailyValues {
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            kind: self.kind.or(other.kind),
+            lowest_temperature_celsius: self
+                .lowest_temperature_celsius
+                .or(other.lowest_temperature_celsius),
+            highest_temperature_celsius: self
+                .highest_temperature_celsius
+                .or(other.highest_temperature_celsius),
+            cloud_coverage_percent: self.cloud_coverage_percent.or(other.cloud_coverage_percent),
+            humidity_percent: self.humidity_percent.or(other.humidity_percent),
+            wind_speed_meters_per_second: self
+                .wind_speed_meters_per_second
+                .or(other.wind_speed_meters_per_second),
+            pressure_hpa: self.pressure_hpa.or(other.pressure_hpa),
+        }
+    }
+
+    pub fn get(&self, attribute: VerifiedAttribute) -> Option<f32> {
+        match attribute {
+            VerifiedAttribute::LowestTemperature => self.lowest_temperature_celsius,
+            VerifiedAttribute::HighestTemperature => self.highest_temperature_celsius,
+            VerifiedAttribute::CloudCoverage => self.cloud_coverage_percent,
+            VerifiedAttribute::Humidity => self.humidity_percent,
+            VerifiedAttribute::WindSpeed => self.wind_speed_meters_per_second,
+            VerifiedAttribute::Pressure => self.pressure_hpa,
+        }
+    }
+}
+
+impl From<&DailyPartialData> for DailyValues {
+    fn from(data: &DailyPartialData) -> Self {
+        let mean_percentage = |range: &PercentageRange| {
+            (f32::from(range.min.value) + f32::from(range.max.value)) / 2.0
+        };
+        Self {
+            kind: data.kind.as_ref().map(KindCategory::from),
+            lowest_temperature_celsius: data
+                .temperature_range
+                .as_ref()
+                .map(|range| range.min().to_celsius().value()),
+            highest_temperature_celsius: data
+                .temperature_range
+                .as_ref()
+                .map(|range| range.max().to_celsius().value()),
+            cloud_coverage_percent: data.cloud_coverage_range.as_ref().map(mean_percentage),
+            humidity_percent: data.humidity_range.as_ref().map(mean_percentage),
+            wind_speed_meters_per_second: data
+                .wind
+                .as_ref()
+                .map(|wind| wind.speed_range.max().to_meters_per_second().value()),
+            pressure_hpa: data
+                .pressure_range
+                .as_ref()
+                .map(|range| (range.min().value() + range.max().value()) / 2.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForecastRecord {
+    pub coordinates: Coordinates,
+    pub issued: Date,
+    pub date: Date,
+    pub values: DailyValues,
+}
+
+impl ForecastRecord {
+    pub fn from_report(report: &DailyPartialReport, issued: Date) -> Vec<Self> {
+        report
+            .data
+            .iter()
+            .map(|data| Self {
+                coordinates: report.location.coordinates,
+                issued,
+                date: data.date,
+                values: DailyValues::from(data),
+            })
+            .collect()
+    }
+
+    pub fn lead_days(&self) -> i64 {
+        (self.date - self.issued).num_days()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LeadTimeAccuracy {
+    pub lead_days: i64,
+    pub forecast_count: usize,
+    pub mean_absolute_errors: Vec<(VerifiedAttribute, f32)>,
+    pub kind_hits: usize,
+    pub kind_count: usize,
+}
+
+pub fn verify(
+    records: &[ForecastRecord],
+    observed: &DailyPartialReport,
+    today: &Date,
+) -> Vec<LeadTimeAccuracy> {
+    let observations: BTreeMap<Date, DailyValues> = observed
+        .data
+        .iter()
+        .filter(|data| data.date < *today)
+        .map(|data| (data.date, DailyValues::from(data)))
+        .collect();
+    let mut pairs: BTreeMap<i64, Vec<(&DailyValues, &DailyValues)>> = BTreeMap::new();
+    for record in records
+        .iter()
+        .filter(|record| record.coordinates.is_near(&observed.location.coordinates))
+    {
+        if let Some(observation) = observations.get(&record.date) {
+            pairs
+                .entry(record.lead_days())
+                .or_default()
+                .push((&record.values, observation));
+        }
+    }
+    pairs
+        .into_iter()
+        .map(|(lead_days, pairs)| measure_accuracy(lead_days, &pairs))
+        .collect()
+}
+
+fn measure_accuracy(lead_days: i64, pairs: &[(&DailyValues, &DailyValues)]) -> LeadTimeAccuracy {
+    let mean_absolute_errors = VerifiedAttribute::ALL
+        .into_iter()
+        .filter_map(|attribute| {
+            let errors: Vec<f32> = pairs
+                .iter()
+                .filter_map(|(forecast, observed)| {
+                    Some((forecast.get(attribute)? - observed.get(attribute)?).abs())
+                })
+                .collect();
+            (!errors.is_empty())
+                .then(|| (attribute, errors.iter().sum::<f32>() / errors.len() as f32))
+        })
+        .collect();
+    let kinds: Vec<bool> = pairs
+        .iter()
+        .filter_map(|(forecast, observed)| Some(forecast.kind? == observed.kind?))
+        .collect();
+    LeadTimeAccuracy {
+        lead_days,
+        forecast_count: pairs.len(),
+        mean_absolute_errors,
+        kind_hits: kinds.iter().filter(|hit| **hit).count(),
+        kind_count: kinds.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_data(day: u32, kind: Kind, temperature: (f32, f32)) -> DailyPartialData {
+        DailyPartialData {
+            date: Date::from_ymd_opt(2025, 3, day).unwrap(),
+            kind: Some(kind),
+            temperature_range: Some(TemperatureRange::new_celsius(temperature.0, temperature.1)),
+            temperature_normal: None,
+            uv_index: None,
+            cloud_coverage_range: None,
+            humidity_range: None,
+            wind: None,
+            pressure_range: None,
+        }
+    }
+
+    fn make_report(data: Vec<DailyPartialData>) -> DailyPartialReport {
+        DailyPartialReport {
+            location: Coordinates::new(52.25, 21.0).into(),
+            data,
+        }
+    }
+
+    fn make_rain() -> Kind {
+        Kind::Precipitation(Precipitation {
+            kind: PrecipitationKind::Rain,
+            intensity: PrecipitationIntensity::Moderate,
+            heat: PrecipitationHeat::Normal,
+        })
+    }
+
+    #[test]
+    fn converts_values_to_common_units() {
+        let mut data = make_data(1, make_rain(), (0.0, 0.0));
+        data.temperature_range = Some(TemperatureRange::new_fahrenheit(32.0, 50.0));
+        data.humidity_range = Some(PercentageRange::new(40, 80));
+        data.wind = Some(WindScope {
+            speed_range: SpeedRange::new_kilometers_per_hour(3.6, 36.0),
+            dominant_direction: Azimuth::from(0.0),
+        });
+        let values = DailyValues::from(&data);
+        assert_eq!(values.kind, Some(KindCategory::Rain));
+        assert_eq!(values.lowest_temperature_celsius, Some(0.0));
+        assert_eq!(values.highest_temperature_celsius, Some(10.0));
+        assert_eq!(values.humidity_percent, Some(60.0));
+        assert_eq!(values.wind_speed_meters_per_second, Some(10.0));
+    }
+
+    #[test]
+    fn measures_errors_per_lead_time() {
+        let issued = Date::from_ymd_opt(2025, 3, 1).unwrap();
+        let forecast = make_report(vec![
+            make_data(2, make_rain(), (1.0, 6.0)),
+            make_data(3, Kind::Clouds(Clouds::Clear), (2.0, 8.0)),
+            make_data(5, Kind::Clouds(Clouds::Clear), (2.0, 8.0)),
+        ]);
+        let mut records = ForecastRecord::from_report(&forecast, issued);
+        let later = make_report(vec![make_data(3, make_rain(), (0.0, 9.0))]);
+        records.extend(ForecastRecord::from_report(
+            &later,
+            Date::from_ymd_opt(2025, 3, 2).unwrap(),
+        ));
+        let observed = make_report(vec![
+            make_data(2, make_rain(), (0.0, 4.0)),
+            make_data(3, make_rain(), (1.0, 8.0)),
+            make_data(4, make_rain(), (1.0, 8.0)),
+        ]);
+        let today = Date::from_ymd_opt(2025, 3, 4).unwrap();
+
+        let accuracy = verify(&records, &observed, &today);
+        assert_eq!(
+            accuracy,
+            [
+                LeadTimeAccuracy {
+                    lead_days: 1,
+                    forecast_count: 2,
+                    mean_absolute_errors: vec![
+                        (VerifiedAttribute::LowestTemperature, 1.0),
+                        (VerifiedAttribute::HighestTemperature, 1.5),
+                    ],
+                    kind_hits: 2,
+                    kind_count: 2,
+                },
+                LeadTimeAccuracy {
+                    lead_days: 2,
+                    forecast_count: 1,
+                    mean_absolute_errors: vec![
+                        (VerifiedAttribute::LowestTemperature, 1.0),
+                        (VerifiedAttribute::HighestTemperature, 0.0),
+                    ],
+                    kind_hits: 0,
+                    kind_count: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_forecasts_of_other_locations() {
+        let issued = Date::from_ymd_opt(2025, 3, 1).unwrap();
+        let mut forecast = make_report(vec![make_data(2, make_rain(), (1.0, 6.0))]);
+        forecast.location = Coordinates::new(50.06, 19.94).into();
+        let records = ForecastRecord::from_report(&forecast, issued);
+        let observed = make_report(vec![make_data(2, make_rain(), (0.0, 4.0))]);
+        let today = Date::from_ymd_opt(2025, 3, 4).unwrap();
+        assert!(verify(&records, &observed, &today).is_empty());
+    }
+}
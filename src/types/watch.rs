@@ -0,0 +1,198 @@
+use crate::types::attributes::WeatherAttribute;
+use crate::types::report::{CurrentPartialReport, Report};
+use std::time::Duration;
+
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(15 * 60);
+pub const MIN_INTERVAL: Duration = Duration::from_secs(60);
+const FIRST_RETRY_DELAY: Duration = Duration::from_secs(30);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60 * 60);
+const MIN_CHANGE: f32 = 0.05;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Up,
+    Down,
+    Changed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub attribute: WeatherAttribute,
+    pub trend: Trend,
+}
+
+pub fn retry_delay(failure_count: u32) -> Duration {
+    let factor = 2u32.saturating_pow(failure_count.saturating_sub(1));
+    FIRST_RETRY_DELAY
+        .saturating_mul(factor)
+        .min(MAX_RETRY_DELAY)
+}
+
+pub fn to_current_partial(report: &Report) -> Option<CurrentPartialReport> {
+    match report {
+        Report::CurrentFull(inner) => Some(inner.clone().into()),
+        Report::CurrentPartial(inner) => Some(inner.clone()),
+        _ => None,
+    }
+}
+
+/// Compares each report with the previous report of the same location, reports of new
+/// locations have no changes
+pub fn compare_reports(previous_reports: &[Report], reports: &[Report]) -> Vec<Vec<Change>> {
+    reports
+        .iter()
+        .map(|report| {
+            let previous = previous_reports
+                .iter()
+                .find(|previous| previous.location().coordinates == report.location().coordinates);
+            match (
+                previous.and_then(to_current_partial),
+                to_current_partial(report),
+            ) {
+                (Some(previous), Some(current)) => compare(&previous, &current),
+                _ => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+pub fn compare(previous: &CurrentPartialReport, current: &CurrentPartialReport) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut push = |attribute, trend: Option<Trend>| {
+        if let Some(trend) = trend {
+            changes.push(Change { attribute, trend });
+        }
+    };
+    push(
+        WeatherAttribute::WeatherKind,
+        compare_values(&previous.kind, &current.kind),
+    );
+    push(
+        WeatherAttribute::Temperature,
+        compare_numbers(
+            previous.temperature.map(|temperature| temperature.value()),
+            current.temperature.map(|temperature| temperature.value()),
+        ),
+    );
+    push(
+        WeatherAttribute::CloudCoverage,
+        compare_values(&previous.cloud_coverage, &current.cloud_coverage),
+    );
+    push(
+        WeatherAttribute::Humidity,
+        compare_values(&previous.humidity, &current.humidity),
+    );
+    push(
+        WeatherAttribute::Wind,
+        compare_values(&previous.wind, &current.wind),
+    );
+    push(
+        WeatherAttribute::Pressure,
+        compare_numbers(
+            previous.pressure.map(|pressure| pressure.value()),
+            current.pressure.map(|pressure| pressure.value()),
+        ),
+    );
+    changes
+}
+
+fn compare_values<T: PartialEq>(previous: &Option<T>, current: &Option<T>) -> Option<Trend> {
+    match (previous, current) {
+        (Some(previous), Some(current)) if previous != current => Some(Trend::Changed),
+        _ => None,
+    }
+}
+
+fn compare_numbers(previous: Option<f32>, current: Option<f32>) -> Option<Trend> {
+    let difference = current? - previous?;
+    if difference >= MIN_CHANGE {
+        Some(Trend::Up)
+    } else if difference <= -MIN_CHANGE {
+        Some(Trend::Down)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::units::*;
+    use crate::types::weather::*;
+
+    fn make_report(temperature: f32, pressure: f32, kind: Kind) -> CurrentPartialReport {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(52.25, 21.0));
+        report.temperature = Some(Temperature::new_celsius(temperature));
+        report.pressure = Some(Pressure::new_hpa(pressure));
+        report.kind = Some(kind);
+        report.humidity = Some(Percentage::from(70));
+        report
+    }
+
+    #[test]
+    fn detects_changes_with_direction_of_numbers() {
+        let previous = make_report(12.0, 1013.2, Kind::Clouds(Clouds::Clear));
+        let current = make_report(12.5, 1012.0, Kind::Clouds(Clouds::Light));
+        assert_eq!(
+            compare(&previous, &current),
+            [
+                Change {
+                    attribute: WeatherAttribute::WeatherKind,
+                    trend: Trend::Changed,
+                },
+                Change {
+                    attribute: WeatherAttribute::Temperature,
+                    trend: Trend::Up,
+                },
+                Change {
+                    attribute: WeatherAttribute::Pressure,
+                    trend: Trend::Down,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_unchanged_and_missing_values() {
+        let previous = make_report(12.0, 1013.2, Kind::Thunderstorm);
+        let mut current = make_report(12.0, 1013.2, Kind::Thunderstorm);
+        current.humidity = None;
+        assert!(compare(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn compares_reports_of_same_location() {
+        let warsaw = make_report(12.0, 1013.2, Kind::Thunderstorm);
+        let mut krakow = make_report(9.0, 1010.0, Kind::Thunderstorm);
+        krakow.location.coordinates = Coordinates::new(50.06, 19.94);
+        let previous = [
+            Report::CurrentPartial(warsaw.clone()),
+            Report::CurrentPartial(krakow.clone()),
+        ];
+        krakow.temperature = Some(Temperature::new_celsius(8.0));
+        let mut gdansk = krakow.clone();
+        gdansk.location.coordinates = Coordinates::new(54.35, 18.65);
+        let current = [
+            Report::CurrentPartial(krakow),
+            Report::CurrentPartial(warsaw),
+            Report::CurrentPartial(gdansk),
+        ];
+        let temperature_down = Change {
+            attribute: WeatherAttribute::Temperature,
+            trend: Trend::Down,
+        };
+        assert_eq!(
+            compare_reports(&previous, &current),
+            [vec![temperature_down], vec![], vec![]]
+        );
+    }
+
+    #[test]
+    fn doubles_retry_delay_up_to_limit() {
+        let delays: Vec<u64> = [1, 2, 3, 8, 40]
+            .into_iter()
+            .map(|count| retry_delay(count).as_secs())
+            .collect();
+        assert_eq!(delays, [30, 60, 120, 3600, 3600]);
+    }
+}
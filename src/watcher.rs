@@ -0,0 +1,253 @@
+use crate::exit_status::ExitStatus;
+use crate::format::{self, Formatter, Refresh};
+use crate::output::{Diagnostics, View};
+use crate::port::clock::Clock;
+use crate::types::report::Report;
+use crate::types::watch;
+use crate::weather_reporter;
+use chrono::Local;
+use std::time::Duration;
+
+/// Refreshes reports until the view fails, backing off while fetching fails
+pub struct Watcher<C: Clock> {
+    clock: C,
+    interval: Duration,
+    styled: bool,
+}
+
+impl<C: Clock> Watcher<C> {
+    pub fn new(clock: C, interval: Duration, styled: bool) -> Self {
+        Self {
+            clock,
+            interval,
+            styled,
+        }
+    }
+
+    pub fn run(
+        &self,
+        mut fetch: impl FnMut() -> Result<Vec<Report>, weather_reporter::Error>,
+        formatter: &dyn Formatter,
+        view: &dyn View,
+        diagnostics: &Diagnostics,
+    ) -> ExitStatus {
+        let mut previous_reports: Vec<Report> = Vec::new();
+        let mut previous_text = String::new();
+        let mut report_text = String::new();
+        let mut failure_count = 0;
+        loop {
+            let (refresh, delay) = match fetch() {
+                Ok(reports) => {
+                    let changes = watch::compare_reports(&previous_reports, &reports);
+                    let text = format::format_reports(formatter, &reports);
+                    report_text = format::highlight_changes(
+                        &previous_text,
+                        &formatter.format_changes(&reports, &changes),
+                        self.styled,
+                    );
+                    previous_reports = reports;
+                    previous_text = text;
+                    failure_count = 0;
+                    (Refresh::Updated, self.interval)
+                }
+                Err(error) if ExitStatus::from(&error) == ExitStatus::InvalidArguments => {
+                    diagnostics.error(&error);
+                    return ExitStatus::InvalidArguments;
+                }
+                Err(error) => {
+                    failure_count += 1;
+                    (
+                        Refresh::Failed(error.to_string()),
+                        watch::retry_delay(failure_count),
+                    )
+                }
+            };
+            let now = self.clock.now().with_timezone(&Local);
+            let screen = format::draw_screen(
+                &report_text,
+                &refresh,
+                now.time(),
+                (now + delay).time(),
+                self.styled,
+            );
+            if let Err(error) = view.display(&screen) {
+                diagnostics.error(error);
+                return ExitStatus::Output;
+            }
+            self.clock.sleep(delay);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::ListFormatter;
+    use crate::output::{self, MockView, Verbosity};
+    use crate::port::mocks::MockClock;
+    use crate::types::report::CurrentPartialReport;
+    use crate::types::units::{Coordinates, Temperature};
+    use chrono::{TimeZone, Utc};
+    use mockall::Sequence;
+    use std::sync::{Arc, Mutex};
+
+    const INTERVAL: Duration = Duration::from_secs(900);
+
+    fn make_report(temperature: f32) -> Report {
+        make_located_report(52.25, temperature)
+    }
+
+    fn make_located_report(latitude: f32, temperature: f32) -> Report {
+        let mut report = CurrentPartialReport::new_empty(Coordinates::new(latitude, 21.0));
+        report.temperature = Some(Temperature::new_celsius(temperature));
+        Report::CurrentPartial(report)
+    }
+
+    fn make_clock(delays: &[Duration]) -> MockClock {
+        let mut clock = MockClock::new();
+        clock
+            .expect_now()
+            .returning(|| Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap());
+        let mut sequence = Sequence::new();
+        for delay in delays.iter().copied() {
+            clock
+                .expect_sleep()
+                .withf(move |duration| *duration == delay)
+                .times(1)
+                .in_sequence(&mut sequence)
+                .return_const(());
+        }
+        clock
+    }
+
+    /// Collects screens and fails to display once `count` screens were shown
+    fn make_view(count: usize) -> (MockView, Arc<Mutex<Vec<String>>>) {
+        let screens = Arc::new(Mutex::new(Vec::new()));
+        let collected = screens.clone();
+        let mut view = MockView::new();
+        view.expect_display().returning(move |screen| {
+            let mut screens = collected.lock().unwrap();
+            if screens.len() == count {
+                return Err(output::Error::WritingToConsole);
+            }
+            screens.push(screen.to_string());
+            Ok(())
+        });
+        (view, screens)
+    }
+
+    #[test]
+    fn refreshes_reports_and_highlights_changes() {
+        let clock = make_clock(&[INTERVAL, INTERVAL]);
+        let (view, screens) = make_view(2);
+        let mut temperatures = vec![12.0, 13.0, 13.0].into_iter();
+        let fetch = || Ok(vec![make_report(temperatures.next().unwrap())]);
+
+        let sut = Watcher::new(clock, INTERVAL, false);
+        let result = sut.run(
+            fetch,
+            &ListFormatter,
+            &view,
+            &Diagnostics::new(Verbosity::Quiet),
+        );
+
+        assert_eq!(result, ExitStatus::Output);
+        let screens = screens.lock().unwrap();
+        assert!(screens[0].contains("Temperature: 12.0°C\n"));
+        assert!(screens[0].contains("Updated at "));
+        assert!(screens[1].contains("Temperature: 13.0°C ↑\n"));
+        assert!(!screens[1].contains('\x1b'));
+    }
+
+    #[test]
+    fn marks_opposite_trends_of_each_location() {
+        let clock = make_clock(&[INTERVAL, INTERVAL]);
+        let (view, screens) = make_view(2);
+        let mut temperatures = vec![(7.0, 9.0), (8.0, 8.0), (8.0, 8.0)].into_iter();
+        let fetch = || {
+            let (first, second) = temperatures.next().unwrap();
+            Ok(vec![
+                make_located_report(52.25, first),
+                make_located_report(50.06, second),
+            ])
+        };
+
+        let sut = Watcher::new(clock, INTERVAL, false);
+        let result = sut.run(
+            fetch,
+            &ListFormatter,
+            &view,
+            &Diagnostics::new(Verbosity::Quiet),
+        );
+
+        assert_eq!(result, ExitStatus::Output);
+        let screens = screens.lock().unwrap();
+        let expected = "52.2500°, 21.0000°:\n\
+                        Coordinates: 52.25000°, 21.00000°\n\
+                        Temperature: 8.0°C ↑\n\
+                        \n\
+                        50.0600°, 21.0000°:\n\
+                        Coordinates: 50.06000°, 21.00000°\n\
+                        Temperature: 8.0°C ↓\n";
+        assert!(screens[1].starts_with(expected));
+    }
+
+    #[test]
+    fn backs_off_while_fetching_fails_and_keeps_last_reports() {
+        let clock = make_clock(&[
+            INTERVAL,
+            Duration::from_secs(30),
+            Duration::from_secs(60),
+            INTERVAL,
+        ]);
+        let (view, screens) = make_view(4);
+        let mut results = vec![
+            Ok(vec![make_report(12.0)]),
+            Err(weather_reporter::Error::FetchingWeatherReport),
+            Err(weather_reporter::Error::FetchingWeatherReport),
+            Ok(vec![make_report(12.0)]),
+        ]
+        .into_iter();
+        let fetch = || results.next().unwrap_or(Ok(vec![make_report(12.0)]));
+
+        let sut = Watcher::new(clock, INTERVAL, true);
+        let result = sut.run(
+            fetch,
+            &ListFormatter,
+            &view,
+            &Diagnostics::new(Verbosity::Quiet),
+        );
+
+        assert_eq!(result, ExitStatus::Output);
+        let screens = screens.lock().unwrap();
+        assert!(screens[1].starts_with("\x1b[H\x1b[2J"));
+        assert!(screens[1].contains("Temperature: 12.0°C\n"));
+        assert!(screens[1].contains("Refresh failed at "));
+        assert!(screens[2].contains("Failed to fetch weather report"));
+        assert!(screens[3].contains("Updated at "));
+    }
+
+    #[test]
+    fn stops_when_request_is_rejected() {
+        let mut clock = MockClock::new();
+        clock.expect_now().never();
+        clock.expect_sleep().never();
+        let mut view = MockView::new();
+        view.expect_display().never();
+        let fetch = || {
+            Err(weather_reporter::Error::RejectedRequest(
+                "Invalid coordinates".into(),
+            ))
+        };
+
+        let sut = Watcher::new(clock, INTERVAL, true);
+        let result = sut.run(
+            fetch,
+            &ListFormatter,
+            &view,
+            &Diagnostics::new(Verbosity::Quiet),
+        );
+
+        assert_eq!(result, ExitStatus::InvalidArguments);
+    }
+}
@@ -1,73 +1,195 @@
+use crate::external::{LocationSource, PlaceNameSource};
+use crate::format::{OutputFormat, ReportContent};
+use crate::output::{Destination, Diagnostics, Verbosity};
+use crate::port::geocoding::ReverseGeocodingProvider;
 use crate::port::geolocation::GeolocationProvider;
 use crate::port::weather::*;
+use crate::types::error::FetchError;
+use crate::types::location::Location;
 use crate::types::report::*;
-use crate::types::units::Coordinates;
+use crate::types::timezone::Timezone;
+use crate::types::units::{Coordinates, Meters};
+use std::path::PathBuf;
 
+#[derive(Clone)]
 pub struct Parameters {
-    pub coordinates: Option<Coordinates>,
+    pub coordinates: Vec<Coordinates>,
+    pub location_source: LocationSource,
+    pub elevation: Option<Meters>,
+    pub timezone: Timezone,
+    pub include_normals: bool,
     pub request_kind: RequestKind,
     pub units: Units,
+    pub output_format: OutputFormat,
+    pub content: ReportContent,
+    pub destination: Destination,
+    pub verbosity: Verbosity,
+    pub forecast_store: Option<PathBuf>,
+    pub history_store: Option<PathBuf>,
+    pub place_names: PlaceNameSource,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
     FetchingCoordinates,
     FetchingWeatherReport,
+    DecodingWeatherReport,
+    UnsupportedRequest(String),
+    RejectedRequest(String),
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let message = match self {
-            Self::FetchingCoordinates => "Failed to fetch current coordinates",
-            Self::FetchingWeatherReport => "Failed to fetch weather report",
-        };
-        write!(f, "{message}")
+        match self {
+            Self::FetchingCoordinates => write!(f, "Failed to fetch current coordinates"),
+            Self::FetchingWeatherReport => write!(f, "Failed to fetch weather report"),
+            Self::DecodingWeatherReport => write!(f, "Failed to decode weather report"),
+            Self::UnsupportedRequest(message) => {
+                write!(f, "Weather report cannot be provided: {message}")
+            }
+            Self::RejectedRequest(reason) => {
+                write!(f, "Weather service rejected the request: {reason}")
+            }
+        }
     }
 }
 
-pub struct WeatherReporter<GP: GeolocationProvider, WP: WeatherProvider> {
+impl std::error::Error for Error {}
+
+pub struct WeatherReporter<
+    GP: GeolocationProvider,
+    WP: WeatherProvider,
+    RG: ReverseGeocodingProvider,
+> {
     geolocation_provider: GP,
     weather_provider: WP,
+    reverse_geocoding_provider: RG,
 }
 
 const MAX_NUMBER_OF_ATTEMPTS: usize = 3;
-
-impl<GP: GeolocationProvider, WP: WeatherProvider> WeatherReporter<GP, WP> {
-    pub fn new(geolocation_provider: GP, weather_provider: WP) -> Self {
+const MAX_NUMBER_OF_COORDINATES_ATTEMPTS: usize = 2;
+
+impl<GP: GeolocationProvider, WP: WeatherProvider, RG: ReverseGeocodingProvider>
+    WeatherReporter<GP, WP, RG>
+{
+    pub fn new(
+        geolocation_provider: GP,
+        weather_provider: WP,
+        reverse_geocoding_provider: RG,
+    ) -> Self {
         Self {
             geolocation_provider,
             weather_provider,
+            reverse_geocoding_provider,
         }
     }
 
-    pub fn run(&self, parameters: Parameters) -> Result<Report, Error> {
-        let coordinates = self.get_coordinates(parameters.coordinates)?;
-        let request = ReportRequest {
-            coordinates,
-            kind: parameters.request_kind,
-            units: parameters.units,
+    pub fn run(&self, parameters: Parameters) -> Result<Vec<Report>, Error> {
+        let diagnostics = Diagnostics::new(parameters.verbosity);
+        let mut coordinates = parameters.coordinates;
+        if coordinates.is_empty() {
+            coordinates.push(self.get_coordinates(&diagnostics)?);
+        }
+        let mut reports = if coordinates.len() == 1 {
+            let request = ReportRequest {
+                coordinates: coordinates[0],
+                elevation: parameters.elevation,
+                timezone: parameters.timezone.clone(),
+                include_normals: parameters.include_normals,
+                kind: parameters.request_kind,
+                units: parameters.units,
+            };
+            vec![self.get_report(&request, &diagnostics)?]
+        } else {
+            let request = BatchReportRequest {
+                coordinates,
+                elevation: parameters.elevation,
+                timezone: parameters.timezone.clone(),
+                include_normals: parameters.include_normals,
+                kind: parameters.request_kind,
+                units: parameters.units,
+            };
+            self.get_reports(&request, &diagnostics)?
         };
-        self.get_report(&request)
+        if parameters.place_names != PlaceNameSource::Off {
+            for report in &mut reports {
+                self.name_location(report.location_mut(), &diagnostics);
+            }
+        }
+        Ok(reports)
     }
 
-    fn get_coordinates(&self, coordinates: Option<Coordinates>) -> Result<Coordinates, Error> {
-        if let Some(coords) = coordinates {
-            return Ok(coords);
+    fn name_location(&self, location: &mut Location, diagnostics: &Diagnostics) {
+        let coordinates = location.coordinates;
+        diagnostics.info(format!("Looking up place name of {coordinates:.4}"));
+        match self.reverse_geocoding_provider.lookup(&coordinates) {
+            Ok(place_name) => location.set_place_name(place_name),
+            Err(error) => diagnostics.info(format!("Place name lookup failed: {error}")),
         }
-        for _ in 0..MAX_NUMBER_OF_ATTEMPTS {
+    }
+
+    fn get_coordinates(&self, diagnostics: &Diagnostics) -> Result<Coordinates, Error> {
+        for attempt in 1..=MAX_NUMBER_OF_COORDINATES_ATTEMPTS {
+            diagnostics.info("Fetching current coordinates");
             match self.geolocation_provider.fetch() {
-                Ok(coords) => return Ok(coords),
-                Err(error) => println!("Error: {error}"),
+                Ok(coords) => {
+                    diagnostics.info(format!("Using coordinates {coords:.4}"));
+                    return Ok(coords);
+                }
+                Err(FetchError::Timeout) => {
+                    diagnostics.info("Timed out waiting for current coordinates");
+                    break;
+                }
+                Err(error) => diagnostics.info(format!(
+                    "Attempt {attempt} of {MAX_NUMBER_OF_COORDINATES_ATTEMPTS} failed: {error}"
+                )),
             }
         }
         Err(Error::FetchingCoordinates)
     }
 
-    fn get_report(&self, request: &ReportRequest) -> Result<Report, Error> {
-        for _ in 0..MAX_NUMBER_OF_ATTEMPTS {
+    fn get_report(
+        &self,
+        request: &ReportRequest,
+        diagnostics: &Diagnostics,
+    ) -> Result<Report, Error> {
+        for attempt in 1..=MAX_NUMBER_OF_ATTEMPTS {
+            diagnostics.info("Fetching weather report");
             match self.weather_provider.fetch(request) {
                 Ok(report) => return Ok(report),
-                Err(error) => println!("Error: {error}"),
+                Err(FetchError::DecodingFailure) => return Err(Error::DecodingWeatherReport),
+                Err(FetchError::Unsupported(message)) => {
+                    return Err(Error::UnsupportedRequest(message));
+                }
+                Err(FetchError::Rejected(reason)) => return Err(Error::RejectedRequest(reason)),
+                Err(error) => diagnostics.info(format!(
+                    "Attempt {attempt} of {MAX_NUMBER_OF_ATTEMPTS} failed: {error}"
+                )),
+            }
+        }
+        Err(Error::FetchingWeatherReport)
+    }
+
+    fn get_reports(
+        &self,
+        request: &BatchReportRequest,
+        diagnostics: &Diagnostics,
+    ) -> Result<Vec<Report>, Error> {
+        for attempt in 1..=MAX_NUMBER_OF_ATTEMPTS {
+            diagnostics.info(format!(
+                "Fetching weather reports for {} locations",
+                request.coordinates.len()
+            ));
+            match self.weather_provider.fetch_batch(request) {
+                Ok(reports) => return Ok(reports),
+                Err(FetchError::DecodingFailure) => return Err(Error::DecodingWeatherReport),
+                Err(FetchError::Unsupported(message)) => {
+                    return Err(Error::UnsupportedRequest(message));
+                }
+                Err(FetchError::Rejected(reason)) => return Err(Error::RejectedRequest(reason)),
+                Err(error) => diagnostics.info(format!(
+                    "Attempt {attempt} of {MAX_NUMBER_OF_ATTEMPTS} failed: {error}"
+                )),
             }
         }
         Err(Error::FetchingWeatherReport)
@@ -77,14 +199,18 @@ impl<GP: GeolocationProvider, WP: WeatherProvider> WeatherReporter<GP, WP> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::port::mocks::{MockGeolocationProvider, MockWeatherProvider};
-    use crate::port::weather::ReportRequest;
-    use crate::types::error::FetchError;
+    use crate::port::mocks::{
+        MockGeolocationProvider, MockReverseGeocodingProvider, MockWeatherProvider,
+    };
+    use crate::types::location::PlaceName;
     use crate::types::units::*;
     use crate::types::weather::*;
+    use mockall::predicate::eq;
 
     fn make_dummy_report() -> Report {
         Report::CurrentFull(CurrentFullReport {
+            location: Coordinates::new(1.23, 45.67).into(),
+            time: "2025-09-18T14:15:00+02:00".parse().unwrap(),
             kind: Kind::Thunderstorm,
             temperature: Temperature::new_celsius(23.4),
             cloud_coverage: Percentage::from(50),
@@ -94,9 +220,40 @@ mod tests {
                 direction: Azimuth::from(90.0),
             },
             pressure: Pressure::new_hpa(1001.23),
+            uv_index: None,
         })
     }
 
+    fn make_failing_reverse_geocoding_provider() -> MockReverseGeocodingProvider {
+        let mut reverse_geocoding_provider = MockReverseGeocodingProvider::new();
+        reverse_geocoding_provider
+            .expect_lookup()
+            .returning(|_| Err(FetchError::NotFound));
+        reverse_geocoding_provider
+    }
+
+    fn make_parameters() -> Parameters {
+        Parameters {
+            coordinates: Vec::new(),
+            location_source: LocationSource::Ip,
+            elevation: None,
+            timezone: Timezone::Auto,
+            include_normals: false,
+            request_kind: RequestKind::CurrentFull,
+            units: Units {
+                temperature: TemperatureUnit::Celsius,
+                speed: SpeedUnit::MetersPerSecond,
+            },
+            output_format: OutputFormat::Summary,
+            content: ReportContent::Weather,
+            destination: Destination::Console,
+            verbosity: Verbosity::Normal,
+            forecast_store: None,
+            history_store: None,
+            place_names: PlaceNameSource::Offline,
+        }
+    }
+
     #[test]
     fn fetches_coordinates_if_not_provided() {
         let mut geolocation_provider = MockGeolocationProvider::new();
@@ -115,15 +272,12 @@ mod tests {
             .withf(matching_coordinates)
             .return_const(Ok(make_dummy_report()));
 
-        let sut = WeatherReporter::new(geolocation_provider, weather_provider);
-        let parameters = Parameters {
-            coordinates: None,
-            request_kind: RequestKind::CurrentFull,
-            units: Units {
-                temperature: TemperatureUnit::Celsius,
-                speed: SpeedUnit::MetersPerSecond,
-            },
-        };
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = make_parameters();
         let result = sut.run(parameters);
         assert!(result.is_ok());
     }
@@ -134,7 +288,7 @@ mod tests {
         let coordinates = Coordinates::new(1.23, 45.67);
         geolocation_provider
             .expect_fetch()
-            .times(2)
+            .once()
             .return_const(Err(FetchError::ConnectionFailure));
         geolocation_provider
             .expect_fetch()
@@ -150,15 +304,12 @@ mod tests {
             .withf(matching_coordinates)
             .return_const(Ok(make_dummy_report()));
 
-        let sut = WeatherReporter::new(geolocation_provider, weather_provider);
-        let parameters = Parameters {
-            coordinates: None,
-            request_kind: RequestKind::CurrentFull,
-            units: Units {
-                temperature: TemperatureUnit::Celsius,
-                speed: SpeedUnit::MetersPerSecond,
-            },
-        };
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = make_parameters();
         let result = sut.run(parameters);
         assert!(result.is_ok());
     }
@@ -168,25 +319,42 @@ mod tests {
         let mut geolocation_provider = MockGeolocationProvider::new();
         geolocation_provider
             .expect_fetch()
-            .times(3)
+            .times(2)
             .return_const(Err(FetchError::ConnectionFailure));
 
         let mut weather_provider = MockWeatherProvider::new();
         weather_provider.expect_fetch().never();
 
-        let sut = WeatherReporter::new(geolocation_provider, weather_provider);
-        let parameters = Parameters {
-            coordinates: None,
-            request_kind: RequestKind::CurrentFull,
-            units: Units {
-                temperature: TemperatureUnit::Celsius,
-                speed: SpeedUnit::MetersPerSecond,
-            },
-        };
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = make_parameters();
         let result = sut.run(parameters);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn does_not_retry_to_fetch_coordinates_after_timeout() {
+        let mut geolocation_provider = MockGeolocationProvider::new();
+        geolocation_provider
+            .expect_fetch()
+            .once()
+            .return_const(Err(FetchError::Timeout));
+
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider.expect_fetch().never();
+
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = make_parameters();
+        assert_eq!(sut.run(parameters), Err(Error::FetchingCoordinates));
+    }
+
     #[test]
     fn uses_provided_coordinates() {
         let mut geolocation_provider = MockGeolocationProvider::new();
@@ -202,14 +370,14 @@ mod tests {
             .withf(matching_coordinates)
             .return_const(Ok(make_dummy_report()));
 
-        let sut = WeatherReporter::new(geolocation_provider, weather_provider);
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
         let parameters = Parameters {
-            coordinates: Some(coordinates),
-            request_kind: RequestKind::CurrentFull,
-            units: Units {
-                temperature: TemperatureUnit::Celsius,
-                speed: SpeedUnit::MetersPerSecond,
-            },
+            coordinates: vec![coordinates],
+            ..make_parameters()
         };
         let result = sut.run(parameters);
         assert!(result.is_ok());
@@ -226,18 +394,18 @@ mod tests {
             .expect_fetch()
             .return_const(Ok(report.clone()));
 
-        let sut = WeatherReporter::new(geolocation_provider, weather_provider);
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
         let coordinates = Coordinates::new(1.23, 45.67);
         let parameters = Parameters {
-            coordinates: Some(coordinates),
-            request_kind: RequestKind::CurrentFull,
-            units: Units {
-                temperature: TemperatureUnit::Celsius,
-                speed: SpeedUnit::MetersPerSecond,
-            },
+            coordinates: vec![coordinates],
+            ..make_parameters()
         };
         let actual_report = sut.run(parameters);
-        assert_eq!(actual_report, Ok(report));
+        assert_eq!(actual_report, Ok(vec![report]));
     }
 
     #[test]
@@ -256,17 +424,17 @@ mod tests {
             .once()
             .return_const(Ok(report.clone()));
 
-        let sut = WeatherReporter::new(geolocation_provider, weather_provider);
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
         let parameters = Parameters {
-            coordinates: Some(Coordinates::new(1.23, 45.67)),
-            request_kind: RequestKind::CurrentFull,
-            units: Units {
-                temperature: TemperatureUnit::Celsius,
-                speed: SpeedUnit::MetersPerSecond,
-            },
+            coordinates: vec![Coordinates::new(1.23, 45.67)],
+            ..make_parameters()
         };
         let actual_report = sut.run(parameters);
-        assert_eq!(actual_report, Ok(report));
+        assert_eq!(actual_report, Ok(vec![report]));
     }
 
     #[test]
@@ -280,16 +448,220 @@ mod tests {
             .times(3)
             .return_const(Err(FetchError::ConnectionFailure));
 
-        let sut = WeatherReporter::new(geolocation_provider, weather_provider);
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
         let parameters = Parameters {
-            coordinates: Some(Coordinates::new(1.23, 45.67)),
+            coordinates: vec![Coordinates::new(1.23, 45.67)],
+            ..make_parameters()
+        };
+        let result = sut.run(parameters);
+        assert_eq!(result, Err(Error::FetchingWeatherReport));
+    }
+
+    #[test]
+    fn does_not_retry_to_decode_report() {
+        let mut geolocation_provider = MockGeolocationProvider::new();
+        geolocation_provider.expect_fetch().never();
+
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider
+            .expect_fetch()
+            .times(1)
+            .return_const(Err(FetchError::DecodingFailure));
+
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = Parameters {
+            coordinates: vec![Coordinates::new(1.23, 45.67)],
+            ..make_parameters()
+        };
+        let result = sut.run(parameters);
+        assert_eq!(result, Err(Error::DecodingWeatherReport));
+    }
+
+    #[test]
+    fn does_not_retry_rejected_request() {
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider
+            .expect_fetch()
+            .once()
+            .return_const(Err(FetchError::Rejected("Invalid elevation".into())));
+
+        let sut = WeatherReporter::new(
+            MockGeolocationProvider::new(),
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = Parameters {
+            coordinates: vec![Coordinates::new(1.23, 45.67)],
+            ..make_parameters()
+        };
+        let result = sut.run(parameters);
+        assert_eq!(
+            result,
+            Err(Error::RejectedRequest("Invalid elevation".into()))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Weather service rejected the request: Invalid elevation"
+        );
+    }
+
+    #[test]
+    fn does_not_retry_unsupported_request() {
+        let mut geolocation_provider = MockGeolocationProvider::new();
+        geolocation_provider.expect_fetch().never();
+
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider
+            .expect_fetch()
+            .times(1)
+            .return_const(Err(FetchError::Unsupported("too many days".into())));
+
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = Parameters {
+            coordinates: vec![Coordinates::new(1.23, 45.67)],
+            request_kind: RequestKind::ForecastFull(20),
+            ..make_parameters()
+        };
+        let result = sut.run(parameters);
+        assert_eq!(
+            result,
+            Err(Error::UnsupportedRequest("too many days".into()))
+        );
+    }
+
+    #[test]
+    fn fetches_reports_for_multiple_locations_in_one_request() {
+        let mut geolocation_provider = MockGeolocationProvider::new();
+        geolocation_provider.expect_fetch().never();
+
+        let coordinates = vec![Coordinates::new(1.23, 45.67), Coordinates::new(8.9, 10.0)];
+        let expected_request = BatchReportRequest {
+            coordinates: coordinates.clone(),
+            elevation: None,
+            timezone: Timezone::Auto,
+            include_normals: false,
+            kind: RequestKind::CurrentFull,
             units: Units {
                 temperature: TemperatureUnit::Celsius,
                 speed: SpeedUnit::MetersPerSecond,
             },
-            request_kind: RequestKind::CurrentFull,
+        };
+        let reports = vec![make_dummy_report(), make_dummy_report()];
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider.expect_fetch().never();
+        weather_provider
+            .expect_fetch_batch()
+            .with(eq(expected_request))
+            .times(1)
+            .return_const(Ok(reports.clone()));
+
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = Parameters {
+            coordinates,
+            ..make_parameters()
         };
         let result = sut.run(parameters);
-        assert!(result.is_err());
+        assert_eq!(result, Ok(reports));
+    }
+
+    #[test]
+    fn names_location_of_fetched_report() {
+        let mut geolocation_provider = MockGeolocationProvider::new();
+        geolocation_provider.expect_fetch().never();
+
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider
+            .expect_fetch()
+            .once()
+            .return_const(Ok(make_dummy_report()));
+
+        let coordinates = Coordinates::new(1.23, 45.67);
+        let place_name = PlaceName {
+            name: "Warsaw".into(),
+            country: Some("Poland".into()),
+        };
+        let mut reverse_geocoding_provider = MockReverseGeocodingProvider::new();
+        reverse_geocoding_provider
+            .expect_lookup()
+            .with(eq(coordinates))
+            .once()
+            .return_const(Ok(place_name.clone()));
+
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            reverse_geocoding_provider,
+        );
+        let parameters = Parameters {
+            coordinates: vec![coordinates],
+            ..make_parameters()
+        };
+        let reports = sut.run(parameters).unwrap();
+        assert_eq!(reports[0].location().place_name(), Some(place_name));
+    }
+
+    #[test]
+    fn skips_place_name_lookup_when_turned_off() {
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider
+            .expect_fetch()
+            .once()
+            .return_const(Ok(make_dummy_report()));
+        let mut reverse_geocoding_provider = MockReverseGeocodingProvider::new();
+        reverse_geocoding_provider.expect_lookup().never();
+
+        let sut = WeatherReporter::new(
+            MockGeolocationProvider::new(),
+            weather_provider,
+            reverse_geocoding_provider,
+        );
+        let parameters = Parameters {
+            coordinates: vec![Coordinates::new(1.23, 45.67)],
+            place_names: PlaceNameSource::Off,
+            ..make_parameters()
+        };
+        let reports = sut.run(parameters).unwrap();
+        assert_eq!(reports[0].location().place_name(), None);
+    }
+
+    #[test]
+    fn passes_requested_elevation() {
+        let mut geolocation_provider = MockGeolocationProvider::new();
+        geolocation_provider.expect_fetch().never();
+
+        let mut weather_provider = MockWeatherProvider::new();
+        weather_provider
+            .expect_fetch()
+            .withf(|request: &ReportRequest| request.elevation == Some(Meters::from(1780.0)))
+            .once()
+            .return_const(Ok(make_dummy_report()));
+
+        let sut = WeatherReporter::new(
+            geolocation_provider,
+            weather_provider,
+            make_failing_reverse_geocoding_provider(),
+        );
+        let parameters = Parameters {
+            coordinates: vec![Coordinates::new(49.2, 19.9)],
+            elevation: Some(Meters::from(1780.0)),
+            ..make_parameters()
+        };
+        assert!(sut.run(parameters).is_ok());
     }
 }